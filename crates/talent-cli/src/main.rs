@@ -3,16 +3,24 @@
 //! Command-line interface for managing skills across AI CLI tools.
 
 use agentloom_core::{
-    Config, ConflictResolution, ImportSelection, Importer, SkillManager, SyncResult,
-    ValidationStatus,
+    has_legacy_skills, legacy_skills_dir, AdoptSelection, Config, ConfigLayer, ConfigSources,
+    ConflictResolution, Diagnostic, ImportSelection, Importer, LinkStyle, PlannedLink,
+    RepairResult, Severity, SkillManager, SkillSize, SyncPlan, SyncResult, SyncStatus, Target,
+    TargetBinary, ValidationStatus, CONFIG_VERSION, RULES,
 };
 use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Parser)]
 #[command(name = "agentloom")]
 #[command(about = "AgentLoom - Sync skills across AI CLI tools")]
 #[command(version)]
 struct Cli {
+    /// Extra config file, applied over all other config layers
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -21,7 +29,7 @@ struct Cli {
 enum Commands {
     /// List all skills
     List {
-        /// Show only valid skills
+        /// Show only valid skills (including those with warnings)
         #[arg(long)]
         valid: bool,
 
@@ -32,6 +40,10 @@ enum Commands {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Show the size of each skill: lines, bytes and estimated tokens
+        #[arg(short, long)]
+        long: bool,
     },
 
     /// Sync skills to all targets
@@ -43,11 +55,52 @@ enum Commands {
         /// Dry run - show what would be synced without making changes
         #[arg(long)]
         dry_run: bool,
+
+        /// Replace native folders that shadow managed skills with links (kept as backups);
+        /// asks before overwriting a central copy with a folder that differs
+        #[arg(long)]
+        adopt: bool,
+    },
+
+    /// Watch skills and targets, resyncing whenever something changes
+    Watch {
+        /// Time to wait for changes to settle, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        debounce: u64,
+    },
+
+    /// Show whether everything is fine: skills, targets and config
+    #[command(after_help = STATUS_HELP)]
+    Status {
+        /// Target specific CLI (e.g., claude-code, codex)
+        #[arg(long)]
+        target: Option<String>,
+
+        /// Output as JSON
+        #[arg(long, conflicts_with = "porcelain")]
+        json: bool,
+
+        /// Stable one-line-per-problem output for scripts
+        #[arg(long)]
+        porcelain: bool,
+    },
+
+    /// Fix broken links in targets and report items AgentLoom doesn't manage
+    Repair {
+        /// Target specific CLI (e.g., claude-code, codex)
+        #[arg(long)]
+        target: Option<String>,
     },
 
     /// Show diagnostic information
     Doctor,
 
+    /// Read and edit configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
     /// List detected targets
     Targets {
         /// Output as JSON
@@ -69,6 +122,10 @@ enum Commands {
     Validate {
         /// Specific skill to validate (validates all if not specified)
         name: Option<String>,
+
+        /// List the validation rules and their configured levels instead
+        #[arg(long, conflicts_with = "name")]
+        rules: bool,
     },
 
     /// Delete a skill
@@ -101,27 +158,99 @@ enum Commands {
     },
 }
 
+/// Config subcommands
+///
+/// `get`, `set`, `unset`, `list` and `edit` work on a single file: the one
+/// passed with `--config`, or the user config file. `show` merges all layers.
+#[derive(clap::Subcommand)]
+enum ConfigCommands {
+    /// Show the effective configuration
+    Show {
+        /// Show which layer (default, system, user, project, env, cli) supplied each value
+        #[arg(long)]
+        origin: bool,
+    },
+
+    /// Print a value (e.g. preferences.validate_on_sync)
+    Get {
+        /// Dotted key path
+        key: String,
+    },
+
+    /// Set a value (e.g. targets.codex.skills_path ~/codex/skills)
+    Set {
+        /// Dotted key path
+        key: String,
+
+        /// New value (TOML literal such as true or ["a", "b"], otherwise a string)
+        value: String,
+    },
+
+    /// Remove a value so it falls back to the default
+    Unset {
+        /// Dotted key path
+        key: String,
+    },
+
+    /// List the values set in the config file
+    List,
+
+    /// Print the path of the config file
+    Path,
+
+    /// Open the config file in $VISUAL or $EDITOR
+    Edit,
+}
+
 fn main() {
     let cli = Cli::parse();
+    let config = cli.config.as_deref();
 
     let result = match cli.command {
         Some(Commands::List {
             valid,
             invalid,
             json,
-        }) => cmd_list(valid, invalid, json),
-        Some(Commands::Sync { target, dry_run }) => cmd_sync(target, dry_run),
-        Some(Commands::Doctor) => cmd_doctor(),
-        Some(Commands::Targets { json }) => cmd_targets(json),
-        Some(Commands::Create { name, description }) => cmd_create(&name, &description),
-        Some(Commands::Validate { name }) => cmd_validate(name),
-        Some(Commands::Delete { name, force }) => cmd_delete(&name, force),
+            long,
+        }) => cmd_list(config, valid, invalid, json, long),
+        Some(Commands::Sync {
+            target,
+            dry_run,
+            adopt,
+        }) => cmd_sync(config, target, dry_run, adopt),
+        Some(Commands::Watch { debounce }) => cmd_watch(config, debounce),
+        Some(Commands::Status {
+            target,
+            json,
+            porcelain,
+        }) => cmd_status(config, target, json, porcelain),
+        Some(Commands::Repair { target }) => cmd_repair(config, target),
+        Some(Commands::Doctor) => cmd_doctor(config),
+        Some(Commands::Config { command }) => match command {
+            ConfigCommands::Show { origin } => cmd_config_show(config, origin),
+            ConfigCommands::Get { key } => cmd_config_get(config, &key),
+            ConfigCommands::Set { key, value } => cmd_config_set(config, &key, &value),
+            ConfigCommands::Unset { key } => cmd_config_unset(config, &key),
+            ConfigCommands::List => cmd_config_list(config),
+            ConfigCommands::Path => cmd_config_path(config),
+            ConfigCommands::Edit => cmd_config_edit(config),
+        },
+        Some(Commands::Targets { json }) => cmd_targets(config, json),
+        Some(Commands::Create { name, description }) => cmd_create(config, &name, &description),
+        Some(Commands::Validate { name, rules }) => {
+            if rules {
+                cmd_validate_rules(config)
+            } else {
+                cmd_validate(config, name)
+            }
+        }
+        Some(Commands::Delete { name, force }) => cmd_delete(config, &name, force),
         Some(Commands::Import {
             all,
             overwrite,
             json,
             dry_run,
-        }) => cmd_import(all, overwrite, json, dry_run),
+        }) => cmd_import(config, all, overwrite, json, dry_run),
         None => {
             println!("AgentLoom");
             println!("Run 'agentloom --help' for usage");
@@ -137,11 +266,13 @@ fn main() {
 
 /// List all skills
 fn cmd_list(
+    config: Option<&Path>,
     valid_only: bool,
     invalid_only: bool,
     json: bool,
+    long: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut manager = SkillManager::new()?;
+    let mut manager = SkillManager::open(config)?;
 
     // Validate all skills first to get their status
     manager.validate_all();
@@ -151,7 +282,7 @@ fn cmd_list(
         .iter()
         .filter(|s| {
             if valid_only {
-                s.validation_status == ValidationStatus::Valid
+                s.is_valid()
             } else if invalid_only {
                 s.validation_status == ValidationStatus::Invalid
             } else {
@@ -164,12 +295,19 @@ fn cmd_list(
         let output: Vec<_> = skills
             .iter()
             .map(|s| {
-                serde_json::json!({
+                let mut entry = serde_json::json!({
                     "name": s.name(),
                     "description": &s.meta.description,
                     "path": s.path,
+                    "scope": s.scope,
                     "status": format!("{:?}", s.validation_status),
-                })
+                    "allowed_tools": s.allowed_tools(),
+                    "warnings": s.diagnostics_of(Severity::Warning).collect::<Vec<_>>(),
+                });
+                if long {
+                    entry["size"] = serde_json::json!(s.size());
+                }
+                entry
             })
             .collect();
         println!("{}", serde_json::to_string_pretty(&output)?);
@@ -184,14 +322,16 @@ fn cmd_list(
         }
 
         println!("Skills ({}):", skills.len());
+        if let Some(project) = manager.project() {
+            println!("Project: {}", project.root.display());
+        }
         println!();
 
+        // Scopes only matter when a project adds skills of its own
+        let show_scope = manager.project().is_some();
+
         for skill in skills {
-            let status_icon = match skill.validation_status {
-                ValidationStatus::Valid => "✓",
-                ValidationStatus::Invalid => "✗",
-                ValidationStatus::Unknown => "?",
-            };
+            let status_icon = status_icon(skill.validation_status);
 
             let desc = if skill.meta.description.is_empty() {
                 "No description"
@@ -199,16 +339,70 @@ fn cmd_list(
                 &skill.meta.description
             };
 
-            println!("  {} {} - {}", status_icon, skill.name(), desc);
+            if show_scope {
+                println!(
+                    "  {} {} [{}] - {}",
+                    status_icon,
+                    skill.name(),
+                    skill.scope.display_name(),
+                    desc
+                );
+            } else {
+                println!("  {} {} - {}", status_icon, skill.name(), desc);
+            }
+            if long {
+                print_size(&skill.size());
+            }
+            for warning in skill.diagnostics_of(Severity::Warning) {
+                println!("      warning[{}] {}", warning.rule, warning);
+            }
         }
     }
 
     Ok(())
 }
 
+/// Print the size of a skill on one line under its name
+fn print_size(size: &SkillSize) {
+    let total = size.total();
+    println!(
+        "      {} lines, {}, ~{} tokens (frontmatter ~{}, body ~{}, references ~{})",
+        total.lines,
+        format_bytes(total.bytes),
+        total.tokens,
+        size.frontmatter.tokens,
+        size.body.tokens,
+        size.references.tokens
+    );
+}
+
+/// Format a byte count as B, KB or MB
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        b if b < 1024 => format!("{b} B"),
+        b if b < 1024 * 1024 => format!("{:.1} KB", b as f64 / 1024.0),
+        b => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+/// Icon shown next to a skill for its validation status
+fn status_icon(status: ValidationStatus) -> &'static str {
+    match status {
+        ValidationStatus::Valid => "✓",
+        ValidationStatus::ValidWithWarnings => "!",
+        ValidationStatus::Invalid => "✗",
+        ValidationStatus::Unknown => "?",
+    }
+}
+
 /// Sync skills to targets
-fn cmd_sync(target: Option<String>, dry_run: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let mut manager = SkillManager::new()?;
+fn cmd_sync(
+    config: Option<&Path>,
+    target: Option<String>,
+    dry_run: bool,
+    adopt: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut manager = SkillManager::open(config)?;
 
     // Validate all skills first
     manager.validate_all();
@@ -219,8 +413,13 @@ fn cmd_sync(target: Option<String>, dry_run: bool) -> Result<(), Box<dyn std::er
         return Ok(());
     }
 
+    if adopt {
+        adopt_native_folders(&mut manager, target.as_deref(), dry_run)?;
+    }
+
     if dry_run {
         println!("Dry run - no changes will be made\n");
+        return print_sync_plans(&manager, target.as_deref());
     }
 
     let results: Vec<SyncResult> = if let Some(target_id) = target {
@@ -240,51 +439,715 @@ fn cmd_sync(target: Option<String>, dry_run: bool) -> Result<(), Box<dyn std::er
 
     // Display results
     for result in &results {
+        print_sync_result(result, results.len() == 1);
+    }
+
+    // Summary
+    let total_created: usize = results.iter().map(|r| r.created.len()).sum();
+    let total_updated: usize = results.iter().map(|r| r.updated.len()).sum();
+    let total_removed: usize = results.iter().map(|r| r.removed.len()).sum();
+    let total_errors: usize = results.iter().map(|r| r.errors.len()).sum();
+
+    println!(
+        "Summary: {} created, {} updated, {} removed, {} errors",
+        total_created, total_updated, total_removed, total_errors
+    );
+
+    Ok(())
+}
+
+/// Replace native folders that shadow managed skills, asking about ones that differ
+fn adopt_native_folders(
+    manager: &mut SkillManager,
+    target: Option<&str>,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{self, BufRead, Write};
+
+    let candidates: Vec<_> = manager
+        .adoptable_skills()
+        .into_iter()
+        .filter(|c| target.is_none_or(|id| c.target_id == id))
+        .collect();
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    if dry_run {
+        for candidate in &candidates {
+            let note = if candidate.conflict.is_some() {
+                " (differs from the central copy, would ask)"
+            } else {
+                ""
+            };
+            println!(
+                "Would adopt '{}' in {}{}",
+                candidate.skill, candidate.target_name, note
+            );
+        }
+        println!();
+        return Ok(());
+    }
+
+    let mut selections = Vec::new();
+    for candidate in candidates {
+        let resolution = if candidate.conflict.is_none() {
+            ConflictResolution::Import
+        } else {
+            eprint!(
+                "'{}' in {} differs from the central copy. Overwrite the central copy with it? [y/N] ",
+                candidate.skill, candidate.target_name
+            );
+            io::stderr().flush()?;
+            match io::stdin().lock().lines().next() {
+                Some(Ok(line)) if matches!(line.trim().to_lowercase().as_str(), "y" | "yes") => {
+                    ConflictResolution::Overwrite
+                }
+                _ => ConflictResolution::Skip,
+            }
+        };
+        selections.push(AdoptSelection {
+            target_id: candidate.target_id,
+            skill: candidate.skill,
+            resolution,
+        });
+    }
+
+    for result in manager.adopt(&selections) {
         println!("Target: {} ({})", result.target_name, result.target_id);
+        for error in &result.errors {
+            let skill_info = error.skill.as_deref().unwrap_or("general");
+            println!("  ✗ Error: {} - {}", skill_info, error.message);
+        }
+        for (skill, backup) in &result.backups {
+            let overwritten = if result.overwritten.contains(skill) {
+                ", central copy overwritten"
+            } else {
+                ""
+            };
+            println!(
+                "  ⇄ Adopted: {} (native folder kept in {}{})",
+                skill,
+                backup.display(),
+                overwritten
+            );
+        }
+        if !result.skipped.is_empty() {
+            println!("  · Kept native: {}", result.skipped.join(", "));
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Watch for changes and resync until interrupted
+fn cmd_watch(config: Option<&Path>, debounce_ms: u64) -> Result<(), Box<dyn std::error::Error>> {
+    let mut manager = SkillManager::open(config)?;
+
+    // Start from a fully synced state
+    manager.validate_all();
+    for result in manager.sync_all().iter().filter(|r| r.has_changes()) {
+        print_sync_result(result, false);
+    }
+
+    let watcher = manager.watch(Duration::from_millis(debounce_ms))?;
+    println!(
+        "Watching {} and {} target(s) for changes (Ctrl+C to stop)",
+        manager.config().skills_dir.display(),
+        watcher.watched_targets()
+    );
+    println!();
+
+    loop {
+        let changes = watcher.next_changes()?;
+        if !changes.skills.is_empty() {
+            let names: Vec<&str> = changes.skills.iter().map(String::as_str).collect();
+            println!("Changed: {}", names.join(", "));
+        }
+
+        // Our own writes into target directories come back as no-op resyncs; keep those quiet
+        for result in manager.apply_changes(&changes) {
+            if result.has_changes()
+                || !result.errors.is_empty()
+                || !result.locally_modified.is_empty()
+            {
+                print_sync_result(&result, false);
+            }
+        }
+    }
+}
+
+/// Exit codes and porcelain format of `agentloom status`
+const STATUS_HELP: &str = "\
+Exit codes (problem codes are added together):
+  0  Everything is fine
+  1  The command itself failed (e.g. unknown target)
+  2  Invalid arguments
+  4  Some skills are invalid
+  8  Some targets are out of sync
+ 16  Config problems, or legacy skills waiting to be migrated
+
+Porcelain lines are `<kind> <target> <name>`, with `-` when no target applies.
+Kinds and the exit code bit they set:
+  invalid                                   4
+  missing, broken, elsewhere, outdated,     8  (fixed by 'agentloom sync' or
+  unexpected, error                             'agentloom repair')
+  shadowed                                  8  (fixed by 'agentloom sync --adopt')
+  config, legacy                           16
+  unmanaged                                 -  (reported only: native folders
+                                                and files AgentLoom leaves alone)
+  modified                                  -  (reported only: copies edited in
+                                                the target, which sync keeps;
+                                                delete them to get updates)";
+
+/// Exit code bit for invalid skills
+const STATUS_INVALID_SKILLS: i32 = 4;
+
+/// Exit code bit for targets out of sync
+const STATUS_OUT_OF_SYNC: i32 = 8;
+
+/// Exit code bit for config problems and legacy content
+const STATUS_CONFIG: i32 = 16;
+
+/// What `agentloom status` found
+#[derive(Default)]
+struct StatusReport {
+    /// Number of skills, or `None` if the config couldn't be loaded
+    total_skills: Option<usize>,
+    /// Invalid skills with their error diagnostics
+    invalid_skills: Vec<(String, Vec<Diagnostic>)>,
+    targets: Vec<TargetReport>,
+    config_problems: Vec<String>,
+    legacy_dir: Option<PathBuf>,
+}
+
+/// Status of a single target
+struct TargetReport {
+    id: String,
+    name: String,
+    enabled: bool,
+    status: SyncStatus,
+    errors: Vec<String>,
+}
+
+impl TargetReport {
+    /// Problems as (porcelain kind, label, names), in display order
+    fn groups(&self) -> [(&'static str, &'static str, &[String]); 7] {
+        let status = &self.status;
+        [
+            ("missing", "missing", &status.missing_skills),
+            ("broken", "broken link", &status.broken_links),
+            ("elsewhere", "points elsewhere", &status.points_elsewhere),
+            ("outdated", "outdated copy", &status.outdated),
+            ("shadowed", "shadowed by native folder", &status.shadowed),
+            ("unexpected", "unexpected", &status.extra_items),
+            ("error", "error", &self.errors),
+        ]
+    }
+
+    fn is_fine(&self) -> bool {
+        !self.enabled || (self.status.is_synced && self.errors.is_empty())
+    }
+}
+
+impl StatusReport {
+    fn exit_code(&self) -> i32 {
+        let mut code = 0;
+        if !self.invalid_skills.is_empty() {
+            code |= STATUS_INVALID_SKILLS;
+        }
+        if self.targets.iter().any(|t| !t.is_fine()) {
+            code |= STATUS_OUT_OF_SYNC;
+        }
+        if !self.config_problems.is_empty() || self.legacy_dir.is_some() {
+            code |= STATUS_CONFIG;
+        }
+        code
+    }
+
+    fn problem_count(&self) -> usize {
+        let target_problems: usize = self
+            .targets
+            .iter()
+            .filter(|t| t.enabled)
+            .flat_map(|t| t.groups())
+            .map(|(_, _, names)| names.len())
+            .sum();
+        self.invalid_skills.len()
+            + target_problems
+            + self.config_problems.len()
+            + usize::from(self.legacy_dir.is_some())
+    }
+}
+
+/// Show whether everything is fine, exiting with a code that says what isn't
+fn cmd_status(
+    config: Option<&Path>,
+    target: Option<String>,
+    json: bool,
+    porcelain: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut report = StatusReport {
+        legacy_dir: legacy_skills_dir().filter(|_| has_legacy_skills()),
+        ..Default::default()
+    };
+
+    // A config that doesn't load is a problem to report, not a reason to stop
+    match SkillManager::open(config) {
+        Ok(mut manager) => {
+            if let Some(target_id) = &target {
+                if !manager.targets().iter().any(|t| t.id() == target_id) {
+                    return Err(format!(
+                        "Target '{}' not found. Run 'agentloom targets' to see available targets.",
+                        target_id
+                    )
+                    .into());
+                }
+            }
+            collect_status(&mut manager, target.as_deref(), &mut report);
+        }
+        Err(e) => report
+            .config_problems
+            .push(format!("Could not load config: {e}")),
+    }
+
+    if json {
+        print_status_json(&report)?;
+    } else if porcelain {
+        print_status_porcelain(&report);
+    } else {
+        print_status(&report);
+    }
+
+    let code = report.exit_code();
+    if code != 0 {
+        std::process::exit(code);
+    }
+    Ok(())
+}
+
+/// Fill in a status report from a loaded manager
+fn collect_status(manager: &mut SkillManager, target: Option<&str>, report: &mut StatusReport) {
+    manager.validate_all();
+    report.total_skills = Some(manager.skills().len());
+    report.invalid_skills = manager
+        .invalid_skills()
+        .map(|s| {
+            let errors = s.diagnostics.iter().filter(|d| d.is_error()).cloned();
+            (s.name().to_string(), errors.collect())
+        })
+        .collect();
+
+    for layer in manager.config().layers() {
+        if layer.is_newer() {
+            report.config_problems.push(format!(
+                "{} uses config version {}, newer than this build understands ({})",
+                layer.origin, layer.version, CONFIG_VERSION
+            ));
+        } else if layer.is_outdated() && layer.origin.layer != ConfigLayer::User {
+            // The user file is upgraded on save; other layers have to be updated by hand
+            report.config_problems.push(format!(
+                "{} uses config version {} and should be updated to {} (see 'agentloom doctor')",
+                layer.origin, layer.version, CONFIG_VERSION
+            ));
+        }
+    }
+
+    for t in manager
+        .targets()
+        .iter()
+        .filter(|t| target.is_none_or(|id| t.id() == id))
+    {
+        let plan = manager.plan_target(t.id());
+        report.targets.push(TargetReport {
+            id: t.id().to_string(),
+            name: t.name().to_string(),
+            enabled: t.enabled,
+            status: manager.target_status(t.id()).unwrap_or_default(),
+            errors: plan
+                .map(|p| p.errors.into_iter().map(|e| e.message).collect())
+                .unwrap_or_default(),
+        });
+    }
+}
+
+/// Print a status report for people, compact like `git status`
+fn print_status(report: &StatusReport) {
+    if let Some(total) = report.total_skills {
+        println!(
+            "Skills: {} total, {} invalid",
+            total,
+            report.invalid_skills.len()
+        );
+        for (name, errors) in &report.invalid_skills {
+            let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            println!("  ✗ {}: {}", name, messages.join("; "));
+        }
+        println!();
+    }
+
+    if !report.targets.is_empty() {
+        println!("Targets:");
+        let width = report.targets.iter().map(|t| t.id.len()).max().unwrap_or(0);
+        for target in &report.targets {
+            let (mark, state) = if !target.enabled {
+                ("○", "disabled")
+            } else if target.is_fine() {
+                ("✓", "in sync")
+            } else {
+                ("✗", "out of sync")
+            };
+            println!("  {mark} {:<width$}  {state}", target.id);
+            if !target.enabled {
+                continue;
+            }
+            for (_, label, names) in target.groups() {
+                if !names.is_empty() {
+                    println!("      {label}: {}", names.join(", "));
+                }
+            }
+            if !target.status.unmanaged.is_empty() {
+                println!(
+                    "      unmanaged (left alone): {}",
+                    target.status.unmanaged.join(", ")
+                );
+            }
+            if !target.status.locally_modified.is_empty() {
+                println!(
+                    "      locally modified (kept by sync): {}",
+                    target.status.locally_modified.join(", ")
+                );
+            }
+        }
+        println!();
+    }
+
+    if !report.config_problems.is_empty() || report.legacy_dir.is_some() {
+        println!("Config:");
+        for problem in &report.config_problems {
+            println!("  ! {problem}");
+        }
+        if let Some(dir) = &report.legacy_dir {
+            println!(
+                "  ! Legacy skills in {} are waiting to be migrated (open the app to move them)",
+                dir.display()
+            );
+        }
+        println!();
+    }
+
+    match report.problem_count() {
+        0 => println!("Everything is in sync."),
+        1 => println!("1 problem found."),
+        n => println!("{n} problems found."),
+    }
+    if report.exit_code() & STATUS_OUT_OF_SYNC != 0 {
+        println!("Run 'agentloom sync' or 'agentloom repair' to fix targets.");
+    }
+}
 
-        if !result.errors.is_empty() {
-            for error in &result.errors {
-                let skill_info = error.skill.as_deref().unwrap_or("general");
-                println!("  ✗ Error: {} - {}", skill_info, error.message);
+/// Print one `<kind> <target> <name>` line per problem
+fn print_status_porcelain(report: &StatusReport) {
+    for (name, _) in &report.invalid_skills {
+        println!("invalid - {name}");
+    }
+    for target in report.targets.iter().filter(|t| t.enabled) {
+        for (kind, _, names) in target.groups() {
+            for name in names {
+                println!("{kind} {} {name}", target.id);
             }
         }
+        for name in &target.status.unmanaged {
+            println!("unmanaged {} {name}", target.id);
+        }
+        for name in &target.status.locally_modified {
+            println!("modified {} {name}", target.id);
+        }
+    }
+    for problem in &report.config_problems {
+        println!("config - {problem}");
+    }
+    if let Some(dir) = &report.legacy_dir {
+        println!("legacy - {}", dir.display());
+    }
+}
 
-        if result.created.is_empty() && result.removed.is_empty() && result.unchanged.is_empty() {
+/// Print a status report as JSON
+fn print_status_json(report: &StatusReport) -> Result<(), Box<dyn std::error::Error>> {
+    let targets: Vec<_> = report
+        .targets
+        .iter()
+        .map(|t| {
+            serde_json::json!({
+                "id": t.id,
+                "name": t.name,
+                "enabled": t.enabled,
+                "in_sync": t.is_fine(),
+                "missing": t.status.missing_skills,
+                "broken_links": t.status.broken_links,
+                "points_elsewhere": t.status.points_elsewhere,
+                "outdated": t.status.outdated,
+                "shadowed": t.status.shadowed,
+                "unexpected": t.status.extra_items,
+                "unmanaged": t.status.unmanaged,
+                "locally_modified": t.status.locally_modified,
+                "errors": t.errors,
+            })
+        })
+        .collect();
+    let invalid: Vec<_> = report
+        .invalid_skills
+        .iter()
+        .map(|(name, errors)| serde_json::json!({ "name": name, "errors": errors }))
+        .collect();
+
+    let output = serde_json::json!({
+        "ok": report.exit_code() == 0,
+        "exit_code": report.exit_code(),
+        "skills": {
+            "total": report.total_skills,
+            "invalid": invalid,
+        },
+        "targets": targets,
+        "config_problems": report.config_problems,
+        "legacy_skills_dir": report.legacy_dir,
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+/// Repair broken links in targets
+fn cmd_repair(
+    config: Option<&Path>,
+    target: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let manager = SkillManager::open(config)?;
+
+    let results: Vec<RepairResult> = if let Some(target_id) = target {
+        match manager.repair_target(&target_id) {
+            Some(result) => vec![result],
+            None => {
+                eprintln!(
+                    "Target '{}' not found. Run 'agentloom targets' to see available targets.",
+                    target_id
+                );
+                return Ok(());
+            }
+        }
+    } else {
+        manager.repair_all()
+    };
+
+    for result in &results {
+        println!("Target: {} ({})", result.target_name, result.target_id);
+
+        for error in &result.errors {
+            let skill_info = error.skill.as_deref().unwrap_or("general");
+            println!("  ✗ Error: {} - {}", skill_info, error.message);
+        }
+        for (link, skill) in &result.relinked {
+            if link == skill {
+                println!("  ~ Relinked: {}", link);
+            } else {
+                println!("  ~ Relinked: {} -> {}", link, skill);
+            }
+        }
+        if !result.removed.is_empty() {
+            println!("  - Removed broken: {}", result.removed.join(", "));
+        }
+        if !result.unmanaged.is_empty() {
+            println!(
+                "  ? Not managed (left alone): {}",
+                result.unmanaged.join(", ")
+            );
+        }
+        if !result.has_changes() && result.errors.is_empty() && result.unmanaged.is_empty() {
+            println!("  No broken links");
+        }
+        println!();
+    }
+
+    let total_relinked: usize = results.iter().map(|r| r.relinked.len()).sum();
+    let total_removed: usize = results.iter().map(|r| r.removed.len()).sum();
+    let total_unmanaged: usize = results.iter().map(|r| r.unmanaged.len()).sum();
+    let total_errors: usize = results.iter().map(|r| r.errors.len()).sum();
+
+    println!(
+        "Summary: {} relinked, {} removed, {} not managed, {} errors",
+        total_relinked, total_removed, total_unmanaged, total_errors
+    );
+
+    Ok(())
+}
+
+/// Print a single sync result
+///
+/// Unchanged skills are only listed when `show_unchanged` is set (less noisy).
+fn print_sync_result(result: &SyncResult, show_unchanged: bool) {
+    println!("Target: {} ({})", result.target_name, result.target_id);
+
+    if result.recovered {
+        println!("  ↺ Rolled back an interrupted earlier sync");
+    }
+    if !result.errors.is_empty() {
+        for error in &result.errors {
+            let skill_info = error.skill.as_deref().unwrap_or("general");
+            println!("  ✗ Error: {} - {}", skill_info, error.message);
+        }
+    }
+    if result.rolled_back {
+        println!("  ↺ Rolled back: the target was left as it was before this sync");
+    }
+
+    if result.created.is_empty()
+        && result.updated.is_empty()
+        && result.removed.is_empty()
+        && result.unchanged.is_empty()
+    {
+        println!("  No skills to sync");
+    } else {
+        if !result.created.is_empty() {
+            println!("  + Created: {}", result.created.join(", "));
+        }
+        if !result.updated.is_empty() {
+            println!("  ~ Updated: {}", result.updated.join(", "));
+        }
+        if !result.removed.is_empty() {
+            println!("  - Removed: {}", result.removed.join(", "));
+        }
+        if !result.unchanged.is_empty() && show_unchanged {
+            println!("  = Unchanged: {}", result.unchanged.join(", "));
+        }
+    }
+    if !result.filtered.is_empty() {
+        println!("  · Filtered out: {}", result.filtered.join(", "));
+    }
+    if !result.locally_modified.is_empty() {
+        println!(
+            "  ! Locally modified (not overwritten): {}",
+            result.locally_modified.join(", ")
+        );
+    }
+    if !result.extra_items.is_empty() {
+        println!(
+            "  ? Not managed (left alone): {}",
+            result.extra_items.join(", ")
+        );
+    }
+    println!();
+}
+
+/// Print what a sync would do without changing anything
+fn print_sync_plans(
+    manager: &SkillManager,
+    target: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let plans: Vec<SyncPlan> = if let Some(target_id) = target {
+        match manager.plan_target(target_id) {
+            Some(plan) => vec![plan],
+            None => {
+                eprintln!(
+                    "Target '{}' not found. Run 'agentloom targets' to see available targets.",
+                    target_id
+                );
+                return Ok(());
+            }
+        }
+    } else {
+        manager.plan_all()
+    };
+
+    for plan in &plans {
+        println!("Target: {} ({})", plan.target_name, plan.target_id);
+
+        for error in &plan.errors {
+            let skill_info = error.skill.as_deref().unwrap_or("general");
+            println!("  ✗ Error: {} - {}", skill_info, error.message);
+        }
+
+        if plan.create_dir {
+            // Section targets point at a file, so it's the enclosing directory that's created
+            let dir = match plan.skills_path.parent() {
+                Some(parent) if plan.strategy.is_section() => parent,
+                _ => &plan.skills_path,
+            };
+            println!("  + Create directory: {}", dir.display());
+        }
+
+        let names = |links: &[PlannedLink]| {
+            links
+                .iter()
+                .map(|l| l.skill.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        if !plan.has_changes() && plan.unchanged.is_empty() && plan.skipped_native.is_empty() {
             println!("  No skills to sync");
         } else {
-            if !result.created.is_empty() {
-                println!("  + Created: {}", result.created.join(", "));
+            if !plan.create.is_empty() {
+                println!("  + Would create: {}", names(&plan.create));
+            }
+            if !plan.broken.is_empty() {
+                println!("  ~ Would relink (broken): {}", names(&plan.broken));
+            }
+            if !plan.update.is_empty() {
+                println!("  ~ Would update copy: {}", names(&plan.update));
+            }
+            if !plan.remove.is_empty() {
+                println!("  - Would remove: {}", plan.remove.join(", "));
             }
-            if !result.removed.is_empty() {
-                println!("  - Removed: {}", result.removed.join(", "));
+            if !plan.skipped_native.is_empty() {
+                println!("  ! Skipped (native): {}", plan.skipped_native.join(", "));
             }
-            if !result.unchanged.is_empty() && results.len() == 1 {
-                // Only show unchanged for single-target sync (less noisy)
-                println!("  = Unchanged: {}", result.unchanged.join(", "));
+            if !plan.unchanged.is_empty() && plans.len() == 1 {
+                // Only show unchanged for single-target plans (less noisy)
+                println!("  = Unchanged: {}", plan.unchanged.join(", "));
             }
         }
+        if !plan.filtered.is_empty() {
+            println!("  · Filtered out: {}", plan.filtered.join(", "));
+        }
+        if !plan.locally_modified.is_empty() {
+            println!(
+                "  ! Locally modified (would not overwrite): {}",
+                plan.locally_modified.join(", ")
+            );
+        }
+        if !plan.extra_items.is_empty() {
+            println!(
+                "  ? Not managed (left alone): {}",
+                plan.extra_items.join(", ")
+            );
+        }
         println!();
     }
 
     // Summary
-    let total_created: usize = results.iter().map(|r| r.created.len()).sum();
-    let total_removed: usize = results.iter().map(|r| r.removed.len()).sum();
-    let total_errors: usize = results.iter().map(|r| r.errors.len()).sum();
+    let total_create: usize = plans.iter().map(|p| p.create.len()).sum();
+    let total_broken: usize = plans.iter().map(|p| p.broken.len()).sum();
+    let total_update: usize = plans.iter().map(|p| p.update.len()).sum();
+    let total_remove: usize = plans.iter().map(|p| p.remove.len()).sum();
+    let total_errors: usize = plans.iter().map(|p| p.errors.len()).sum();
 
     println!(
-        "Summary: {} created, {} removed, {} errors",
-        total_created, total_removed, total_errors
+        "Summary: would create {}, relink {}, update {}, remove {}; {} errors",
+        total_create, total_broken, total_update, total_remove, total_errors
     );
 
     Ok(())
 }
 
 /// Show diagnostic information
-fn cmd_doctor() -> Result<(), Box<dyn std::error::Error>> {
+fn cmd_doctor(config: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
     println!("AgentLoom Doctor - Diagnostic Information\n");
 
     // Try to create manager
-    let manager_result = SkillManager::new();
+    let mut manager_result = SkillManager::open(config);
+    if let Ok(manager) = &mut manager_result {
+        manager.probe_versions();
+    }
 
     // Config info
     println!("Configuration:");
@@ -296,6 +1159,12 @@ fn cmd_doctor() -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap_or_else(|_| "unknown".to_string());
             println!("  Skills directory: {}", config.skills_dir.display());
             println!("  Config file: {}", config_path);
+            for layer in config.layers() {
+                println!(
+                    "  Config layer: {}, version {}",
+                    layer.origin, layer.version
+                );
+            }
             println!(
                 "  Validate on sync: {}",
                 if config.preferences.validate_on_sync {
@@ -304,30 +1173,56 @@ fn cmd_doctor() -> Result<(), Box<dyn std::error::Error>> {
                     "disabled"
                 }
             );
+            println!(
+                "  Link style: {}",
+                match config.preferences.link_style {
+                    LinkStyle::Absolute => "absolute",
+                    LinkStyle::Relative => "relative",
+                }
+            );
+            let overrides = config.lint.rules.len()
+                + config.lint.skills.values().map(|r| r.len()).sum::<usize>();
+            println!("  Lint rule overrides: {}", overrides);
+            for rule in config.lint.unknown_rules() {
+                println!("  Warning: lint config sets unknown rule '{}'", rule);
+            }
         }
         Err(e) => {
             println!("  Error loading config: {e}");
         }
     }
+    if let Ok(manager) = &manager_result {
+        print_config_version_issues(manager.config());
+    }
     println!();
 
     // Skills info
     println!("Skills:");
     match &manager_result {
         Ok(_) => {
-            let mut manager = SkillManager::new()?;
+            let mut manager = SkillManager::open(config)?;
             manager.validate_all();
 
             let stats = manager.stats();
             println!("  Total: {}", stats.total_skills);
             println!("  Valid: {}", stats.valid_skills);
+            println!("  With warnings: {}", stats.warning_skills);
             println!("  Invalid: {}", stats.invalid_skills);
+            let total = stats.total_size.total();
+            println!(
+                "  Size: {} lines, {}, ~{} tokens (~{} in frontmatter, always loaded)",
+                total.lines,
+                format_bytes(total.bytes),
+                total.tokens,
+                stats.total_size.frontmatter.tokens
+            );
 
             // List invalid skills if any
             if stats.invalid_skills > 0 {
                 println!("\n  Invalid skills:");
                 for skill in manager.invalid_skills() {
-                    println!("    - {}: {:?}", skill.name(), skill.validation_errors);
+                    println!("    - {}:", skill.name());
+                    print_diagnostics(&skill.diagnostics, Severity::Error, "        ");
                 }
             }
         }
@@ -346,16 +1241,18 @@ fn cmd_doctor() -> Result<(), Box<dyn std::error::Error>> {
                 println!("  No targets detected");
             } else {
                 for target in targets {
-                    let status = if target.enabled {
-                        if target.skills_path.exists() {
-                            "✓ ready"
-                        } else {
-                            "! skills dir missing"
-                        }
+                    let issues = target_issues(target);
+                    let status = if !target.enabled {
+                        "○ disabled".to_string()
+                    } else if issues.is_empty() {
+                        "✓ ready".to_string()
                     } else {
-                        "○ disabled"
+                        format!("! {}", issues.join(", "))
                     };
-                    println!("  {} {} ({})", status, target.name(), target.id());
+                    println!("  {} ({}): {}", target.name(), target.id(), status);
+                    if let Some(binary) = target.binary.as_ref().filter(|b| b.is_found()) {
+                        println!("      {}", describe_binary(binary));
+                    }
                 }
             }
         }
@@ -367,9 +1264,178 @@ fn cmd_doctor() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Show the effective configuration, one value per line
+fn cmd_config_show(config: Option<&Path>, origin: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::load_with(&ConfigSources::discover(config)?)?;
+    let entries = config.entries()?;
+
+    let lines: Vec<_> = entries
+        .iter()
+        .map(|entry| format!("{} = {}", entry.key, entry.value))
+        .collect();
+
+    if !origin {
+        for line in &lines {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+
+    let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    for (line, entry) in lines.iter().zip(&entries) {
+        println!("{line:<width$}  # {}", entry.origin);
+    }
+
+    Ok(())
+}
+
+/// Get the config file that `config get/set/unset/list/edit` work on
+fn config_file(config: Option<&Path>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    match config {
+        Some(path) => Ok(path.to_path_buf()),
+        None => Ok(Config::default_config_path()?),
+    }
+}
+
+/// Print a single config value
+fn cmd_config_get(config: Option<&Path>, key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let path = config_file(config)?;
+    let config = Config::load_from(&path)?;
+
+    match config.get(key)? {
+        // Print strings bare so they can be used in scripts
+        Some(value) => match value.as_str() {
+            Some(s) => println!("{s}"),
+            None => println!("{value}"),
+        },
+        None => return Err(format!("{key} is not set").into()),
+    }
+
+    Ok(())
+}
+
+/// Set a config value and save the file
+fn cmd_config_set(
+    config: Option<&Path>,
+    key: &str,
+    value: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = config_file(config)?;
+    let mut config = Config::load_from(&path)?;
+    config.set(key, value)?;
+    config.save_to(&path)?;
+
+    if let Some(value) = config.get(key)? {
+        println!("✓ {key} = {value}");
+    }
+    Ok(())
+}
+
+/// Remove a config value and save the file
+fn cmd_config_unset(config: Option<&Path>, key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let path = config_file(config)?;
+    let mut config = Config::load_from(&path)?;
+    config.unset(key)?;
+    config.save_to(&path)?;
+
+    println!("✓ Unset {key}");
+    Ok(())
+}
+
+/// List the values set in the config file
+fn cmd_config_list(config: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = config_file(config)?;
+    let config = Config::load_from(&path)?;
+
+    for entry in config.entries()? {
+        if entry.origin.layer != ConfigLayer::Default {
+            println!("{} = {}", entry.key, entry.value);
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the path of the config file
+fn cmd_config_path(config: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", config_file(config)?.display());
+    Ok(())
+}
+
+/// Open the config file in an editor and check it still loads afterwards
+fn cmd_config_edit(config: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = config_file(config)?;
+    if !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, "")?;
+    }
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        });
+
+    // Allow editors with arguments, e.g. "code --wait"
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().ok_or("no editor configured")?;
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(&path)
+        .status()
+        .map_err(|e| format!("failed to run editor '{program}': {e}"))?;
+
+    if !status.success() {
+        return Err(format!("editor '{program}' exited with {status}").into());
+    }
+
+    Config::load_from(&path)
+        .map_err(|e| format!("{e}\nRun 'agentloom config edit' again to fix the file"))?;
+    println!("✓ {} is valid", path.display());
+    Ok(())
+}
+
+/// Explain config layers whose schema version differs from this build's
+fn print_config_version_issues(config: &Config) {
+    for layer in config.layers() {
+        if layer.is_newer() {
+            println!(
+                "  ! {} uses config version {}, but this build only understands up to version {}.",
+                layer.origin, layer.version, CONFIG_VERSION
+            );
+            println!("    It was written by a newer AgentLoom. Settings this build doesn't know");
+            println!("    are kept when saving but have no effect. Upgrade AgentLoom to use them.");
+        } else if layer.is_outdated() {
+            println!(
+                "  ~ {} uses config version {} (current is {}) and was migrated when loaded:",
+                layer.origin, layer.version, CONFIG_VERSION
+            );
+            for migration in &layer.migrations {
+                println!("    - {migration}");
+            }
+            if layer.origin.layer == ConfigLayer::User {
+                println!(
+                    "    The file is upgraded the next time AgentLoom saves it; older AgentLoom"
+                );
+                println!("    builds will then report it as written by a newer version.");
+            } else {
+                println!("    AgentLoom never writes this file. Apply the changes above and set");
+                println!("    config_version = {CONFIG_VERSION} to silence this message.");
+            }
+        }
+    }
+}
+
 /// List detected targets
-fn cmd_targets(json: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let manager = SkillManager::new()?;
+fn cmd_targets(config: Option<&Path>, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut manager = SkillManager::open(config)?;
+    manager.probe_versions();
     let targets = manager.targets();
 
     if json {
@@ -383,6 +1449,9 @@ fn cmd_targets(json: bool) -> Result<(), Box<dyn std::error::Error>> {
                     "enabled": t.enabled,
                     "skills_path": t.skills_path,
                     "exists": t.skills_path.exists(),
+                    "format": t.format,
+                    "config_dir_exists": t.config_dir_exists(),
+                    "binary": t.binary,
                 })
             })
             .collect();
@@ -398,19 +1467,27 @@ fn cmd_targets(json: bool) -> Result<(), Box<dyn std::error::Error>> {
         println!();
 
         for target in targets {
-            let status_icon = if target.enabled {
-                if target.skills_path.exists() {
-                    "✓"
-                } else {
-                    "!"
-                }
-            } else {
+            let issues = target_issues(target);
+            let status_icon = if !target.enabled {
                 "○"
+            } else if issues.is_empty() {
+                "✓"
+            } else {
+                "!"
             };
 
             println!("  {} {}", status_icon, target.name());
             println!("    ID: {}", target.id());
             println!("    Skills: {}", target.skills_path.display());
+            if !target.format.is_skill_folder() {
+                println!("    Format: {}", target.format.id());
+            }
+            if let Some(ref binary) = target.binary {
+                println!("    Binary: {}", describe_binary(binary));
+            }
+            if !issues.is_empty() {
+                println!("    Issues: {}", issues.join(", "));
+            }
             println!(
                 "    Status: {}",
                 if target.enabled {
@@ -426,9 +1503,38 @@ fn cmd_targets(json: bool) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// List what's missing for a target: its binary, config dir or skills dir
+fn target_issues(target: &Target) -> Vec<String> {
+    let mut issues = Vec::new();
+    if let Some(binary) = target.binary.as_ref().filter(|b| !b.is_found()) {
+        issues.push(format!("`{}` not found on PATH", binary.name));
+    }
+    if !target.config_dir_exists() {
+        issues.push("config dir missing".to_string());
+    } else if !target.skills_dir_exists() {
+        issues.push("skills dir missing".to_string());
+    }
+    issues
+}
+
+/// Describe a found executable as "path (version)"
+fn describe_binary(binary: &TargetBinary) -> String {
+    let Some(ref path) = binary.path else {
+        return format!("{} (not found on PATH)", binary.name);
+    };
+    match binary.version {
+        Some(ref version) => format!("{} ({})", path.display(), version),
+        None => format!("{} (version unknown)", path.display()),
+    }
+}
+
 /// Create a new skill
-fn cmd_create(name: &str, description: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let mut manager = SkillManager::new()?;
+fn cmd_create(
+    config: Option<&Path>,
+    name: &str,
+    description: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut manager = SkillManager::open(config)?;
 
     // Check if skill already exists
     if manager.get_skill(name).is_some() {
@@ -445,19 +1551,30 @@ fn cmd_create(name: &str, description: &str) -> Result<(), Box<dyn std::error::E
 }
 
 /// Validate skills
-fn cmd_validate(name: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
-    let mut manager = SkillManager::new()?;
+fn cmd_validate(
+    config: Option<&Path>,
+    name: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut manager = SkillManager::open(config)?;
 
     if let Some(skill_name) = name {
         // Validate specific skill
         match manager.validate_skill(&skill_name) {
             Ok(()) => {
-                println!("✓ Skill '{}' is valid", skill_name);
+                let status = manager.get_skill(&skill_name).map(|s| s.validation_status);
+                if status == Some(ValidationStatus::ValidWithWarnings) {
+                    println!("! Skill '{}' is valid, with warnings", skill_name);
+                } else {
+                    println!("✓ Skill '{}' is valid", skill_name);
+                }
             }
             Err(e) => {
                 println!("✗ Skill '{}' is invalid: {}", skill_name, e);
             }
         }
+        if let Some(skill) = manager.get_skill(&skill_name) {
+            print_diagnostics(&skill.diagnostics, Severity::Info, "    ");
+        }
     } else {
         // Validate all skills
         let results = manager.validate_all();
@@ -466,34 +1583,79 @@ fn cmd_validate(name: Option<String>) -> Result<(), Box<dyn std::error::Error>>
         let invalid_count = results.len() - valid_count;
 
         println!(
-            "Validated {} skills: {} valid, {} invalid\n",
+            "Validated {} skills: {} valid ({} with warnings), {} invalid\n",
             results.len(),
             valid_count,
+            manager.stats().warning_skills,
             invalid_count
         );
 
         for skill in manager.skills() {
-            let icon = match skill.validation_status {
-                ValidationStatus::Valid => "✓",
-                ValidationStatus::Invalid => "✗",
-                ValidationStatus::Unknown => "?",
-            };
-            print!("  {} {}", icon, skill.name());
+            println!(
+                "  {} {}",
+                status_icon(skill.validation_status),
+                skill.name()
+            );
+            print_diagnostics(&skill.diagnostics, Severity::Warning, "      ");
+        }
+    }
 
-            if !skill.validation_errors.is_empty() {
-                println!(" - {}", skill.validation_errors.join(", "));
-            } else {
-                println!();
+    Ok(())
+}
+
+/// Print diagnostics of at least a severity, one per line, e.g. `error[name-format] SKILL.md:2: ...`
+fn print_diagnostics(diagnostics: &[Diagnostic], min_severity: Severity, indent: &str) {
+    for diagnostic in diagnostics.iter().filter(|d| d.severity >= min_severity) {
+        let location = diagnostic
+            .location
+            .as_ref()
+            .map(|l| format!(" {l}:"))
+            .unwrap_or_default();
+        println!(
+            "{indent}{}[{}]{location} {diagnostic}",
+            diagnostic.severity, diagnostic.rule
+        );
+    }
+}
+
+/// List validation rules with their default and configured levels
+fn cmd_validate_rules(config: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::load_with(&ConfigSources::discover(config)?)?;
+
+    println!("Validation rules:");
+    for rule in RULES {
+        let level = match config.lint.rules.get(rule.id) {
+            Some(level) => level.to_string(),
+            None => rule.severity.to_string(),
+        };
+        println!("  {:<22} {:<8} {}", rule.id, level, rule.description);
+    }
+
+    if !config.lint.skills.is_empty() {
+        println!("\nPer-skill levels:");
+        for (skill, rules) in &config.lint.skills {
+            for (rule, level) in rules {
+                println!("  {skill}: {rule} = {level}");
             }
         }
     }
+    for rule in config.lint.unknown_rules() {
+        println!("\nWarning: config sets unknown rule '{rule}'");
+    }
+    println!(
+        "\nChange a level with 'agentloom config set lint.rules.<rule> <off|info|warning|error>'."
+    );
 
     Ok(())
 }
 
 /// Delete a skill
-fn cmd_delete(name: &str, force: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let mut manager = SkillManager::new()?;
+fn cmd_delete(
+    config: Option<&Path>,
+    name: &str,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut manager = SkillManager::open(config)?;
 
     // Check if skill exists
     if manager.get_skill(name).is_none() {
@@ -525,12 +1687,13 @@ fn cmd_delete(name: &str, force: bool) -> Result<(), Box<dyn std::error::Error>>
 
 /// Import skills from detected targets
 fn cmd_import(
+    config: Option<&Path>,
     all: bool,
     overwrite: bool,
     json: bool,
     dry_run: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let manager = SkillManager::new()?;
+    let manager = SkillManager::open(config)?;
     let importer = Importer::from_config(manager.config());
 
     // Discover importable skills
@@ -659,7 +1822,7 @@ fn cmd_import(
             // Sync after import
             if !result.imported.is_empty() {
                 println!("\nSyncing imported skills to targets...");
-                let mut manager = SkillManager::new()?;
+                let mut manager = SkillManager::open(config)?;
                 manager.validate_all();
                 let sync_results = manager.sync_all();
                 let total_synced: usize = sync_results.iter().map(|r| r.created.len()).sum();
@@ -743,7 +1906,7 @@ fn cmd_import(
     // Sync after import
     if !result.imported.is_empty() {
         println!("\nSyncing imported skills to targets...");
-        let mut manager = SkillManager::new()?;
+        let mut manager = SkillManager::open(config)?;
         manager.validate_all();
         let sync_results = manager.sync_all();
         let total_synced: usize = sync_results.iter().map(|r| r.created.len()).sum();
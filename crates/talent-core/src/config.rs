@@ -4,16 +4,84 @@
 //! - Skills storage directory location
 //! - Target CLI configurations (auto-detected and manual)
 //! - User preferences
+//!
+//! Configuration is resolved from layers, each overriding the previous one:
+//! built-in defaults, the system file, the user file (`~/.agents/config.toml`),
+//! the project's `agentloom.toml`, `AGENTLOOM_*` environment variables and
+//! finally a file passed with `--config`. [`Config`] remembers which layer
+//! supplied each value, and saving only writes values changed at runtime back
+//! to the user file so lower and higher layers never leak into it.
+//!
+//! Config files carry a `config_version`. Older files are migrated when read
+//! (see [`migrate_config`]) and keys this build doesn't know are kept on save.
 
 use crate::error::{Error, Result};
+use crate::filter::SkillFilter;
+use crate::kinds::KindDefinition;
+use crate::lint::LintConfig;
+use crate::migration::{migrate_config, CONFIG_VERSION};
+use crate::project::{find_repo_root, PROJECT_CONFIG_FILE_NAME};
+use crate::render::SectionStyle;
+use crate::syncer::{LinkStyle, SyncStrategy};
+use crate::target::{TargetFormat, TargetKind};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
+use toml_edit::{Item, TableLike};
 
 /// Default name for the config file
 const CONFIG_FILE_NAME: &str = "config.toml";
 
+/// Prefix of environment variables that override config values
+///
+/// `AGENTLOOM_SKILLS_DIR` sets `skills_dir`; a double underscore separates
+/// sections, so `AGENTLOOM_PREFERENCES__VALIDATE_ON_SYNC` sets
+/// `preferences.validate_on_sync`.
+pub const ENV_PREFIX: &str = "AGENTLOOM_";
+
+/// Keys of a project's `agentloom.toml` that describe the project itself
+/// rather than overriding the application configuration
+const PROJECT_ONLY_KEYS: &[&str] = &["skills_dir", "targets"];
+
+/// Keys that can be read and written by path (`*` matches any target id)
+const CONFIG_KEYS: &[&str] = &[
+    "skills_dir",
+    "preferences.validate_on_sync",
+    "preferences.link_style",
+    "targets.*.enabled",
+    "targets.*.skills_path",
+    "targets.*.kind",
+    "targets.*.display_name",
+    "targets.*.custom",
+    "targets.*.strategy",
+    "targets.*.format",
+    "targets.*.section_style",
+    "targets.*.include",
+    "targets.*.exclude",
+    "targets.*.tags",
+    "targets.*.exclude_tags",
+    "targets.*.known_tools",
+    "kinds.*.display_name",
+    "kinds.*.config_dir",
+    "kinds.*.config_dir.default",
+    "kinds.*.config_dir.windows",
+    "kinds.*.config_dir.macos",
+    "kinds.*.config_dir.linux",
+    "kinds.*.skills_subdir",
+    "kinds.*.project_dir",
+    "kinds.*.binary",
+    "kinds.*.version_args",
+    "kinds.*.detect",
+    "lint.rules.*",
+    "lint.skills.*.*",
+    "lint.budgets.frontmatter_tokens",
+    "lint.budgets.body_tokens",
+    "lint.budgets.body_lines",
+    "lint.budgets.references_tokens",
+];
+
 /// Default name for the skills directory
 const DEFAULT_SKILLS_DIR: &str = "skills";
 
@@ -21,6 +89,9 @@ const DEFAULT_SKILLS_DIR: &str = "skills";
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
+    /// Schema version of the config file (see [`CONFIG_VERSION`])
+    pub config_version: u32,
+
     /// Directory where skills are stored (default: ~/.agents/skills/)
     pub skills_dir: PathBuf,
 
@@ -29,17 +100,262 @@ pub struct Config {
 
     /// User preferences
     pub preferences: Preferences,
+
+    /// Declared target kinds (key = kind id), merged with the built-in ones
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub kinds: BTreeMap<String, KindDefinition>,
+
+    /// Validation rule levels (see [`crate::lint`])
+    #[serde(skip_serializing_if = "is_default")]
+    pub lint: LintConfig,
+
+    /// Which layer supplied each value (key = dotted key path)
+    #[serde(skip)]
+    pub(crate) origins: BTreeMap<String, ConfigOrigin>,
+
+    /// Layers that were read, lowest precedence first
+    #[serde(skip)]
+    pub(crate) layers: Vec<ConfigLayerInfo>,
+
+    /// Effective values at load time, used to save only what changed since
+    #[serde(skip)]
+    pub(crate) loaded: Option<toml::Table>,
+
+    /// Keys unset since loading, removed from the file on save
+    #[serde(skip)]
+    pub(crate) removed: BTreeSet<String>,
+}
+
+/// A configuration layer, from lowest to highest precedence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigLayer {
+    /// Built-in defaults
+    Default,
+    /// System-wide file shared by all users of the machine
+    System,
+    /// The user's `~/.agents/config.toml`
+    User,
+    /// The current project's `agentloom.toml`
+    Project,
+    /// `AGENTLOOM_*` environment variables
+    Env,
+    /// File passed with `--config`
+    Cli,
+}
+
+impl ConfigLayer {
+    /// Get the display name for this layer
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ConfigLayer::Default => "default",
+            ConfigLayer::System => "system",
+            ConfigLayer::User => "user",
+            ConfigLayer::Project => "project",
+            ConfigLayer::Env => "env",
+            ConfigLayer::Cli => "cli",
+        }
+    }
+}
+
+/// Where a configuration value came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigOrigin {
+    /// Layer that supplied the value
+    pub layer: ConfigLayer,
+
+    /// File path or environment variable name within the layer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl ConfigOrigin {
+    fn new(layer: ConfigLayer, source: Option<String>) -> Self {
+        Self { layer, source }
+    }
+
+    fn file(layer: ConfigLayer, path: &Path) -> Self {
+        Self::new(layer, Some(path.display().to_string()))
+    }
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "{} ({})", self.layer.display_name(), source),
+            None => f.write_str(self.layer.display_name()),
+        }
+    }
+}
+
+/// A layer that was read while loading
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigLayerInfo {
+    /// Where the layer came from
+    pub origin: ConfigOrigin,
+
+    /// Schema version the layer declared before migrating
+    pub version: u32,
+
+    /// Migrations applied in memory while reading (the file itself is only
+    /// upgraded when it is saved)
+    pub migrations: Vec<String>,
+}
+
+impl ConfigLayerInfo {
+    /// Check if the layer was written by a newer version than this build understands
+    pub fn is_newer(&self) -> bool {
+        self.version > CONFIG_VERSION
+    }
+
+    /// Check if the layer uses an older schema that was migrated on read
+    pub fn is_outdated(&self) -> bool {
+        self.version < CONFIG_VERSION
+    }
+}
+
+/// A single effective config value and where it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigEntry {
+    /// Dotted key path (e.g. `preferences.validate_on_sync`)
+    pub key: String,
+
+    /// Effective value
+    pub value: toml::Value,
+
+    /// Layer that supplied the value
+    pub origin: ConfigOrigin,
+}
+
+/// Where each configuration layer is read from
+///
+/// Missing system, user and project files are skipped; a missing `cli` file
+/// is an error since it was asked for explicitly.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    /// System-wide config file
+    pub system: Option<PathBuf>,
+
+    /// User config file (also where changes are saved)
+    pub user: Option<PathBuf>,
+
+    /// Project `agentloom.toml`
+    pub project: Option<PathBuf>,
+
+    /// `AGENTLOOM_*` environment variables (name, value)
+    pub env: Vec<(String, String)>,
+
+    /// File passed with `--config`
+    pub cli: Option<PathBuf>,
+}
+
+impl ConfigSources {
+    /// Standard locations for the current process
+    ///
+    /// The project file is looked up at the root of the git work tree
+    /// containing the current directory.
+    pub fn discover(cli: Option<&Path>) -> Result<Self> {
+        let cwd = std::env::current_dir().map_err(|e| Error::io(".", e))?;
+
+        Ok(Self {
+            system: system_config_path(),
+            user: Some(Config::default_config_path()?),
+            project: find_repo_root(&cwd).map(|root| root.join(PROJECT_CONFIG_FILE_NAME)),
+            env: std::env::vars()
+                .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+                .collect(),
+            cli: cli.map(Path::to_path_buf),
+        })
+    }
+
+    /// Read every present layer, lowest precedence first
+    fn read_layers(&self) -> Result<Vec<(ConfigLayerInfo, toml::Table)>> {
+        let mut layers = Vec::new();
+
+        if let Some(path) = self.system.as_deref().filter(|p| p.exists()) {
+            layers.push(read_layer(ConfigLayer::System, path)?);
+        }
+
+        if let Some(path) = self.user.as_deref().filter(|p| p.exists()) {
+            layers.push(read_layer(ConfigLayer::User, path)?);
+        }
+
+        if let Some(path) = self.project.as_deref().filter(|p| p.exists()) {
+            let (info, mut table) = read_layer(ConfigLayer::Project, path)?;
+            table.retain(|key, _| !PROJECT_ONLY_KEYS.contains(&key));
+            layers.push((info, table));
+        }
+
+        for (name, value) in &self.env {
+            let Some(key) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let table = env_table(name, key, value)?;
+            let info = ConfigLayerInfo {
+                origin: ConfigOrigin::new(ConfigLayer::Env, Some(name.clone())),
+                version: CONFIG_VERSION,
+                migrations: Vec::new(),
+            };
+            layers.push((info, table));
+        }
+
+        if let Some(path) = self.cli.as_deref() {
+            if !path.exists() {
+                return Err(Error::ConfigLoad {
+                    path: path.to_path_buf(),
+                    source: "file not found".into(),
+                });
+            }
+            layers.push(read_layer(ConfigLayer::Cli, path)?);
+        }
+
+        Ok(layers)
+    }
 }
 
 /// Configuration for a target CLI tool
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TargetConfig {
     /// Whether this target is enabled for syncing
+    #[serde(default = "default_enabled")]
     pub enabled: bool,
 
     /// Path to the target's skills directory
     /// If None, will attempt auto-detection
     pub skills_path: Option<PathBuf>,
+
+    /// Known tool kind for this target (if None, derived from the target id)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<TargetKind>,
+
+    /// Display name for custom targets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Whether this is a custom folder target rather than a known tool
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub custom: bool,
+
+    /// How skills are placed into this target (symlink, hardlink-tree, copy or section)
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub strategy: SyncStrategy,
+
+    /// File layout the tool reads (skill-folder, cursor-rule, gemini-command or agents-md)
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub format: TargetFormat,
+
+    /// For section targets, whether skills are listed with their paths or inlined (list or inline)
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub section_style: SectionStyle,
+
+    /// Which skills this target receives (include/exclude globs and tags)
+    #[serde(flatten)]
+    pub filter: SkillFilter,
+
+    /// Tool names the target knows, checked against skills' `allowed-tools`
+    /// (a trailing `*` matches a prefix; empty = not checked)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_tools: Vec<String>,
 }
 
 /// User preferences
@@ -48,14 +364,24 @@ pub struct TargetConfig {
 pub struct Preferences {
     /// Whether to validate skills before syncing
     pub validate_on_sync: bool,
+
+    /// Whether new links use absolute or relative paths
+    pub link_style: LinkStyle,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            config_version: CONFIG_VERSION,
             skills_dir: Self::default_skills_dir(),
             targets: HashMap::new(),
             preferences: Preferences::default(),
+            kinds: BTreeMap::new(),
+            lint: LintConfig::default(),
+            origins: BTreeMap::new(),
+            layers: Vec::new(),
+            loaded: None,
+            removed: BTreeSet::new(),
         }
     }
 }
@@ -65,6 +391,14 @@ impl Default for TargetConfig {
         Self {
             enabled: true,
             skills_path: None,
+            kind: None,
+            display_name: None,
+            custom: false,
+            strategy: SyncStrategy::default(),
+            format: TargetFormat::default(),
+            section_style: SectionStyle::default(),
+            filter: SkillFilter::default(),
+            known_tools: Vec::new(),
         }
     }
 }
@@ -73,29 +407,152 @@ impl Default for Preferences {
     fn default() -> Self {
         Self {
             validate_on_sync: true,
+            link_style: LinkStyle::default(),
         }
     }
 }
 
+fn default_enabled() -> bool {
+    true
+}
+
+/// Check if a value equals its default, so serde can leave it out of config files
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
 impl Config {
-    /// Load configuration from the default location (~/.agents/config.toml)
+    /// Load configuration from all standard layers
     pub fn load() -> Result<Self> {
-        let config_path = Self::default_config_path()?;
-        Self::load_from(&config_path)
+        Self::load_with(&ConfigSources::discover(None)?)
     }
 
-    /// Load configuration from a specific file path
+    /// Load configuration from a specific file path (as the only layer besides defaults)
     pub fn load_from(path: &Path) -> Result<Self> {
-        if !path.exists() {
-            return Ok(Self::default());
+        Self::load_with(&ConfigSources {
+            user: Some(path.to_path_buf()),
+            ..Default::default()
+        })
+    }
+
+    /// Load configuration by merging the given layers over the defaults
+    pub fn load_with(sources: &ConfigSources) -> Result<Self> {
+        let mut merged = toml::Table::try_from(Self::default())?;
+        let mut origins = BTreeMap::new();
+        record_origins(
+            &merged,
+            "",
+            &ConfigOrigin::new(ConfigLayer::Default, None),
+            &mut origins,
+        );
+
+        let layers = sources.read_layers()?;
+        for (info, table) in &layers {
+            merge_tables(&mut merged, table, "", &info.origin, &mut origins);
         }
 
-        let contents = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        let mut config: Self = toml::Value::Table(merged).try_into()?;
+        config.loaded = Some(toml::Table::try_from(&config)?);
+        config.origins = origins;
+        config.layers = layers.into_iter().map(|(info, _)| info).collect();
+        Ok(config)
+    }
 
-        toml::from_str(&contents).map_err(|e| Error::ConfigLoad {
-            path: path.to_path_buf(),
-            source: Box::new(e),
+    /// Get the layer that supplied a value (dotted key path)
+    pub fn origin(&self, key: &str) -> Option<&ConfigOrigin> {
+        self.origins.get(key)
+    }
+
+    /// Layers that were read when loading, lowest precedence first
+    pub fn layers(&self) -> &[ConfigLayerInfo] {
+        &self.layers
+    }
+
+    /// All effective values with their origins, sorted by key
+    ///
+    /// Values that no layer supplied (e.g. set at runtime) are reported as defaults.
+    pub fn entries(&self) -> Result<Vec<ConfigEntry>> {
+        let table = toml::Table::try_from(self)?;
+        let mut values = Vec::new();
+        flatten_table(&table, "", &mut values);
+
+        Ok(values
+            .into_iter()
+            .map(|(key, value)| {
+                let origin = self
+                    .origins
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or_else(|| ConfigOrigin::new(ConfigLayer::Default, None));
+                ConfigEntry { key, value, origin }
+            })
+            .collect())
+    }
+
+    /// Get a value by dotted key path (e.g. `targets.codex.skills_path`)
+    ///
+    /// Returns `None` for values that aren't set. Table keys such as
+    /// `targets.codex` return the whole table.
+    pub fn get(&self, key: &str) -> Result<Option<toml::Value>> {
+        let parts = parse_key(key, true)?;
+        let table = toml::Table::try_from(self)?;
+        Ok(lookup(&table, &parts).cloned())
+    }
+
+    /// Set a value by dotted key path, checking it has the right type
+    ///
+    /// The value is read as a TOML literal (`false`, `["a", "b"]`) and falls
+    /// back to a plain string, so paths and names don't need quoting.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        let parts = parse_key(key, false)?;
+        let table = toml::Table::try_from(&*self)?;
+
+        let updated = parse_value(value, |candidate| {
+            let mut table = table.clone();
+            insert_at(&mut table, &parts, candidate);
+            toml::Value::Table(table).try_into::<Config>()
         })
+        .map_err(|e| Error::InvalidConfigValue {
+            key: key.to_string(),
+            message: e.message().to_string(),
+        })?;
+
+        self.replace_values(updated);
+        // Setting a value below an unset table brings the table back
+        self.removed
+            .retain(|k| k != key && !key.starts_with(&format!("{k}.")));
+        Ok(())
+    }
+
+    /// Remove a value or table by dotted key path
+    ///
+    /// The value falls back to its default here; on save the key is removed
+    /// from the file so lower layers apply again.
+    pub fn unset(&mut self, key: &str) -> Result<()> {
+        let parts = parse_key(key, true)?;
+        let mut table = toml::Table::try_from(&*self)?;
+        remove_at(&mut table, &parts);
+
+        let updated: Config =
+            toml::Value::Table(table)
+                .try_into()
+                .map_err(|e: toml::de::Error| Error::InvalidConfigValue {
+                    key: key.to_string(),
+                    message: e.message().to_string(),
+                })?;
+
+        self.replace_values(updated);
+        self.removed.insert(key.to_string());
+        Ok(())
+    }
+
+    /// Take the values of `values`, keeping this config's load bookkeeping
+    fn replace_values(&mut self, mut values: Config) {
+        values.origins = std::mem::take(&mut self.origins);
+        values.layers = std::mem::take(&mut self.layers);
+        values.loaded = self.loaded.take();
+        values.removed = std::mem::take(&mut self.removed);
+        *self = values;
     }
 
     /// Save configuration to the default location
@@ -105,13 +562,41 @@ impl Config {
     }
 
     /// Save configuration to a specific file path
+    ///
+    /// Only values changed since loading are written (for a config that
+    /// wasn't loaded, values that differ from the file's). The rest of the file
+    /// is kept as is: unknown keys, comments and formatting. An older file is
+    /// upgraded to the current schema version on the way.
     pub fn save_to(&self, path: &Path) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent).map_err(|e| Error::create_dir(parent, e))?;
         }
 
-        let contents = toml::to_string_pretty(self).map_err(Error::TomlSerialize)?;
+        let mut document = read_document(path)?;
+        migrate_config(&mut document);
+
+        let file_values;
+        let before = match &self.loaded {
+            Some(loaded) => loaded,
+            None => {
+                let config: Config =
+                    toml::from_str(&document.to_string()).map_err(|e| Error::ConfigLoad {
+                        path: path.to_path_buf(),
+                        source: Box::new(e),
+                    })?;
+                file_values = toml::Table::try_from(config)?;
+                &file_values
+            }
+        };
+
+        let current = toml::Table::try_from(self)?;
+        apply_changes(document.as_table_mut(), before, &current);
+        for key in &self.removed {
+            let parts: Vec<&str> = key.split('.').collect();
+            remove_from_document(document.as_table_mut(), &parts);
+        }
+        let contents = document.to_string();
 
         fs::write(path, contents).map_err(|e| Error::ConfigSave {
             path: path.to_path_buf(),
@@ -164,6 +649,352 @@ impl Config {
     }
 }
 
+/// Get the system-wide config file path
+///
+/// `/etc/agentloom/config.toml` on Unix, `%ProgramData%\agentloom\config.toml` on Windows.
+pub fn system_config_path() -> Option<PathBuf> {
+    #[cfg(unix)]
+    {
+        Some(PathBuf::from("/etc/agentloom").join(CONFIG_FILE_NAME))
+    }
+
+    #[cfg(windows)]
+    {
+        std::env::var_os("ProgramData")
+            .map(|d| PathBuf::from(d).join("agentloom").join(CONFIG_FILE_NAME))
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        None
+    }
+}
+
+/// Read a config file into a table
+///
+/// The file is migrated to the current schema (in memory only) and checked
+/// to have the shape of a config.
+fn read_layer(layer: ConfigLayer, path: &Path) -> Result<(ConfigLayerInfo, toml::Table)> {
+    let mut document = read_document(path)?;
+    let migration = migrate_config(&mut document);
+
+    let table: toml::Table =
+        toml::from_str(&document.to_string()).map_err(|e| Error::ConfigLoad {
+            path: path.to_path_buf(),
+            source: Box::new(e),
+        })?;
+
+    // Report type errors against the file they're in rather than the merged result
+    toml::Value::Table(table.clone())
+        .try_into::<Config>()
+        .map_err(|e| Error::ConfigLoad {
+            path: path.to_path_buf(),
+            source: Box::new(e),
+        })?;
+
+    let info = ConfigLayerInfo {
+        origin: ConfigOrigin::file(layer, path),
+        version: migration.from_version,
+        migrations: migration.applied.iter().map(|m| m.to_string()).collect(),
+    };
+    Ok((info, table))
+}
+
+/// Turn an `AGENTLOOM_*` variable into a single-value table
+///
+/// Values are read as TOML literals (`true`, `42`, `["a"]`) and fall back to
+/// plain strings, so paths don't need quoting.
+fn env_table(name: &str, key: &str, value: &str) -> Result<toml::Table> {
+    let path: Vec<String> = key.split("__").map(|part| part.to_lowercase()).collect();
+
+    parse_value(value, |candidate| {
+        let table = nest_value(&path, candidate);
+        toml::Value::Table(table.clone()).try_into::<Config>()?;
+        Ok(table)
+    })
+    .map_err(|e| Error::ConfigEnv {
+        var: name.to_string(),
+        message: e.message().to_string(),
+    })
+}
+
+/// Interpret a raw string value, trying it as a TOML literal first and then as a plain string
+///
+/// `attempt` checks a candidate; the first one it accepts wins.
+fn parse_value<T>(
+    raw: &str,
+    mut attempt: impl FnMut(toml::Value) -> std::result::Result<T, toml::de::Error>,
+) -> std::result::Result<T, toml::de::Error> {
+    let literal = toml::from_str::<toml::Table>(&format!("value = {raw}"))
+        .ok()
+        .and_then(|mut t| t.remove("value"));
+
+    if let Some(literal) = literal {
+        if let Ok(result) = attempt(literal) {
+            return Ok(result);
+        }
+    }
+    attempt(toml::Value::String(raw.to_string()))
+}
+
+/// Split a dotted key path, checking it names a known value
+///
+/// With `allow_table`, keys naming a table of known values (such as
+/// `targets.codex`) are accepted too.
+fn parse_key(key: &str, allow_table: bool) -> Result<Vec<&str>> {
+    let parts: Vec<&str> = key.split('.').collect();
+
+    let known = !parts.iter().any(|p| p.is_empty())
+        && CONFIG_KEYS.iter().any(|pattern| {
+            let pattern: Vec<&str> = pattern.split('.').collect();
+            let length_matches = if allow_table {
+                parts.len() <= pattern.len()
+            } else {
+                parts.len() == pattern.len()
+            };
+            length_matches
+                && parts
+                    .iter()
+                    .zip(&pattern)
+                    .all(|(part, expected)| *expected == "*" || part == expected)
+        });
+
+    if known {
+        Ok(parts)
+    } else {
+        Err(Error::UnknownConfigKey(key.to_string()))
+    }
+}
+
+/// Find the value at a key path
+fn lookup<'a>(table: &'a toml::Table, path: &[&str]) -> Option<&'a toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for part in parents {
+        table = table.get(*part)?.as_table()?;
+    }
+    table.get(*last)
+}
+
+/// Put a value at a key path, creating (or replacing non-table values with) tables on the way
+fn insert_at(table: &mut toml::Table, path: &[&str], value: toml::Value) {
+    match path {
+        [] => {}
+        [last] => {
+            table.insert(last.to_string(), value);
+        }
+        [first, rest @ ..] => {
+            let entry = table
+                .entry(first.to_string())
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            match entry.as_table_mut() {
+                Some(inner) => insert_at(inner, rest, value),
+                None => {
+                    let mut inner = toml::Table::new();
+                    insert_at(&mut inner, rest, value);
+                    *entry = toml::Value::Table(inner);
+                }
+            }
+        }
+    }
+}
+
+/// Remove the value at a key path
+fn remove_at(table: &mut toml::Table, path: &[&str]) {
+    match path {
+        [] => {}
+        [last] => {
+            table.remove(*last);
+        }
+        [first, rest @ ..] => {
+            if let Some(inner) = table.get_mut(*first).and_then(toml::Value::as_table_mut) {
+                remove_at(inner, rest);
+            }
+        }
+    }
+}
+
+/// Remove the item at a key path from a TOML document
+///
+/// Tables left empty by the removal are removed too.
+fn remove_from_document(table: &mut dyn TableLike, path: &[&str]) {
+    match path {
+        [] => {}
+        [last] => {
+            table.remove(last);
+        }
+        [first, rest @ ..] => {
+            if let Some(inner) = table.get_mut(first).and_then(Item::as_table_like_mut) {
+                remove_from_document(inner, rest);
+                if inner.is_empty() {
+                    table.remove(first);
+                }
+            }
+        }
+    }
+}
+
+/// Read a config file as an editable document (empty if it doesn't exist)
+fn read_document(path: &Path) -> Result<toml_edit::Document> {
+    if !path.exists() {
+        return Ok(toml_edit::Document::new());
+    }
+
+    let contents = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+    contents
+        .parse::<toml_edit::Document>()
+        .map_err(|e| Error::ConfigLoad {
+            path: path.to_path_buf(),
+            source: Box::new(e),
+        })
+}
+
+/// Build a table holding `value` at a key path
+fn nest_value(path: &[String], value: toml::Value) -> toml::Table {
+    let mut value = value;
+    for part in path.iter().skip(1).rev() {
+        let mut table = toml::Table::new();
+        table.insert(part.clone(), value);
+        value = toml::Value::Table(table);
+    }
+
+    let mut table = toml::Table::new();
+    if let Some(first) = path.first() {
+        table.insert(first.clone(), value);
+    }
+    table
+}
+
+/// Join a dotted key path
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+/// Record `origin` for every leaf value in a table
+fn record_origins(
+    table: &toml::Table,
+    prefix: &str,
+    origin: &ConfigOrigin,
+    origins: &mut BTreeMap<String, ConfigOrigin>,
+) {
+    for (key, value) in table {
+        let key = join_key(prefix, key);
+        match value {
+            toml::Value::Table(inner) => record_origins(inner, &key, origin, origins),
+            _ => {
+                origins.insert(key, origin.clone());
+            }
+        }
+    }
+}
+
+/// Merge `layer` into `base`: tables merge key by key, anything else replaces
+fn merge_tables(
+    base: &mut toml::Table,
+    layer: &toml::Table,
+    prefix: &str,
+    origin: &ConfigOrigin,
+    origins: &mut BTreeMap<String, ConfigOrigin>,
+) {
+    for (key, value) in layer {
+        let path = join_key(prefix, key);
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(inner)) => {
+                merge_tables(existing, inner, &path, origin, origins);
+            }
+            _ => {
+                // Anything previously recorded below this key is replaced
+                origins.retain(|k, _| !k.starts_with(&format!("{path}.")));
+                match value {
+                    toml::Value::Table(inner) => record_origins(inner, &path, origin, origins),
+                    _ => {
+                        origins.insert(path, origin.clone());
+                    }
+                }
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Collect every leaf value of a table under its dotted key path
+fn flatten_table(table: &toml::Table, prefix: &str, out: &mut Vec<(String, toml::Value)>) {
+    for (key, value) in table {
+        let key = join_key(prefix, key);
+        match value {
+            toml::Value::Table(inner) => flatten_table(inner, &key, out),
+            _ => out.push((key, value.clone())),
+        }
+    }
+}
+
+/// Apply the differences between `before` and `after` to `target`
+///
+/// Values that are unchanged are left as `target` has them; changed and new
+/// values are written and removed keys are removed.
+fn apply_changes(target: &mut dyn TableLike, before: &toml::Table, after: &toml::Table) {
+    for (key, new) in after {
+        match (before.get(key), new) {
+            (Some(old), _) if old == new => {}
+            (Some(toml::Value::Table(old)), toml::Value::Table(new)) => {
+                if !target.get(key).is_some_and(Item::is_table_like) {
+                    let mut table = toml_edit::Table::new();
+                    table.set_implicit(true);
+                    target.insert(key, Item::Table(table));
+                }
+                if let Some(inner) = target.get_mut(key).and_then(Item::as_table_like_mut) {
+                    apply_changes(inner, old, new);
+                }
+            }
+            _ => {
+                target.insert(key, to_item(new));
+            }
+        }
+    }
+
+    for key in before.keys() {
+        if !after.contains_key(key) {
+            target.remove(key);
+        }
+    }
+}
+
+/// Convert a value into a document item (tables become `[section]` tables)
+fn to_item(value: &toml::Value) -> Item {
+    match value {
+        toml::Value::Table(table) => {
+            let mut out = toml_edit::Table::new();
+            out.set_implicit(true);
+            for (key, value) in table {
+                out.insert(key, to_item(value));
+            }
+            Item::Table(out)
+        }
+        other => Item::Value(to_edit_value(other)),
+    }
+}
+
+/// Convert a value into an inline document value
+fn to_edit_value(value: &toml::Value) -> toml_edit::Value {
+    match value {
+        toml::Value::String(s) => s.as_str().into(),
+        toml::Value::Integer(i) => (*i).into(),
+        toml::Value::Float(f) => (*f).into(),
+        toml::Value::Boolean(b) => (*b).into(),
+        toml::Value::Datetime(d) => (*d).into(),
+        toml::Value::Array(items) => items.iter().map(to_edit_value).collect(),
+        toml::Value::Table(table) => table
+            .iter()
+            .map(|(k, v)| (k.as_str(), to_edit_value(v)))
+            .collect::<toml_edit::InlineTable>()
+            .into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +1032,57 @@ mod tests {
         assert!(loaded.targets["claude-code"].enabled);
     }
 
+    #[test]
+    fn custom_target_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+
+        let mut config = Config::default();
+        let target = config.get_or_create_target("folder-notes");
+        target.enabled = false;
+        target.skills_path = Some(PathBuf::from("/path/to/notes"));
+        target.display_name = Some("notes".to_string());
+        target.custom = true;
+
+        config.save_to(&config_path).unwrap();
+        let loaded = Config::load_from(&config_path).unwrap();
+
+        let target = &loaded.targets["folder-notes"];
+        assert!(!target.enabled);
+        assert!(target.custom);
+        assert_eq!(target.display_name.as_deref(), Some("notes"));
+        assert_eq!(target.skills_path, Some(PathBuf::from("/path/to/notes")));
+        assert!(target.kind.is_none());
+    }
+
+    #[test]
+    fn parses_target_filters() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+skills_dir = "/skills"
+
+[targets.codex]
+enabled = true
+tags = ["python"]
+exclude = ["internal-*"]
+"#,
+        )
+        .unwrap();
+
+        let config = Config::load_from(&config_path).unwrap();
+        let filter = &config.targets["codex"].filter;
+        assert_eq!(filter.tags, vec!["python"]);
+        assert_eq!(filter.exclude, vec!["internal-*"]);
+        assert!(filter.include.is_empty());
+
+        config.save_to(&config_path).unwrap();
+        let reloaded = Config::load_from(&config_path).unwrap();
+        assert_eq!(&reloaded.targets["codex"].filter, filter);
+    }
+
     #[test]
     fn get_or_create_target_creates_default() {
         let mut config = Config::default();
@@ -247,4 +1129,320 @@ skills_path = "/path/to/claude/skills"
             Some(PathBuf::from("/path/to/claude/skills"))
         );
     }
+
+    fn write_file(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn layers_override_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+
+        let sources = ConfigSources {
+            system: Some(write_file(
+                dir,
+                "system.toml",
+                "skills_dir = \"/system/skills\"\n[targets.codex]\nstrategy = \"copy\"\n",
+            )),
+            user: Some(write_file(
+                dir,
+                "user.toml",
+                "skills_dir = \"/user/skills\"\n[targets.codex]\nenabled = false\n",
+            )),
+            // The project's own skills_dir and targets don't override the app config
+            project: Some(write_file(
+                dir,
+                "agentloom.toml",
+                "skills_dir = \"skills\"\n[preferences]\nvalidate_on_sync = false\n",
+            )),
+            env: vec![(
+                "AGENTLOOM_SKILLS_DIR".to_string(),
+                "/env/skills".to_string(),
+            )],
+            cli: None,
+        };
+
+        let config = Config::load_with(&sources).unwrap();
+        assert_eq!(config.skills_dir, PathBuf::from("/env/skills"));
+        assert!(!config.preferences.validate_on_sync);
+        assert!(!config.targets["codex"].enabled);
+        assert_eq!(config.targets["codex"].strategy, SyncStrategy::Copy);
+
+        let layer = |key: &str| config.origin(key).unwrap().layer;
+        assert_eq!(layer("skills_dir"), ConfigLayer::Env);
+        assert_eq!(layer("preferences.validate_on_sync"), ConfigLayer::Project);
+        assert_eq!(layer("targets.codex.enabled"), ConfigLayer::User);
+        assert_eq!(layer("targets.codex.strategy"), ConfigLayer::System);
+        assert_eq!(
+            config.origin("skills_dir").unwrap().to_string(),
+            "env (AGENTLOOM_SKILLS_DIR)"
+        );
+        assert_eq!(config.layers().len(), 4);
+    }
+
+    #[test]
+    fn cli_file_wins_and_must_exist() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+
+        let sources = ConfigSources {
+            env: vec![(
+                "AGENTLOOM_SKILLS_DIR".to_string(),
+                "/env/skills".to_string(),
+            )],
+            cli: Some(write_file(
+                dir,
+                "cli.toml",
+                "skills_dir = \"/cli/skills\"\n",
+            )),
+            ..Default::default()
+        };
+        let config = Config::load_with(&sources).unwrap();
+        assert_eq!(config.skills_dir, PathBuf::from("/cli/skills"));
+        assert_eq!(config.origin("skills_dir").unwrap().layer, ConfigLayer::Cli);
+
+        let missing = ConfigSources {
+            cli: Some(dir.join("missing.toml")),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Config::load_with(&missing),
+            Err(Error::ConfigLoad { .. })
+        ));
+    }
+
+    #[test]
+    fn env_values_are_typed() {
+        let sources = ConfigSources {
+            env: vec![(
+                "AGENTLOOM_PREFERENCES__VALIDATE_ON_SYNC".to_string(),
+                "false".to_string(),
+            )],
+            ..Default::default()
+        };
+        let config = Config::load_with(&sources).unwrap();
+        assert!(!config.preferences.validate_on_sync);
+
+        let invalid = ConfigSources {
+            env: vec![(
+                "AGENTLOOM_PREFERENCES__VALIDATE_ON_SYNC".to_string(),
+                "sometimes".to_string(),
+            )],
+            ..Default::default()
+        };
+        assert!(matches!(
+            Config::load_with(&invalid),
+            Err(Error::ConfigEnv { .. })
+        ));
+    }
+
+    #[test]
+    fn save_writes_only_changed_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        let user = write_file(dir, "user.toml", "skills_dir = \"/user/skills\"\n");
+
+        let sources = ConfigSources {
+            system: Some(write_file(
+                dir,
+                "system.toml",
+                "[preferences]\nvalidate_on_sync = false\n",
+            )),
+            user: Some(user.clone()),
+            ..Default::default()
+        };
+        let mut config = Config::load_with(&sources).unwrap();
+        config.enable_target("claude-code");
+        config.save_to(&user).unwrap();
+
+        let saved: toml::Table = toml::from_str(&fs::read_to_string(&user).unwrap()).unwrap();
+        assert_eq!(saved["skills_dir"].as_str(), Some("/user/skills"));
+        assert!(saved["targets"]["claude-code"]["enabled"]
+            .as_bool()
+            .unwrap());
+        // Values from other layers stay where they came from
+        assert!(!saved.contains_key("preferences"));
+    }
+
+    #[test]
+    fn entries_report_origins() {
+        let config = Config::default();
+        let entries = config.entries().unwrap();
+        let skills_dir = entries.iter().find(|e| e.key == "skills_dir").unwrap();
+        assert_eq!(skills_dir.origin.layer, ConfigLayer::Default);
+        assert!(entries
+            .iter()
+            .any(|e| e.key == "preferences.validate_on_sync"));
+    }
+
+    #[test]
+    fn get_set_and_unset_by_key_path() {
+        let mut config = Config::default();
+
+        config.set("preferences.validate_on_sync", "false").unwrap();
+        config
+            .set("targets.codex.skills_path", "/tools/codex/skills")
+            .unwrap();
+        config.set("targets.codex.tags", r#"["python"]"#).unwrap();
+        config.set("targets.codex.strategy", "copy").unwrap();
+        config.set("lint.rules.content-required", "off").unwrap();
+
+        assert!(!config.preferences.validate_on_sync);
+        assert_eq!(
+            config.lint.level("any-skill", "content-required"),
+            Some(crate::lint::RuleLevel::Off)
+        );
+        assert_eq!(
+            config.get("targets.codex.skills_path").unwrap(),
+            Some(toml::Value::String("/tools/codex/skills".to_string()))
+        );
+        assert_eq!(config.targets["codex"].filter.tags, vec!["python"]);
+        assert_eq!(config.targets["codex"].strategy, SyncStrategy::Copy);
+        assert!(config.targets["codex"].enabled);
+
+        config.unset("targets.codex.skills_path").unwrap();
+        assert_eq!(config.get("targets.codex.skills_path").unwrap(), None);
+        config.unset("targets.codex").unwrap();
+        assert!(config.targets.is_empty());
+    }
+
+    #[test]
+    fn set_rejects_unknown_keys_and_wrong_types() {
+        let mut config = Config::default();
+
+        assert!(matches!(
+            config.set("preferences.colour", "blue"),
+            Err(Error::UnknownConfigKey(_))
+        ));
+        assert!(matches!(
+            config.set("targets.codex", "on"),
+            Err(Error::UnknownConfigKey(_))
+        ));
+        assert!(matches!(
+            config.set("preferences.validate_on_sync", "sometimes"),
+            Err(Error::InvalidConfigValue { .. })
+        ));
+        assert!(matches!(
+            config.set("targets.codex.strategy", "teleport"),
+            Err(Error::InvalidConfigValue { .. })
+        ));
+        assert!(config.preferences.validate_on_sync);
+        assert!(config.targets.is_empty());
+    }
+
+    #[test]
+    fn save_keeps_comments_and_unrelated_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_file(
+            temp_dir.path(),
+            "config.toml",
+            r#"# Team skills live on the shared drive
+skills_dir = "/shared/skills"
+
+[preferences]
+# Keep this on
+validate_on_sync = true
+
+[targets.codex]
+enabled = true
+skills_path = "/tools/codex"
+"#,
+        );
+
+        let mut config = Config::load_from(&path).unwrap();
+        config.set("targets.claude-code.enabled", "false").unwrap();
+        config.unset("targets.codex.skills_path").unwrap();
+        config.unset("preferences.validate_on_sync").unwrap();
+        config.save_to(&path).unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("# Team skills live on the shared drive"));
+        assert!(saved.contains("skills_dir = \"/shared/skills\""));
+        assert!(!saved.contains("skills_path"));
+        assert!(!saved.contains("validate_on_sync"));
+        assert!(!saved.contains("[preferences]"));
+
+        let reloaded = Config::load_from(&path).unwrap();
+        assert!(!reloaded.targets["claude-code"].enabled);
+        assert!(reloaded.targets["codex"].skills_path.is_none());
+        assert_eq!(
+            reloaded
+                .origin("preferences.validate_on_sync")
+                .unwrap()
+                .layer,
+            ConfigLayer::Default
+        );
+    }
+
+    #[test]
+    fn save_preserves_unknown_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_file(
+            temp_dir.path(),
+            "config.toml",
+            r#"config_version = 2
+future_setting = "keep me"
+
+[targets.codex]
+enabled = true
+future_flag = true
+"#,
+        );
+
+        let mut config = Config::load_from(&path).unwrap();
+        config.preferences.validate_on_sync = false;
+        config.save_to(&path).unwrap();
+
+        // A config that wasn't loaded from the file keeps them too
+        let mut fresh = Config::default();
+        fresh.disable_target("codex");
+        fresh.save_to(&path).unwrap();
+
+        let saved: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["future_setting"].as_str(), Some("keep me"));
+        assert_eq!(
+            saved["targets"]["codex"]["future_flag"].as_bool(),
+            Some(true)
+        );
+        assert_eq!(saved["targets"]["codex"]["enabled"].as_bool(), Some(false));
+    }
+
+    #[test]
+    fn old_files_are_migrated_and_upgraded_on_save() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_file(temp_dir.path(), "config.toml", "skills_dir = \"/skills\"\n");
+
+        let config = Config::load_from(&path).unwrap();
+        let layer = &config.layers()[0];
+        assert_eq!(layer.version, 1);
+        assert!(layer.is_outdated());
+        assert_eq!(config.config_version, CONFIG_VERSION);
+        // Reading doesn't touch the file
+        assert!(!fs::read_to_string(&path)
+            .unwrap()
+            .contains("config_version"));
+
+        config.save_to(&path).unwrap();
+        let reloaded = Config::load_from(&path).unwrap();
+        assert_eq!(reloaded.layers()[0].version, CONFIG_VERSION);
+        assert!(reloaded.layers()[0].migrations.is_empty());
+    }
+
+    #[test]
+    fn newer_files_keep_their_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_file(temp_dir.path(), "config.toml", "config_version = 99\n");
+
+        let mut config = Config::load_from(&path).unwrap();
+        assert!(config.layers()[0].is_newer());
+
+        config.enable_target("codex");
+        config.save_to(&path).unwrap();
+
+        let saved: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["config_version"].as_integer(), Some(99));
+    }
 }
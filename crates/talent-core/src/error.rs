@@ -28,6 +28,18 @@ pub enum Error {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 
+    /// An `AGENTLOOM_*` environment variable has a value of the wrong type
+    #[error("Invalid value in environment variable {var}: {message}")]
+    ConfigEnv { var: String, message: String },
+
+    /// A config key path that doesn't name a known setting
+    #[error("Unknown config key: {0}")]
+    UnknownConfigKey(String),
+
+    /// A config value of the wrong type
+    #[error("Invalid value for {key}: {message}")]
+    InvalidConfigValue { key: String, message: String },
+
     /// Configuration directory not found
     #[error("Could not determine config directory")]
     ConfigDirNotFound,
@@ -57,6 +69,10 @@ pub enum Error {
     #[error("Skill validation failed for '{name}': {message}")]
     ValidationFailed { name: String, message: String },
 
+    /// Malformed entry in a skill's `allowed-tools` field
+    #[error("Invalid allowed-tools entry '{entry}': {message}")]
+    InvalidAllowedTool { entry: String, message: String },
+
     // === Target Errors ===
     /// Target CLI not found on system
     #[error("Target CLI '{name}' not found at expected path: {path}")]
@@ -78,6 +94,10 @@ pub enum Error {
     #[error("Unknown target type: {0}")]
     UnknownTargetType(String),
 
+    /// Invalid glob pattern in a skill filter
+    #[error("Invalid pattern '{pattern}': {message}")]
+    InvalidPattern { pattern: String, message: String },
+
     // === Sync Errors ===
     /// Failed to create symlink
     #[error("Failed to create symlink from {link_source} to {link_target}: {message}")]
@@ -95,6 +115,10 @@ pub enum Error {
     #[error("Path exists and is not a symlink: {0}")]
     NotASymlink(PathBuf),
 
+    /// A managed section's begin marker has no matching end marker
+    #[error("Managed section in {0} is missing its end marker")]
+    UnterminatedSection(PathBuf),
+
     // === File System Errors ===
     /// Generic I/O error with context
     #[error("I/O error at {path}: {source}")]
@@ -120,6 +144,10 @@ pub enum Error {
         source: std::io::Error,
     },
 
+    /// Filesystem watcher failed
+    #[error("Watch error: {0}")]
+    Watch(String),
+
     // === Serialization Errors ===
     /// YAML parsing/serialization error
     #[error("YAML error: {0}")]
@@ -0,0 +1,167 @@
+//! Per-target skill selection
+//!
+//! A target can narrow down which skills it receives using name globs and
+//! tags from the skill frontmatter. Skills are selected when they:
+//! - match at least one `include` glob (or `include` is empty),
+//! - match no `exclude` glob,
+//! - carry at least one of `tags` (or `tags` is empty),
+//! - carry none of `exclude_tags`.
+
+use crate::error::{Error, Result};
+use crate::skill::Skill;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+/// Include/exclude rules for the skills synced to a target
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillFilter {
+    /// Skill name globs to sync (empty = all skills)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
+    /// Skill name globs that are never synced
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// Only sync skills with at least one of these tags (empty = any)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Never sync skills with any of these tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_tags: Vec<String>,
+}
+
+impl SkillFilter {
+    /// Check if the filter has no rules (every skill is selected)
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty()
+            && self.exclude.is_empty()
+            && self.tags.is_empty()
+            && self.exclude_tags.is_empty()
+    }
+
+    /// Compile the globs into a matcher
+    pub fn compile(&self) -> Result<SkillMatcher<'_>> {
+        Ok(SkillMatcher {
+            include: build_set(&self.include)?,
+            exclude: build_set(&self.exclude)?,
+            filter: self,
+        })
+    }
+}
+
+/// A compiled [`SkillFilter`], ready to test skills against
+#[derive(Debug)]
+pub struct SkillMatcher<'a> {
+    include: GlobSet,
+    exclude: GlobSet,
+    filter: &'a SkillFilter,
+}
+
+impl SkillMatcher<'_> {
+    /// Check if a skill is selected by the filter
+    pub fn matches(&self, skill: &Skill) -> bool {
+        let name = skill.name();
+        let tags = &skill.meta.tags;
+
+        if !self.filter.include.is_empty() && !self.include.is_match(name) {
+            return false;
+        }
+        if self.exclude.is_match(name) {
+            return false;
+        }
+        if !self.filter.tags.is_empty() && !tags.iter().any(|t| self.filter.tags.contains(t)) {
+            return false;
+        }
+        !tags.iter().any(|t| self.filter.exclude_tags.contains(t))
+    }
+}
+
+/// Build a glob set from a list of patterns
+fn build_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|e| Error::InvalidPattern {
+            pattern: pattern.clone(),
+            message: e.kind().to_string(),
+        })?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| Error::InvalidPattern {
+        pattern: patterns.join(", "),
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn skill_with_tags(temp: &TempDir, name: &str, tags: &[&str]) -> Skill {
+        let mut skill = Skill::create(temp.path(), name, "Test skill").unwrap();
+        skill.meta.tags = tags.iter().map(|t| t.to_string()).collect();
+        skill
+    }
+
+    #[test]
+    fn empty_filter_selects_everything() {
+        let temp = TempDir::new().unwrap();
+        let skill = skill_with_tags(&temp, "any-skill", &[]);
+
+        let filter = SkillFilter::default();
+        assert!(filter.is_empty());
+        assert!(filter.compile().unwrap().matches(&skill));
+    }
+
+    #[test]
+    fn include_and_exclude_globs() {
+        let temp = TempDir::new().unwrap();
+        let public = skill_with_tags(&temp, "py-lint", &[]);
+        let internal = skill_with_tags(&temp, "internal-deploy", &[]);
+        let other = skill_with_tags(&temp, "rust-fmt", &[]);
+
+        let filter = SkillFilter {
+            include: vec!["py-*".to_string(), "internal-*".to_string()],
+            exclude: vec!["internal-*".to_string()],
+            ..Default::default()
+        };
+        let matcher = filter.compile().unwrap();
+
+        assert!(matcher.matches(&public));
+        assert!(!matcher.matches(&internal));
+        assert!(!matcher.matches(&other));
+    }
+
+    #[test]
+    fn tag_selectors() {
+        let temp = TempDir::new().unwrap();
+        let python = skill_with_tags(&temp, "py-lint", &["python"]);
+        let secret = skill_with_tags(&temp, "py-secret", &["python", "internal"]);
+        let untagged = skill_with_tags(&temp, "plain", &[]);
+
+        let filter = SkillFilter {
+            tags: vec!["python".to_string()],
+            exclude_tags: vec!["internal".to_string()],
+            ..Default::default()
+        };
+        let matcher = filter.compile().unwrap();
+
+        assert!(matcher.matches(&python));
+        assert!(!matcher.matches(&secret));
+        assert!(!matcher.matches(&untagged));
+    }
+
+    #[test]
+    fn invalid_glob_is_an_error() {
+        let filter = SkillFilter {
+            include: vec!["[unclosed".to_string()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            filter.compile(),
+            Err(Error::InvalidPattern { .. })
+        ));
+    }
+}
@@ -5,6 +5,7 @@
 
 use crate::config::Config;
 use crate::error::{Error, Result};
+use crate::manifest::Manifest;
 use crate::skill::{normalize_frontmatter, to_kebab_case, Skill, SKILL_FILE_NAME};
 use crate::target::{Target, TargetKind};
 use serde::{Deserialize, Serialize};
@@ -166,6 +167,9 @@ impl Importer {
 
         let mut skills = Vec::new();
 
+        // Copies materialized by the syncer are already managed
+        let manifest = Manifest::load(skills_path).unwrap_or_default();
+
         // Read entries in the skills directory (depth 1)
         let entries = fs::read_dir(skills_path).map_err(|e| Error::read_dir(skills_path, e))?;
 
@@ -183,6 +187,15 @@ impl Importer {
                 continue;
             }
 
+            let materialized = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| manifest.links.get(n))
+                .is_some_and(|e| e.strategy.is_materialized());
+            if materialized {
+                continue;
+            }
+
             // Check for SKILL.md
             let skill_file = path.join(SKILL_FILE_NAME);
             if !skill_file.exists() {
@@ -350,7 +363,7 @@ impl Importer {
             name,
             description: skill.meta.description.clone(),
             source_path: skill_dir.to_path_buf(),
-            needs_fixes: normalize_result.was_modified || !skill.validation_errors.is_empty(),
+            needs_fixes: normalize_result.was_modified || !skill.diagnostics.is_empty(),
             fixes_preview: normalize_result.fixes,
             conflict,
         })
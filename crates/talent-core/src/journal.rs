@@ -0,0 +1,243 @@
+//! Write-ahead journal that makes applying a sync all-or-nothing per target
+//!
+//! Before the syncer changes an entry in a target skills directory, it records
+//! what was there in `.agentloom-journal/` inside that directory: where a link
+//! pointed, or a backup of a file or folder. If applying fails partway, the
+//! recorded entries are restored newest first and the target is left as it was.
+//! A journal that is still on disk when the next sync starts belongs to a run
+//! that was interrupted, and is rolled back before anything else happens.
+
+use crate::error::{Error, Result};
+use crate::syncer::{copy_tree, Syncer};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the journal directory inside a target's skills directory
+pub const JOURNAL_DIR_NAME: &str = ".agentloom-journal";
+
+/// Name of the journal file inside the journal directory (backups sit next to it)
+const JOURNAL_FILE_NAME: &str = "journal.json";
+
+/// What was at a path before the syncer changed it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Previous {
+    /// Nothing; the syncer created the entry
+    Missing,
+    /// A symlink (or junction) pointing here
+    Link { destination: PathBuf },
+    /// A file or folder, backed up under this name in the journal directory
+    Backup { name: String },
+}
+
+/// A change the syncer is about to make
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Path that is changed
+    pub path: PathBuf,
+
+    /// What was there before
+    pub previous: Previous,
+}
+
+/// Changes made to one target since the sync started applying
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Journal {
+    /// Journal directory (not stored, derived from the target)
+    #[serde(skip)]
+    dir: PathBuf,
+
+    /// Recorded changes, oldest first
+    pub entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Get the journal directory for a target skills directory
+    pub fn dir_in(skills_path: &Path) -> PathBuf {
+        skills_path.join(JOURNAL_DIR_NAME)
+    }
+
+    /// Start an empty journal for a target skills directory
+    ///
+    /// Nothing is written until the first change is recorded.
+    pub fn begin(skills_path: &Path) -> Self {
+        Self {
+            dir: Self::dir_in(skills_path),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Load the journal an interrupted sync left behind, if any
+    pub fn load(skills_path: &Path) -> Result<Option<Self>> {
+        let dir = Self::dir_in(skills_path);
+        let path = dir.join(JOURNAL_FILE_NAME);
+        if !path.exists() {
+            // A directory without a journal file only holds backups of changes never made
+            return Ok(dir.exists().then(|| Self::begin(skills_path)));
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        let mut journal: Self = serde_json::from_str(&contents)?;
+        journal.dir = dir;
+        Ok(Some(journal))
+    }
+
+    /// Check if no changes were recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Record what is at `path` before the syncer changes it
+    ///
+    /// Links are remembered by destination; files and folders are backed up
+    /// into the journal directory. The journal is saved before returning.
+    pub fn record(&mut self, syncer: &Syncer, path: &Path) -> Result<()> {
+        let previous = match path.symlink_metadata() {
+            Err(_) => Previous::Missing,
+            Ok(metadata) if syncer.is_link(&metadata, path) => Previous::Link {
+                destination: syncer
+                    .read_link_target(path)
+                    .map_err(|e| Error::io(path, e))?,
+            },
+            Ok(metadata) => {
+                let name = self.entries.len().to_string();
+                let backup = self.dir.join(&name);
+                fs::create_dir_all(&self.dir).map_err(|e| Error::create_dir(&self.dir, e))?;
+                if metadata.is_dir() {
+                    copy_tree(path, &backup)?;
+                } else {
+                    fs::copy(path, &backup).map_err(|e| Error::io(path, e))?;
+                }
+                Previous::Backup { name }
+            }
+        };
+
+        self.entries.push(JournalEntry {
+            path: path.to_path_buf(),
+            previous,
+        });
+        self.save()
+    }
+
+    /// Forget the most recent entry because the change turned out not to be made
+    pub fn discard_last(&mut self) -> Result<()> {
+        if let Some(entry) = self.entries.pop() {
+            if let Previous::Backup { name } = entry.previous {
+                remove_any(&self.dir.join(name))?;
+            }
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Keep all recorded changes and delete the journal
+    pub fn commit(self) -> Result<()> {
+        remove_any(&self.dir)
+    }
+
+    /// Undo all recorded changes, newest first, then delete the journal
+    ///
+    /// Restoring is idempotent, so if it fails the journal is kept and the
+    /// next attempt picks up where this one stopped.
+    pub fn rollback(self, syncer: &Syncer) -> Result<()> {
+        for entry in self.entries.iter().rev() {
+            self.restore(syncer, entry)?;
+        }
+        self.commit()
+    }
+
+    /// Put back what was at an entry's path
+    fn restore(&self, syncer: &Syncer, entry: &JournalEntry) -> Result<()> {
+        let path = &entry.path;
+        match &entry.previous {
+            Previous::Missing => remove_any(path),
+            Previous::Link { destination } => {
+                let current = syncer.read_link_target(path).ok();
+                if current.as_ref() == Some(destination) {
+                    return Ok(());
+                }
+                remove_any(path)?;
+                syncer.do_create_symlink(path, destination)
+            }
+            Previous::Backup { name } => {
+                let backup = self.dir.join(name);
+                // Already restored by an earlier attempt
+                if !backup.exists() {
+                    return Ok(());
+                }
+                remove_any(path)?;
+                fs::rename(&backup, path).map_err(|e| Error::io(path, e))
+            }
+        }
+    }
+
+    /// Write the journal file
+    fn save(&self) -> Result<()> {
+        fs::create_dir_all(&self.dir).map_err(|e| Error::create_dir(&self.dir, e))?;
+        let path = self.dir.join(JOURNAL_FILE_NAME);
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&path, contents).map_err(|e| Error::io(&path, e))
+    }
+}
+
+/// Remove whatever is at a path (link, file or folder); missing paths are fine
+fn remove_any(path: &Path) -> Result<()> {
+    let Ok(metadata) = path.symlink_metadata() else {
+        return Ok(());
+    };
+    let removed = if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        // Also removes symlinks, including links to directories
+        fs::remove_file(path).or_else(|_| fs::remove_dir(path))
+    };
+    removed.map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn rollback_restores_links_files_and_folders() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join("target");
+        let source = temp.path().join("source");
+        fs::create_dir_all(&target).unwrap();
+        fs::create_dir_all(&source).unwrap();
+
+        let syncer = Syncer::new();
+        let link = target.join("linked");
+        syncer.do_create_symlink(&link, &source).unwrap();
+        let folder = target.join("copied");
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("SKILL.md"), "original").unwrap();
+        let created = target.join("created");
+
+        let mut journal = Journal::begin(&target);
+        for path in [&link, &folder, &created] {
+            journal.record(&syncer, path).unwrap();
+        }
+
+        // Make the changes, then "crash" before committing
+        fs::remove_file(&link).unwrap();
+        fs::write(folder.join("SKILL.md"), "changed").unwrap();
+        fs::create_dir_all(&created).unwrap();
+        drop(journal);
+
+        let journal = Journal::load(&target).unwrap().unwrap();
+        assert_eq!(journal.entries.len(), 3);
+        journal.rollback(&syncer).unwrap();
+
+        assert_eq!(fs::read_link(&link).unwrap(), source);
+        assert_eq!(
+            fs::read_to_string(folder.join("SKILL.md")).unwrap(),
+            "original"
+        );
+        assert!(!created.exists());
+        assert!(!Journal::dir_in(&target).exists());
+        assert!(Journal::load(&target).unwrap().is_none());
+    }
+}
@@ -0,0 +1,442 @@
+//! Declarative target kind definitions
+//!
+//! A target kind describes an agent tool: where its user-level config directory
+//! lives on each OS, which subdirectory holds skills, its directory inside a
+//! project and how to tell whether it's installed. The built-in kinds are
+//! embedded from `builtin_kinds.toml`; more can be declared under `[kinds.*]`
+//! in config using the same format:
+//!
+//! ```toml
+//! [kinds.zed]
+//! display_name = "Zed"
+//! config_dir = { default = "~/.config/zed", macos = "~/Library/Application Support/Zed" }
+//! project_dir = ".zed"
+//! binary = "zed"
+//! detect = [{ binary = "zed" }, { dir_exists = "~/.config/zed" }]
+//! ```
+
+use crate::target::TargetKind;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Built-in target kinds, in the same format as `[kinds.*]` in config
+const BUILTIN_KINDS: &str = include_str!("builtin_kinds.toml");
+
+/// Default skills subdirectory inside a tool's config directory
+const DEFAULT_SKILLS_SUBDIR: &str = "skills";
+
+/// Default argument for the version probe
+const DEFAULT_VERSION_ARG: &str = "--version";
+
+/// A target kind declared in data rather than code
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KindDefinition {
+    /// Identifier (the key under `[kinds]`, also used for `[targets.<id>]`)
+    #[serde(skip)]
+    pub id: String,
+
+    /// Display name (defaults to the id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// User-level config directory (a path, or one path per OS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config_dir: Option<PathTemplate>,
+
+    /// Skills directory inside the config directory (default: `skills`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills_subdir: Option<String>,
+
+    /// The tool's directory inside a project (e.g. `.claude`); kinds without one get no project targets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_dir: Option<String>,
+
+    /// The tool's executable, looked up on `PATH` (None for tools without a CLI)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary: Option<String>,
+
+    /// Arguments that make the executable print its version (default: `--version`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_args: Option<Vec<String>>,
+
+    /// Rules that detect the tool; any match counts
+    /// (default: the config directory exists or the binary is on `PATH`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub detect: Vec<DetectRule>,
+}
+
+/// A path template, either for every OS or per OS
+///
+/// Templates may start with `~` or `{home}`, `{config}`, `{data}` or
+/// `{data_local}` (the platform's config and data directories).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PathTemplate {
+    /// The same path on every OS
+    Any(String),
+    /// Different paths per OS
+    PerOs(OsPaths),
+}
+
+/// Per-OS path templates; the current OS falls back to `default`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OsPaths {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub windows: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub macos: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linux: Option<String>,
+}
+
+/// A rule that tells whether a tool is installed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectRule {
+    /// A directory exists (path template)
+    DirExists(String),
+    /// An executable with this name is on `PATH`
+    Binary(String),
+    /// A file (path template) contains a string
+    FileContains { path: String, pattern: String },
+}
+
+impl KindDefinition {
+    /// Get the display name
+    pub fn name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Get the built-in kind with this id, if any
+    pub fn builtin_kind(&self) -> Option<TargetKind> {
+        TargetKind::from_id(&self.id)
+    }
+
+    /// Resolve the user-level config directory for the current OS
+    pub fn config_dir(&self) -> Option<PathBuf> {
+        self.config_dir.as_ref()?.resolve()
+    }
+
+    /// Get the skills subdirectory name within the config dir
+    pub fn skills_subdir(&self) -> &str {
+        self.skills_subdir
+            .as_deref()
+            .unwrap_or(DEFAULT_SKILLS_SUBDIR)
+    }
+
+    /// Resolve the user-level skills directory
+    pub fn skills_path(&self) -> Option<PathBuf> {
+        Some(self.config_dir()?.join(self.skills_subdir()))
+    }
+
+    /// Get the arguments for the version probe
+    pub fn version_args(&self) -> Vec<String> {
+        self.version_args
+            .clone()
+            .unwrap_or_else(|| vec![DEFAULT_VERSION_ARG.to_string()])
+    }
+
+    /// Check if the tool is installed
+    pub fn is_installed(&self) -> bool {
+        if self.detect.is_empty() {
+            return self.config_dir().is_some_and(|dir| dir.exists())
+                || self
+                    .binary
+                    .as_deref()
+                    .is_some_and(|name| find_executable(name).is_some());
+        }
+        self.detect.iter().any(DetectRule::matches)
+    }
+
+    /// Fill fields this definition leaves unset from `base`
+    fn or(mut self, base: &KindDefinition) -> Self {
+        self.display_name = self.display_name.or_else(|| base.display_name.clone());
+        self.config_dir = self.config_dir.or_else(|| base.config_dir.clone());
+        self.skills_subdir = self.skills_subdir.or_else(|| base.skills_subdir.clone());
+        self.project_dir = self.project_dir.or_else(|| base.project_dir.clone());
+        self.binary = self.binary.or_else(|| base.binary.clone());
+        self.version_args = self.version_args.or_else(|| base.version_args.clone());
+        if self.detect.is_empty() {
+            self.detect = base.detect.clone();
+        }
+        self
+    }
+}
+
+impl PathTemplate {
+    /// Resolve the template for the current OS
+    pub fn resolve(&self) -> Option<PathBuf> {
+        let template = match self {
+            PathTemplate::Any(template) => template,
+            PathTemplate::PerOs(paths) => paths.current()?,
+        };
+        expand_path(template)
+    }
+}
+
+impl OsPaths {
+    /// Get the template for the current OS
+    fn current(&self) -> Option<&String> {
+        let specific = if cfg!(windows) {
+            &self.windows
+        } else if cfg!(target_os = "macos") {
+            &self.macos
+        } else if cfg!(target_os = "linux") {
+            &self.linux
+        } else {
+            &None
+        };
+        specific.as_ref().or(self.default.as_ref())
+    }
+}
+
+impl DetectRule {
+    /// Check if the rule matches on this machine
+    pub fn matches(&self) -> bool {
+        match self {
+            DetectRule::DirExists(path) => expand_path(path).is_some_and(|p| p.is_dir()),
+            DetectRule::Binary(name) => find_executable(name).is_some(),
+            DetectRule::FileContains { path, pattern } => expand_path(path)
+                .and_then(|p| fs::read_to_string(p).ok())
+                .is_some_and(|contents| contents.contains(pattern.as_str())),
+        }
+    }
+}
+
+/// Resolves a platform directory such as the home directory
+type BaseDir = fn() -> Option<PathBuf>;
+
+/// Expand `~` and `{home}`, `{config}`, `{data}`, `{data_local}` at the start of a path
+///
+/// Returns None if the platform doesn't have the named directory.
+pub fn expand_path(template: &str) -> Option<PathBuf> {
+    let prefixes: [(&str, BaseDir); 5] = [
+        ("~", dirs::home_dir),
+        ("{home}", dirs::home_dir),
+        ("{config}", dirs::config_dir),
+        ("{data}", dirs::data_dir),
+        ("{data_local}", dirs::data_local_dir),
+    ];
+
+    for (prefix, base) in prefixes {
+        if let Some(rest) = template.strip_prefix(prefix) {
+            let base = base()?;
+            let rest = rest.trim_start_matches(['/', '\\']);
+            return Some(if rest.is_empty() {
+                base
+            } else {
+                base.join(rest)
+            });
+        }
+    }
+
+    Some(PathBuf::from(template))
+}
+
+/// Find an executable on `PATH`
+pub fn find_executable(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+
+    // On Windows, `claude` is really `claude.exe` or `claude.cmd`
+    let extensions: Vec<String> = if cfg!(windows) {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".EXE;.CMD;.BAT;.COM".to_string())
+            .split(';')
+            .map(|ext| ext.to_lowercase())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    std::env::split_paths(&path).find_map(|dir| {
+        std::iter::once(dir.join(name))
+            .chain(
+                extensions
+                    .iter()
+                    .map(|ext| dir.join(format!("{name}{ext}"))),
+            )
+            .find(|candidate| is_executable(candidate))
+    })
+}
+
+/// Check if a path is an executable file
+fn is_executable(path: &std::path::Path) -> bool {
+    let Ok(metadata) = fs::metadata(path) else {
+        return false;
+    };
+    if !metadata.is_file() {
+        return false;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode() & 0o111 != 0
+    }
+
+    #[cfg(not(unix))]
+    {
+        true
+    }
+}
+
+/// Contents of a kinds table (`builtin_kinds.toml` or the `[kinds]` part of config)
+#[derive(Debug, Deserialize)]
+struct KindsFile {
+    kinds: BTreeMap<String, KindDefinition>,
+}
+
+/// Get the built-in kind definitions (keyed by id)
+pub fn builtin_kinds() -> &'static BTreeMap<String, KindDefinition> {
+    static KINDS: OnceLock<BTreeMap<String, KindDefinition>> = OnceLock::new();
+
+    KINDS.get_or_init(|| {
+        let file: KindsFile =
+            toml::from_str(BUILTIN_KINDS).expect("builtin_kinds.toml is a valid kinds table");
+        with_ids(file.kinds)
+    })
+}
+
+/// Merge declared kinds with the built-in ones
+///
+/// Built-in kinds come first in their usual order. A declared kind with a
+/// built-in id overrides the fields it sets; other declared kinds follow.
+pub fn merged_kinds(declared: &BTreeMap<String, KindDefinition>) -> Vec<KindDefinition> {
+    let builtins = builtin_kinds();
+    let declared = with_ids(declared.clone());
+
+    let mut kinds: Vec<KindDefinition> = TargetKind::all()
+        .iter()
+        .filter_map(|kind| builtins.get(kind.id()))
+        .map(|builtin| match declared.get(&builtin.id) {
+            Some(overrides) => overrides.clone().or(builtin),
+            None => builtin.clone(),
+        })
+        .collect();
+
+    kinds.extend(
+        declared
+            .into_values()
+            .filter(|kind| !builtins.contains_key(&kind.id)),
+    );
+    kinds
+}
+
+/// Set each definition's id from its key
+fn with_ids(kinds: BTreeMap<String, KindDefinition>) -> BTreeMap<String, KindDefinition> {
+    kinds
+        .into_iter()
+        .map(|(id, mut kind)| {
+            kind.id = id.clone();
+            (id, kind)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn builtin_table_matches_target_kinds() {
+        let builtins = builtin_kinds();
+        assert_eq!(builtins.len(), TargetKind::all().len());
+        for kind in TargetKind::all() {
+            let definition = &builtins[kind.id()];
+            assert!(definition.display_name.is_some());
+            assert!(definition.config_dir.is_some());
+            assert!(definition.project_dir.is_some());
+        }
+    }
+
+    #[test]
+    fn expands_path_templates() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(expand_path("~/.tool"), Some(home.join(".tool")));
+        assert_eq!(expand_path("{home}"), Some(home));
+        assert_eq!(expand_path("/opt/tool"), Some(PathBuf::from("/opt/tool")));
+
+        let per_os = PathTemplate::PerOs(OsPaths {
+            default: Some("/default".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(per_os.resolve(), Some(PathBuf::from("/default")));
+    }
+
+    #[test]
+    fn declared_kinds_merge_with_builtins() {
+        let config: toml::Table = toml::from_str(
+            r#"
+[kinds.zed]
+display_name = "Zed"
+config_dir = "/opt/zed"
+detect = [{ binary = "zed" }, { dir_exists = "/opt/zed" }]
+
+[kinds.codex]
+skills_subdir = "prompts"
+"#,
+        )
+        .unwrap();
+        let file: KindsFile = toml::Value::Table(config).try_into().unwrap();
+
+        let kinds = merged_kinds(&file.kinds);
+        assert_eq!(kinds.len(), TargetKind::all().len() + 1);
+        assert_eq!(kinds[0].id, "claude-code");
+
+        let codex = kinds.iter().find(|k| k.id == "codex").unwrap();
+        assert_eq!(codex.skills_subdir(), "prompts");
+        assert_eq!(codex.name(), "Codex");
+
+        let zed = kinds.last().unwrap();
+        assert_eq!(zed.id, "zed");
+        assert_eq!(zed.name(), "Zed");
+        assert_eq!(zed.skills_path(), Some(PathBuf::from("/opt/zed/skills")));
+        assert!(zed.builtin_kind().is_none());
+        assert_eq!(
+            zed.detect,
+            vec![
+                DetectRule::Binary("zed".to_string()),
+                DetectRule::DirExists("/opt/zed".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn detect_rules() {
+        let temp = TempDir::new().unwrap();
+        let settings = temp.path().join("settings.json");
+        fs::write(&settings, r#"{"agent": {"skills": true}}"#).unwrap();
+
+        let dir = temp.path().display().to_string();
+        let path = settings.display().to_string();
+
+        assert!(DetectRule::DirExists(dir).matches());
+        assert!(!DetectRule::DirExists(path.clone()).matches());
+        assert!(DetectRule::FileContains {
+            path: path.clone(),
+            pattern: "\"skills\"".to_string()
+        }
+        .matches());
+        assert!(!DetectRule::FileContains {
+            path,
+            pattern: "nope".to_string()
+        }
+        .matches());
+        assert!(!DetectRule::Binary("agentloom-no-such-binary".to_string()).matches());
+
+        let kind = KindDefinition {
+            id: "tool".to_string(),
+            config_dir: Some(PathTemplate::Any(temp.path().display().to_string())),
+            ..Default::default()
+        };
+        assert!(kind.is_installed());
+    }
+}
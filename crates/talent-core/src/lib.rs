@@ -3,31 +3,62 @@
 //! This crate provides the core functionality for managing agent skills:
 //! - Skill discovery and parsing
 //! - Target CLI detection
-//! - Symlink synchronization
+//! - Symlink synchronization, journaled so a failed sync is rolled back
+//! - Filesystem watching for automatic resyncs
 //! - Validation
 
 pub mod config;
 pub mod error;
+pub mod filter;
 pub mod importer;
+pub mod journal;
+pub mod kinds;
+pub mod lint;
 pub mod manager;
+pub mod manifest;
 pub mod migration;
+pub mod project;
+pub mod render;
+pub mod resources;
+pub mod size;
 pub mod skill;
 pub mod syncer;
 pub mod target;
+pub mod tools;
 pub mod validator;
+pub mod watcher;
 
-pub use config::Config;
+pub use config::{Config, ConfigEntry, ConfigLayer, ConfigLayerInfo, ConfigOrigin, ConfigSources};
 pub use error::{Error, Result};
+pub use filter::SkillFilter;
 pub use importer::{
     check_filemerge_available, open_filemerge, ConflictInfo, ConflictResolution, DiscoveredSkill,
     FolderImportSelection, ImportResult, ImportSelection, Importer, ScannedSkill,
 };
+pub use journal::{Journal, JOURNAL_DIR_NAME};
+pub use kinds::{DetectRule, KindDefinition, OsPaths, PathTemplate};
+pub use lint::{Diagnostic, LintConfig, Location, RuleLevel, Severity};
 pub use manager::{ManagerStats, SkillManager};
-pub use migration::{has_legacy_skills, legacy_skills_dir, migrate_if_needed, MigrationResult};
+pub use manifest::{Manifest, ManifestEntry, RenameLog, MANIFEST_FILE_NAME, RENAME_LOG_FILE_NAME};
+pub use migration::{
+    has_legacy_skills, legacy_skills_dir, migrate_config, migrate_if_needed, ConfigMigrationResult,
+    MigrationResult, CONFIG_VERSION,
+};
+pub use project::{Project, ProjectConfig, Scope, PROJECT_CONFIG_FILE_NAME};
+pub use render::SectionStyle;
+pub use size::{Budgets, SkillSize, TextSize};
 pub use skill::{
     discover_skills, normalize_frontmatter, to_kebab_case, NormalizeResult, Skill, SkillMeta,
     ValidationStatus, SKILL_FILE_NAME,
 };
-pub use syncer::{SyncError, SyncResult, Syncer};
-pub use target::{Target, TargetInfo, TargetKind};
-pub use validator::Validator;
+pub use syncer::{
+    AdoptCandidate, AdoptResult, AdoptSelection, LinkStyle, PlannedLink, RepairResult, SyncError,
+    SyncPlan, SyncResult, SyncStrategy, Syncer, ADOPT_BACKUP_DIR_NAME,
+};
+pub use target::{
+    FormatRenderer, RenderedFile, SyncStatus, Target, TargetBinary, TargetFormat, TargetInfo,
+    TargetKind,
+};
+pub use tools::{parse_allowed_tools, AllowedTool};
+pub use validator::{Rule, Validator, RULES};
+pub use watcher::{ChangeSet, SkillWatcher, DEFAULT_DEBOUNCE};
@@ -0,0 +1,271 @@
+//! Diagnostics and rule configuration for skill validation
+//!
+//! Every check the [`Validator`](crate::validator::Validator) runs is a named
+//! rule with a default severity (see [`RULES`](crate::validator::RULES)).
+//! Config can switch rules off or change their severity, for every skill or
+//! for a single one:
+//!
+//! ```toml
+//! [lint.rules]
+//! content-required = "off"
+//!
+//! [lint.skills.my-skill]
+//! description-length = "warning"
+//! ```
+//!
+//! Size budgets for the `budget-*` rules are set the same way (0 = no limit):
+//!
+//! ```toml
+//! [lint.budgets]
+//! body_tokens = 3000
+//! ```
+//!
+//! A skill can also switch rules off for itself from its frontmatter:
+//!
+//! ```yaml
+//! metadata:
+//!   agentloom-disable: description-length, name-format
+//! ```
+
+use crate::size::Budgets;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Metadata key listing the rules a skill switches off for itself
+pub const DISABLE_DIRECTIVE: &str = "agentloom-disable";
+
+/// Rule reported while loading when SKILL.md can't be read
+pub const UNREADABLE: &str = "unreadable";
+
+/// Rule reported while loading when the frontmatter can't be parsed
+pub const FRONTMATTER_SYNTAX: &str = "frontmatter-syntax";
+
+/// How serious a diagnostic is; only errors make a skill invalid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Worth knowing, nothing to fix
+    Info,
+    /// Should be fixed, but the skill still works
+    Warning,
+    /// The skill is invalid
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// Level a rule is configured to: a severity, or off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// The rule isn't checked
+    Off,
+    /// Report as [`Severity::Info`]
+    Info,
+    /// Report as [`Severity::Warning`]
+    Warning,
+    /// Report as [`Severity::Error`]
+    Error,
+}
+
+impl RuleLevel {
+    /// Severity diagnostics get at this level, or `None` if the rule is off
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Info => Some(Severity::Info),
+            RuleLevel::Warning => Some(Severity::Warning),
+            RuleLevel::Error => Some(Severity::Error),
+        }
+    }
+}
+
+impl fmt::Display for RuleLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severity() {
+            Some(severity) => severity.fmt(f),
+            None => f.write_str("off"),
+        }
+    }
+}
+
+/// Where in a skill a diagnostic applies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    /// File, relative to the skill directory
+    pub file: PathBuf,
+
+    /// Line in the file (1-based), when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    /// Frontmatter field the diagnostic is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A problem found in a skill
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// Id of the rule that found it
+    pub rule: String,
+
+    /// How serious it is
+    pub severity: Severity,
+
+    /// What is wrong
+    pub message: String,
+
+    /// Where it is, when it can be pointed at
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+
+    /// What an automatic fix would do, if one is possible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl Diagnostic {
+    /// Create a diagnostic without a location
+    pub fn new(rule: &str, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            rule: rule.to_string(),
+            severity,
+            message: message.into(),
+            location: None,
+            fix: None,
+        }
+    }
+
+    /// Check if this diagnostic makes the skill invalid
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if let Some(fix) = &self.fix {
+            write!(f, " ({fix})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Rule levels set in config
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LintConfig {
+    /// Levels for every skill (key = rule id)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>,
+
+    /// Levels for single skills, taking precedence (key = skill name, then rule id)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub skills: BTreeMap<String, BTreeMap<String, RuleLevel>>,
+
+    /// Size budgets checked by the `budget-*` rules
+    #[serde(skip_serializing_if = "crate::config::is_default")]
+    pub budgets: Budgets,
+}
+
+impl LintConfig {
+    /// Level configured for a rule on a skill, if any
+    pub fn level(&self, skill: &str, rule: &str) -> Option<RuleLevel> {
+        self.skills
+            .get(skill)
+            .and_then(|rules| rules.get(rule))
+            .or_else(|| self.rules.get(rule))
+            .copied()
+    }
+
+    /// Rule ids used in config that no rule has
+    pub fn unknown_rules(&self) -> Vec<String> {
+        let used = self
+            .rules
+            .keys()
+            .chain(self.skills.values().flat_map(|rules| rules.keys()));
+        let mut unknown: Vec<String> = used
+            .filter(|id| crate::validator::find_rule(id).is_none())
+            .cloned()
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+        unknown
+    }
+}
+
+/// Rules a skill switches off with the [`DISABLE_DIRECTIVE`] metadata key
+pub fn disabled_rules(metadata: &std::collections::HashMap<String, String>) -> HashSet<&str> {
+    metadata
+        .get(DISABLE_DIRECTIVE)
+        .map(|rules| {
+            rules
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|r| !r.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skill_levels_win_over_global_ones() {
+        let config: LintConfig = toml::from_str(
+            r#"
+[rules]
+content-required = "off"
+name-format = "warning"
+
+[skills.legacy]
+name-format = "off"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.level("any", "content-required"),
+            Some(RuleLevel::Off)
+        );
+        assert_eq!(config.level("any", "name-format"), Some(RuleLevel::Warning));
+        assert_eq!(config.level("legacy", "name-format"), Some(RuleLevel::Off));
+        assert_eq!(config.level("legacy", "description-length"), None);
+        assert!(config.unknown_rules().is_empty());
+    }
+
+    #[test]
+    fn disable_directive_accepts_commas_and_spaces() {
+        let metadata = [(
+            DISABLE_DIRECTIVE.to_string(),
+            "name-format, description-length  content-required".to_string(),
+        )]
+        .into();
+        let disabled = disabled_rules(&metadata);
+        assert_eq!(disabled.len(), 3);
+        assert!(disabled.contains("description-length"));
+    }
+}
@@ -3,13 +3,22 @@
 //! The SkillManager is the main entry point for interacting with Talent.
 //! It integrates config, skills, targets, validation, and syncing.
 
-use crate::config::Config;
+use crate::config::{Config, ConfigSources};
 use crate::error::{Error, Result};
-use crate::skill::{discover_skills, Skill, ValidationStatus};
-use crate::syncer::{SyncResult, Syncer};
-use crate::target::Target;
+use crate::kinds::{merged_kinds, KindDefinition};
+use crate::manifest::RenameLog;
+use crate::project::{same_dir, Project, Scope};
+use crate::size::SkillSize;
+use crate::skill::{discover_skills, Skill, ValidationStatus, SKILL_FILE_NAME};
+use crate::syncer::{
+    AdoptCandidate, AdoptResult, AdoptSelection, RepairResult, SyncPlan, SyncResult, Syncer,
+};
+use crate::target::{SyncStatus, Target};
 use crate::validator::Validator;
-use std::path::PathBuf;
+use crate::watcher::{ChangeSet, SkillWatcher};
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Main manager for Talent operations
 pub struct SkillManager {
@@ -22,37 +31,78 @@ pub struct SkillManager {
     /// Detected/configured targets
     targets: Vec<Target>,
 
+    /// Project the manager was opened in (if any)
+    project: Option<Project>,
+
     /// Symlink syncer
     syncer: Syncer,
 
+    /// Syncer for project targets (treats links into the project skills directory as managed)
+    project_syncer: Syncer,
+
     /// Skill validator
     validator: Validator,
 }
 
 impl SkillManager {
     /// Create a new SkillManager with default configuration
+    ///
+    /// When run inside a git work tree with project skills, those are merged in.
     pub fn new() -> Result<Self> {
-        let config = Config::load()?;
-        Self::with_config(config)
+        Self::open(None)
+    }
+
+    /// Create a new SkillManager, layering an extra config file over the standard ones
+    pub fn open(config_file: Option<&Path>) -> Result<Self> {
+        let config = Config::load_with(&ConfigSources::discover(config_file)?)?;
+        let cwd = std::env::current_dir().map_err(|e| Error::io(".", e))?;
+        let project = Project::discover(&cwd)?;
+        Self::with_project(config, project)
     }
 
     /// Create a new SkillManager with the given configuration
     pub fn with_config(config: Config) -> Result<Self> {
+        Self::with_project(config, None)
+    }
+
+    /// Create a new SkillManager with the given configuration and project
+    pub fn with_project(config: Config, project: Option<Project>) -> Result<Self> {
         // Ensure skills directory exists
         config.ensure_skills_dir()?;
 
+        // A project whose skills are the global library would sync them twice
+        let project = project.filter(|p| !same_dir(&p.skills_dir, &config.skills_dir));
+
         // Discover skills
-        let skills = discover_skills(&config.skills_dir)?;
+        let skills = Self::discover_all_skills(&config, project.as_ref())?;
 
         // Detect targets and merge with config
-        let targets = Self::load_targets_with_config(&config);
+        let targets = Self::load_all_targets(&config, project.as_ref());
+
+        // Links into the central skills directory are managed by us
+        let mut syncer = Syncer::with_skills_dir(config.skills_dir.clone());
+        let mut project_syncer = match project {
+            Some(ref project) => Syncer::with_skills_dir(project.skills_dir.clone()),
+            None => Syncer::new(),
+        };
+        syncer.link_style = config.preferences.link_style;
+        project_syncer.link_style = config.preferences.link_style;
+        let mut validator = Validator::with_lint(config.lint.clone());
+        validator.known_tools = config
+            .targets
+            .iter()
+            .filter(|(_, target)| target.enabled && !target.known_tools.is_empty())
+            .map(|(id, target)| (id.clone(), target.known_tools.clone()))
+            .collect();
 
         Ok(Self {
             config,
             skills,
             targets,
-            syncer: Syncer::new(),
-            validator: Validator::new(),
+            project,
+            syncer,
+            project_syncer,
+            validator,
         })
     }
 
@@ -71,6 +121,16 @@ impl SkillManager {
         &self.config
     }
 
+    /// Get all target kinds: the built-in ones plus those declared in config
+    pub fn target_kinds(&self) -> Vec<KindDefinition> {
+        merged_kinds(&self.config.kinds)
+    }
+
+    /// Get the project the manager was opened in (if any)
+    pub fn project(&self) -> Option<&Project> {
+        self.project.as_ref()
+    }
+
     /// Get a skill by name
     pub fn get_skill(&self, name: &str) -> Option<&Skill> {
         self.skills.iter().find(|s| s.name() == name)
@@ -83,26 +143,68 @@ impl SkillManager {
 
     /// Refresh the list of skills by re-scanning the directory
     pub fn refresh_skills(&mut self) -> Result<()> {
-        self.skills = discover_skills(&self.config.skills_dir)?;
+        self.skills = Self::discover_all_skills(&self.config, self.project.as_ref())?;
         Ok(())
     }
 
     /// Refresh the list of targets by re-detecting installed CLIs
     pub fn refresh_targets(&mut self) {
-        self.targets = Self::load_targets_with_config(&self.config);
+        self.targets = Self::load_all_targets(&self.config, self.project.as_ref());
+    }
+
+    /// Discover global skills followed by project skills
+    ///
+    /// Names are unique: a project skill named like a global one is left out,
+    /// so syncing from inside a repository never changes user-level targets.
+    fn discover_all_skills(config: &Config, project: Option<&Project>) -> Result<Vec<Skill>> {
+        let mut skills = discover_skills(&config.skills_dir)?;
+        if let Some(project) = project {
+            let global: HashSet<String> = skills.iter().map(|s| s.name().to_string()).collect();
+            skills.extend(
+                project
+                    .discover_skills()?
+                    .into_iter()
+                    .filter(|s| !global.contains(s.name())),
+            );
+        }
+        Ok(skills)
+    }
+
+    /// Load global targets followed by project targets
+    ///
+    /// Project targets pointing at a global target's directory are left out;
+    /// the global syncer already manages it.
+    fn load_all_targets(config: &Config, project: Option<&Project>) -> Vec<Target> {
+        let mut targets = Self::load_targets_with_config(config);
+        if let Some(project) = project {
+            let project_targets: Vec<Target> = project
+                .targets(&merged_kinds(&config.kinds))
+                .into_iter()
+                .filter(|p| {
+                    !targets
+                        .iter()
+                        .any(|t| same_dir(&t.skills_path, &p.skills_path))
+                })
+                .collect();
+            targets.extend(project_targets);
+        }
+        targets
     }
 
     /// Load targets, merging auto-detected with config settings
     fn load_targets_with_config(config: &Config) -> Vec<Target> {
-        use crate::target::TargetKind;
-
+        let kinds = merged_kinds(&config.kinds);
         let mut targets = Vec::new();
 
         // First, add all auto-detected targets
-        for mut target in Target::detect_all() {
+        for mut target in kinds.iter().filter_map(Target::detect_definition) {
             // Check if config has settings for this target
             if let Some(target_config) = config.targets.get(target.id()) {
                 target.enabled = target_config.enabled;
+                target.strategy = target_config.strategy;
+                target.format = target_config.format;
+                target.section_style = target_config.section_style;
+                target.filter = target_config.filter.clone();
                 // Override path if specified in config
                 if let Some(ref custom_path) = target_config.skills_path {
                     target.skills_path = custom_path.clone();
@@ -119,21 +221,53 @@ impl SkillManager {
             }
 
             // Only add if it has a custom path (otherwise it's just a disabled auto-detected one)
-            if let Some(ref custom_path) = target_config.skills_path {
-                // Try to parse the id as a known TargetKind, or treat as custom
-                if let Some(kind) = TargetKind::all().iter().find(|k| k.id() == id) {
-                    let mut target = Target::new(*kind, custom_path.clone());
-                    target.enabled = target_config.enabled;
-                    target.auto_detected = false;
-                    targets.push(target);
+            let Some(ref custom_path) = target_config.skills_path else {
+                continue;
+            };
+
+            // Use the stored kind, or try to find a known or declared kind with this id
+            let definition = target_config
+                .kind
+                .map(|kind| kind.definition())
+                .or_else(|| kinds.iter().find(|k| k.id == *id));
+
+            let mut target = match definition {
+                Some(definition) if !target_config.custom => {
+                    Target::from_definition(definition, custom_path.clone())
                 }
-                // Note: For truly custom targets (not in TargetKind), we'd need to extend the system
-            }
+                // Custom folder targets (and entries saved before the custom flag existed)
+                _ => {
+                    let name = target_config.display_name.clone().unwrap_or_else(|| {
+                        custom_path
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .unwrap_or("folder")
+                            .to_string()
+                    });
+                    Target::new_folder(custom_path.clone(), id.clone(), name)
+                }
+            };
+            target.enabled = target_config.enabled;
+            target.strategy = target_config.strategy;
+            target.format = target_config.format;
+            target.section_style = target_config.section_style;
+            target.filter = target_config.filter.clone();
+            target.auto_detected = false;
+            targets.push(target);
         }
 
         targets
     }
 
+    /// Ask each target's executable for its version (e.g. `claude --version`)
+    ///
+    /// Versions aren't probed during detection because running every tool is slow.
+    pub fn probe_versions(&mut self) {
+        for target in &mut self.targets {
+            target.probe_version();
+        }
+    }
+
     /// Toggle a target's enabled state
     pub fn toggle_target(&mut self, target_id: &str) -> Result<bool> {
         // Find the target
@@ -182,12 +316,11 @@ impl SkillManager {
 
     /// Add a custom target with a specific path
     pub fn add_custom_target(&mut self, target_id: &str, skills_path: PathBuf) -> Result<()> {
-        use crate::target::TargetKind;
-
-        // Validate the target_id is a known kind
-        let kind = TargetKind::all()
-            .iter()
-            .find(|k| k.id() == target_id)
+        // Validate the target_id is a known or declared kind
+        let definition = self
+            .target_kinds()
+            .into_iter()
+            .find(|k| k.id == target_id)
             .ok_or_else(|| Error::UnknownTargetType(target_id.to_string()))?;
 
         // Check if target already exists
@@ -196,7 +329,7 @@ impl SkillManager {
         }
 
         // Create the target
-        let mut target = Target::new(*kind, skills_path.clone());
+        let mut target = Target::from_definition(&definition, skills_path.clone());
         target.enabled = true;
         target.auto_detected = false;
 
@@ -210,6 +343,7 @@ impl SkillManager {
         let target_config = self.config.get_or_create_target(target_id);
         target_config.enabled = true;
         target_config.skills_path = Some(skills_path);
+        target_config.kind = definition.builtin_kind();
         self.config.save()?;
 
         Ok(())
@@ -246,7 +380,7 @@ impl SkillManager {
         let display_name = folder_name.to_string();
 
         // Create the target
-        let mut target = Target::new_folder(path.clone(), target_id.clone(), display_name);
+        let mut target = Target::new_folder(path.clone(), target_id.clone(), display_name.clone());
         target.enabled = true;
 
         // Ensure the directory exists
@@ -259,6 +393,8 @@ impl SkillManager {
         let target_config = self.config.get_or_create_target(&target_id);
         target_config.enabled = true;
         target_config.skills_path = Some(path);
+        target_config.display_name = Some(display_name);
+        target_config.custom = true;
         self.config.save()?;
 
         Ok(target)
@@ -307,37 +443,224 @@ impl SkillManager {
     }
 
     /// Sync all skills to all targets
+    ///
+    /// Global skills go to user-level targets and project skills to project targets.
     pub fn sync_all(&self) -> Vec<SyncResult> {
-        // Filter to only valid skills if validate_on_sync is enabled
-        let skills_to_sync: Vec<_> = if self.config.preferences.validate_on_sync {
-            self.skills
-                .iter()
-                .filter(|s| s.validation_status == ValidationStatus::Valid)
-                .cloned()
-                .collect()
-        } else {
-            self.skills.clone()
-        };
-
-        self.syncer.sync_all(&self.targets, &skills_to_sync)
+        self.targets
+            .iter()
+            .map(|target| {
+                let skills = self.skills_to_sync(target.scope);
+                self.syncer_for(target).sync_target(target, &skills)
+            })
+            .collect()
     }
 
     /// Sync all skills to a specific target
     pub fn sync_target(&self, target_id: &str) -> Option<SyncResult> {
         let target = self.targets.iter().find(|t| t.id() == target_id)?;
+        let skills = self.skills_to_sync(target.scope);
+        Some(self.syncer_for(target).sync_target(target, &skills))
+    }
 
-        // Filter to only valid skills if validate_on_sync is enabled
-        let skills_to_sync: Vec<_> = if self.config.preferences.validate_on_sync {
-            self.skills
-                .iter()
-                .filter(|s| s.validation_status == ValidationStatus::Valid)
-                .cloned()
-                .collect()
-        } else {
-            self.skills.clone()
+    /// Compare a target with the skills it should have, without changing anything
+    pub fn target_status(&self, target_id: &str) -> Option<SyncStatus> {
+        let target = self.targets.iter().find(|t| t.id() == target_id)?;
+        let skills = self.skills_to_sync(target.scope);
+        Some(self.syncer_for(target).status(target, &skills))
+    }
+
+    /// Repair broken links in all targets
+    pub fn repair_all(&self) -> Vec<RepairResult> {
+        self.targets
+            .iter()
+            .map(|target| {
+                let skills = self.skills_to_sync(target.scope);
+                self.syncer_for(target).repair(target, &skills)
+            })
+            .collect()
+    }
+
+    /// Repair broken links in a specific target
+    pub fn repair_target(&self, target_id: &str) -> Option<RepairResult> {
+        let target = self.targets.iter().find(|t| t.id() == target_id)?;
+        let skills = self.skills_to_sync(target.scope);
+        Some(self.syncer_for(target).repair(target, &skills))
+    }
+
+    /// Find native folders in all targets that keep managed skills out
+    pub fn adoptable_skills(&self) -> Vec<AdoptCandidate> {
+        self.targets
+            .iter()
+            .flat_map(|target| {
+                let skills = self.skills_to_sync(target.scope);
+                self.syncer_for(target).adoptable(target, &skills)
+            })
+            .collect()
+    }
+
+    /// Replace the selected native folders with the managed skills
+    ///
+    /// Skills are reloaded afterwards if a central copy was overwritten.
+    pub fn adopt(&mut self, selections: &[AdoptSelection]) -> Vec<AdoptResult> {
+        let results: Vec<AdoptResult> = self
+            .targets
+            .iter()
+            .filter(|target| selections.iter().any(|s| s.target_id == target.id()))
+            .map(|target| {
+                let skills = self.skills_to_sync(target.scope);
+                self.syncer_for(target).adopt(target, &skills, selections)
+            })
+            .collect();
+
+        if results.iter().any(|r| !r.overwritten.is_empty()) && self.refresh_skills().is_ok() {
+            self.validate_all();
+        }
+        results
+    }
+
+    /// Plan a sync of all skills to all targets without changing anything
+    pub fn plan_all(&self) -> Vec<SyncPlan> {
+        self.targets
+            .iter()
+            .map(|target| {
+                let skills = self.skills_to_sync(target.scope);
+                self.syncer_for(target).plan(target, &skills)
+            })
+            .collect()
+    }
+
+    /// Plan a sync of all skills to a specific target without changing anything
+    pub fn plan_target(&self, target_id: &str) -> Option<SyncPlan> {
+        let target = self.targets.iter().find(|t| t.id() == target_id)?;
+        let skills = self.skills_to_sync(target.scope);
+        Some(self.syncer_for(target).plan(target, &skills))
+    }
+
+    /// The syncer responsible for a target's scope
+    fn syncer_for(&self, target: &Target) -> &Syncer {
+        match target.scope {
+            Scope::Global => &self.syncer,
+            Scope::Project => &self.project_syncer,
+        }
+    }
+
+    /// Start watching the skills directories and enabled targets for changes
+    pub fn watch(&self, debounce: Duration) -> Result<SkillWatcher> {
+        let project_skills = self.project.as_ref().map(|p| p.skills_dir.as_path());
+        SkillWatcher::new(
+            &self.config.skills_dir,
+            project_skills,
+            self.enabled_targets(),
+            debounce,
+        )
+    }
+
+    /// Directories [`watch`](Self::watch) would watch right now
+    ///
+    /// Changes when a target is added, enabled or disabled, or when a missing
+    /// skills directory appears; a long-running watcher compares this with
+    /// what it was built from to know when to rebuild.
+    pub fn watched_paths(&self) -> Vec<PathBuf> {
+        let project_skills = self
+            .project
+            .as_ref()
+            .map(|p| p.skills_dir.clone())
+            .filter(|dir| dir.is_dir());
+        std::iter::once(self.config.skills_dir.clone())
+            .chain(project_skills)
+            .chain(
+                self.enabled_targets()
+                    .filter(|t| t.skills_dir_exists())
+                    .map(|t| t.skills_path.clone()),
+            )
+            .collect()
+    }
+
+    /// Reload and revalidate changed skills, then sync incrementally
+    ///
+    /// Only the changed skills are synced, except for targets whose own skills
+    /// directory changed; those get a full sync. Returns one result per synced target.
+    pub fn apply_changes(&mut self, changes: &ChangeSet) -> Vec<SyncResult> {
+        let global_names = self.reload_skills(Scope::Global, &changes.skills);
+        let project_names = self.reload_skills(Scope::Project, &changes.project_skills);
+
+        self.enabled_targets()
+            .filter_map(|target| {
+                let names = match target.scope {
+                    Scope::Global => &global_names,
+                    Scope::Project => &project_names,
+                };
+                let skills = self.skills_to_sync(target.scope);
+                let syncer = self.syncer_for(target);
+
+                if changes.targets.contains(target.id()) {
+                    Some(syncer.sync_target(target, &skills))
+                } else if !names.is_empty() {
+                    let names: HashSet<&str> = names.iter().map(String::as_str).collect();
+                    Some(syncer.sync_skills(target, &skills, &names))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Reload and revalidate skill folders of a scope
+    ///
+    /// Returns the skill names as linked in targets, before and after the
+    /// reload (a skill may have been renamed or deleted).
+    fn reload_skills(&mut self, scope: Scope, folders: &BTreeSet<String>) -> HashSet<String> {
+        let mut names = HashSet::new();
+        let skills_dir = match scope {
+            Scope::Global => self.config.skills_dir.clone(),
+            Scope::Project => match self.project {
+                Some(ref project) => project.skills_dir.clone(),
+                None => return names,
+            },
         };
 
-        Some(self.syncer.sync_target(target, &skills_to_sync))
+        for folder in folders {
+            if let Some(pos) = self
+                .skills
+                .iter()
+                .position(|s| s.scope == scope && s.folder_name() == folder)
+            {
+                names.insert(self.skills.remove(pos).name().to_string());
+            }
+
+            let path = skills_dir.join(folder);
+            if path.join(SKILL_FILE_NAME).is_file() {
+                let mut skill = Skill::load_lenient(&path);
+                skill.scope = scope;
+                // Keep names unique, global skills first (see discover_all_skills)
+                match scope {
+                    Scope::Global => self
+                        .skills
+                        .retain(|s| s.scope == Scope::Global || s.name() != skill.name()),
+                    Scope::Project if self.get_skill(skill.name()).is_some() => continue,
+                    Scope::Project => {}
+                }
+                // Validation results are recorded on the skill itself
+                let _ = self.validator.validate(&mut skill);
+                names.insert(skill.name().to_string());
+                self.skills.push(skill);
+            }
+        }
+
+        names
+    }
+
+    /// Skills of a scope that should be synced to targets of the same scope
+    ///
+    /// Filters to only valid skills if validate_on_sync is enabled.
+    fn skills_to_sync(&self, scope: Scope) -> Vec<Skill> {
+        let validate = self.config.preferences.validate_on_sync;
+        self.skills
+            .iter()
+            .filter(|s| s.scope == scope)
+            .filter(|s| !validate || s.is_valid())
+            .cloned()
+            .collect()
     }
 
     /// Create a new skill (name is automatically converted to kebab-case)
@@ -353,6 +676,9 @@ impl SkillManager {
 
         // Create the skill with normalized name
         let skill = Skill::create(&self.config.skills_dir, &normalized_name, description)?;
+        // The new global skill takes over from a project skill of the same name
+        self.skills
+            .retain(|s| s.scope == Scope::Global || s.name() != skill.name());
         self.skills.push(skill);
 
         // Return a reference to the newly created skill
@@ -367,26 +693,17 @@ impl SkillManager {
             return Err(Error::SkillNotFound(skill_path));
         }
 
-        // Remove symlinks from all targets FIRST (only symlinks, never native content)
-        for target in &self.targets {
-            if target.enabled {
-                let link_path = target.skill_link_path(name);
-                // Only remove if it's actually a symlink — never delete native content
-                if link_path
-                    .symlink_metadata()
-                    .map(|m| m.file_type().is_symlink())
-                    .unwrap_or(false)
-                {
-                    let _ = std::fs::remove_file(&link_path);
-                }
-            }
+        // Remove symlinks from all targets FIRST (only managed links, never native content)
+        for target in self.global_targets() {
+            let _ = self.syncer.unlink_skill(target, name);
         }
 
         // Remove the skill directory
         std::fs::remove_dir_all(&skill_path).map_err(|e| Error::io(&skill_path, e))?;
 
         // Remove from our list
-        self.skills.retain(|s| s.name() != name);
+        self.skills
+            .retain(|s| s.scope != Scope::Global || s.name() != name);
 
         Ok(())
     }
@@ -410,18 +727,18 @@ impl SkillManager {
         }
 
         // Remove old symlinks from all targets first
-        for target in &self.targets {
-            if target.enabled {
-                let old_symlink = target.skill_link_path(old_name);
-                if old_symlink.is_symlink() {
-                    let _ = std::fs::remove_file(&old_symlink);
-                }
-            }
+        for target in self.global_targets() {
+            let _ = self.syncer.unlink_skill(target, old_name);
         }
 
         // Rename the directory
         std::fs::rename(&old_path, &new_path).map_err(|e| Error::io(&old_path, e))?;
 
+        // Remember the old name so links the targets below don't cover can be repaired later
+        let mut renames = RenameLog::load(&self.config.skills_dir).unwrap_or_default();
+        renames.record(old_name, new_name);
+        let _ = renames.save(&self.config.skills_dir);
+
         // Load the skill from the new path (may have old name in YAML)
         let mut skill = Skill::load(&new_path)?;
 
@@ -433,25 +750,23 @@ impl SkillManager {
         }
 
         // Update in our list - find by folder_name since YAML name may have been different
-        if let Some(existing) = self.skills.iter_mut().find(|s| s.folder_name() == old_name) {
+        if let Some(existing) = self
+            .skills
+            .iter_mut()
+            .find(|s| s.scope == Scope::Global && s.folder_name() == old_name)
+        {
             *existing = skill;
         }
 
         // Create new symlinks
-        for target in &self.targets {
-            if target.enabled {
-                let new_symlink = target.skill_link_path(new_name);
-                #[cfg(unix)]
-                let _ = std::os::unix::fs::symlink(&new_path, &new_symlink);
-                #[cfg(windows)]
-                let _ = std::os::windows::fs::symlink_dir(&new_path, &new_symlink);
-            }
+        for target in self.global_targets() {
+            let _ = self.syncer.link_skill(target, new_name, &new_path);
         }
 
         // Return reference to renamed skill - find by folder_name which is now new_name
         self.skills
             .iter()
-            .find(|s| s.folder_name() == new_name)
+            .find(|s| s.scope == Scope::Global && s.folder_name() == new_name)
             .ok_or_else(|| Error::SkillNotFound(new_path))
     }
 
@@ -496,11 +811,14 @@ impl SkillManager {
         self.targets.iter().filter(|t| t.enabled)
     }
 
-    /// Get valid skills
+    /// Get enabled user-level targets (the ones global skills are synced to)
+    fn global_targets(&self) -> impl Iterator<Item = &Target> {
+        self.enabled_targets().filter(|t| t.scope == Scope::Global)
+    }
+
+    /// Get valid skills, including those with warnings
     pub fn valid_skills(&self) -> impl Iterator<Item = &Skill> {
-        self.skills
-            .iter()
-            .filter(|s| s.validation_status == ValidationStatus::Valid)
+        self.skills.iter().filter(|s| s.is_valid())
     }
 
     /// Get invalid skills
@@ -621,10 +939,11 @@ impl SkillManager {
     pub fn stats(&self) -> ManagerStats {
         ManagerStats {
             total_skills: self.skills.len(),
-            valid_skills: self
+            valid_skills: self.skills.iter().filter(|s| s.is_valid()).count(),
+            warning_skills: self
                 .skills
                 .iter()
-                .filter(|s| s.validation_status == ValidationStatus::Valid)
+                .filter(|s| s.validation_status == ValidationStatus::ValidWithWarnings)
                 .count(),
             invalid_skills: self
                 .skills
@@ -633,6 +952,7 @@ impl SkillManager {
                 .count(),
             total_targets: self.targets.len(),
             enabled_targets: self.targets.iter().filter(|t| t.enabled).count(),
+            total_size: self.skills.iter().map(Skill::size).sum(),
         }
     }
 }
@@ -641,10 +961,15 @@ impl SkillManager {
 #[derive(Debug, Clone)]
 pub struct ManagerStats {
     pub total_skills: usize,
+    /// Valid skills, including those with warnings
     pub valid_skills: usize,
+    /// Valid skills that have warnings
+    pub warning_skills: usize,
     pub invalid_skills: usize,
     pub total_targets: usize,
     pub enabled_targets: usize,
+    /// Sizes of all skills added up
+    pub total_size: SkillSize,
 }
 
 #[cfg(test)]
@@ -657,6 +982,7 @@ mod tests {
             skills_dir: temp_dir.path().join("skills"),
             preferences: crate::config::Preferences {
                 validate_on_sync: true,
+                ..Default::default()
             },
             ..Default::default()
         }
@@ -688,13 +1014,29 @@ mod tests {
         let config = create_test_config(&temp);
         let mut manager = SkillManager::with_config(config).unwrap();
 
-        manager.create_skill("valid-skill", "Valid").unwrap();
+        manager
+            .create_skill("valid-skill", "Checks that valid skills pass validation")
+            .unwrap();
+        manager.create_skill("terse-skill", "Terse").unwrap();
+        manager
+            .save_skill_content(
+                "valid-skill",
+                "---\nname: valid-skill\ndescription: Checks that valid skills pass validation\n\
+                 license: MIT\ncompatibility: Any agent\n---\n\n# Valid skill\n",
+            )
+            .unwrap();
 
         let result = manager.validate_skill("valid-skill");
         assert!(result.is_ok());
-
         let skill = manager.get_skill("valid-skill").unwrap();
         assert_eq!(skill.validation_status, ValidationStatus::Valid);
+
+        // Warnings don't stop a skill from syncing
+        assert!(manager.validate_skill("terse-skill").is_ok());
+        let skill = manager.get_skill("terse-skill").unwrap();
+        assert_eq!(skill.validation_status, ValidationStatus::ValidWithWarnings);
+        assert_eq!(manager.stats().warning_skills, 1);
+        assert_eq!(manager.skills_to_sync(Scope::Global).len(), 2);
     }
 
     #[test]
@@ -771,6 +1113,213 @@ mod tests {
         assert!(results.iter().all(|r| r.is_ok()));
     }
 
+    #[test]
+    fn plan_all_leaves_targets_untouched() {
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let mut manager = SkillManager::with_config(config).unwrap();
+
+        manager.create_skill("planned-skill", "Test").unwrap();
+        manager.validate_all();
+
+        let target_path = temp.path().join("target-skills");
+        manager.targets = vec![Target::new(
+            crate::target::TargetKind::ClaudeCode,
+            target_path.clone(),
+        )];
+
+        let plans = manager.plan_all();
+        assert_eq!(plans.len(), 1);
+        assert_eq!(plans[0].create.len(), 1);
+        assert!(!target_path.exists());
+
+        let plan = manager.plan_target("claude-code").unwrap();
+        assert_eq!(plan.create[0].skill, "planned-skill");
+        assert!(manager.plan_target("missing").is_none());
+    }
+
+    #[test]
+    fn apply_changes_syncs_only_changed_skills() {
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let mut manager = SkillManager::with_config(config).unwrap();
+
+        manager.create_skill("kept-skill", "Test").unwrap();
+        manager.create_skill("gone-skill", "Test").unwrap();
+        manager.validate_all();
+
+        let target_path = temp.path().join("target-skills");
+        manager.targets = vec![Target::new(
+            crate::target::TargetKind::ClaudeCode,
+            target_path.clone(),
+        )];
+        manager.sync_all();
+
+        // A new skill appears and another is deleted behind the manager's back
+        let skills_dir = manager.config.skills_dir.clone();
+        Skill::create(&skills_dir, "new-skill", "Test").unwrap();
+        std::fs::remove_dir_all(skills_dir.join("gone-skill")).unwrap();
+
+        let mut changes = ChangeSet::default();
+        changes.skills.insert("new-skill".to_string());
+        changes.skills.insert("gone-skill".to_string());
+
+        let results = manager.apply_changes(&changes);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].created, vec!["new-skill"]);
+        assert_eq!(results[0].removed, vec!["gone-skill"]);
+        assert!(results[0].unchanged.is_empty());
+
+        assert!(manager.get_skill("new-skill").is_some());
+        assert!(manager.get_skill("gone-skill").is_none());
+        assert!(target_path.join("kept-skill").exists());
+
+        // A change inside the target directory triggers a full sync of that target
+        let mut changes = ChangeSet::default();
+        changes.targets.insert("claude-code".to_string());
+        let results = manager.apply_changes(&changes);
+        assert_eq!(results[0].unchanged.len(), 2);
+    }
+
+    #[test]
+    fn project_skills_sync_to_project_targets_only() {
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join(".claude")).unwrap();
+        let project_skills = repo.join(crate::project::PROJECT_SKILLS_DIR);
+        std::fs::create_dir_all(&project_skills).unwrap();
+        Skill::create(&project_skills, "repo-skill", "Repo skill").unwrap();
+
+        let project = Project::discover(&repo).unwrap();
+        let mut manager = SkillManager::with_project(config, project).unwrap();
+        manager.create_skill("global-skill", "Test").unwrap();
+        manager.validate_all();
+
+        let global_path = temp.path().join("target-skills");
+        manager.targets.retain(|t| t.scope == Scope::Project);
+        manager.targets.push(Target::new(
+            crate::target::TargetKind::ClaudeCode,
+            global_path.clone(),
+        ));
+
+        let results = manager.sync_all();
+        assert!(results.iter().all(|r| r.is_success()));
+
+        let project_target = repo.join(".claude/skills");
+        assert!(project_target.join("repo-skill").exists());
+        assert!(!project_target.join("global-skill").exists());
+        assert!(global_path.join("global-skill").exists());
+        assert!(!global_path.join("repo-skill").exists());
+
+        let repo_skill = manager.get_skill("repo-skill").unwrap();
+        assert_eq!(repo_skill.scope, Scope::Project);
+    }
+
+    #[test]
+    fn watched_paths_follow_targets() {
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        let notes = temp.path().join("notes");
+        let target_config = config.get_or_create_target("folder-notes");
+        target_config.enabled = true;
+        target_config.skills_path = Some(notes.clone());
+        target_config.custom = true;
+
+        let mut manager = SkillManager::with_config(config).unwrap();
+        manager.targets.retain(|t| t.id() == "folder-notes");
+        let before = manager.watched_paths();
+        assert!(!before.contains(&notes));
+
+        // A target directory that appears later is picked up
+        std::fs::create_dir_all(&notes).unwrap();
+        assert!(manager.watched_paths().contains(&notes));
+
+        manager.targets[0].enabled = false;
+        assert_eq!(manager.watched_paths(), before);
+    }
+
+    #[test]
+    fn project_never_duplicates_global_skills_or_targets() {
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        std::fs::create_dir_all(&config.skills_dir).unwrap();
+        Skill::create(&config.skills_dir, "shared", "Global version").unwrap();
+        let global_target = temp.path().join("claude-skills");
+        let target_config = config.get_or_create_target("claude-code");
+        target_config.enabled = true;
+        target_config.skills_path = Some(global_target.clone());
+
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        let project_skills = repo.join(crate::project::PROJECT_SKILLS_DIR);
+        std::fs::create_dir_all(&project_skills).unwrap();
+        Skill::create(&project_skills, "shared", "Project version").unwrap();
+        Skill::create(&project_skills, "repo-only", "Project skill").unwrap();
+        // A project target configured onto a global target's directory
+        std::fs::write(
+            repo.join(crate::project::PROJECT_CONFIG_FILE_NAME),
+            format!(
+                "[targets.claude-code]\nenabled = true\nskills_path = {:?}\n",
+                global_target
+            ),
+        )
+        .unwrap();
+
+        let project = Project::discover(&repo).unwrap();
+        let manager = SkillManager::with_project(config.clone(), project).unwrap();
+
+        let shared: Vec<_> = manager
+            .skills()
+            .iter()
+            .filter(|s| s.name() == "shared")
+            .collect();
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].scope, Scope::Global);
+        assert!(manager.get_skill("repo-only").is_some());
+        assert!(!manager.targets().iter().any(|t| t.scope == Scope::Project));
+
+        // A project whose skills dir is the global library is ignored
+        let mut project = Project::discover(&repo).unwrap().unwrap();
+        project.skills_dir = config.skills_dir.clone();
+        let manager = SkillManager::with_project(config, Some(project)).unwrap();
+        assert!(manager.project().is_none());
+        assert_eq!(manager.skills().len(), 1);
+    }
+
+    #[test]
+    fn folder_targets_reload_from_config() {
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+
+        let notes_path = temp.path().join("notes");
+        let target_config = config.get_or_create_target("folder-notes");
+        target_config.enabled = false;
+        target_config.skills_path = Some(notes_path.clone());
+        target_config.display_name = Some("My Notes".to_string());
+        target_config.custom = true;
+
+        // Entry written before the custom flag existed
+        let legacy_path = temp.path().join("legacy");
+        config.get_or_create_target("folder-legacy").skills_path = Some(legacy_path.clone());
+
+        let targets = SkillManager::load_targets_with_config(&config);
+
+        let notes = targets.iter().find(|t| t.id() == "folder-notes").unwrap();
+        assert!(notes.kind.is_none());
+        assert_eq!(notes.name(), "My Notes");
+        assert_eq!(notes.skills_path, notes_path);
+        assert!(!notes.enabled);
+        assert!(!notes.auto_detected);
+
+        let legacy = targets.iter().find(|t| t.id() == "folder-legacy").unwrap();
+        assert!(legacy.kind.is_none());
+        assert_eq!(legacy.name(), "legacy");
+        assert!(legacy.enabled);
+    }
+
     #[test]
     fn rename_skill_updates_folder_and_yaml() {
         let temp = TempDir::new().unwrap();
@@ -846,4 +1395,91 @@ description: A test skill
         assert!(!updated.contains("name: old-name"));
         assert!(updated.contains("description: A test skill"));
     }
+
+    #[test]
+    fn declared_kinds_are_detected() {
+        let temp = TempDir::new().unwrap();
+        let tool_dir = temp.path().join("tool");
+        std::fs::create_dir_all(&tool_dir).unwrap();
+
+        let mut config = create_test_config(&temp);
+        let kinds: toml::Table = toml::from_str(&format!(
+            "[tool]\ndisplay_name = \"Tool\"\nconfig_dir = {:?}\ndetect = [{{ dir_exists = {:?} }}]\n",
+            tool_dir.display().to_string(),
+            tool_dir.display().to_string(),
+        ))
+        .unwrap();
+        config.kinds = toml::Value::Table(kinds).try_into().unwrap();
+
+        let manager = SkillManager::with_config(config).unwrap();
+        let target = manager.targets().iter().find(|t| t.id() == "tool").unwrap();
+        assert_eq!(target.name(), "Tool");
+        assert_eq!(target.skills_path, tool_dir.join("skills"));
+        assert!(target.auto_detected);
+        assert!(manager.target_kinds().iter().any(|k| k.id == "tool"));
+    }
+
+    #[test]
+    fn target_status_reports_without_changing_anything() {
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let mut manager = SkillManager::with_config(config).unwrap();
+
+        manager.create_skill("status-skill", "Test").unwrap();
+        manager.validate_all();
+
+        let target_path = temp.path().join("target-skills");
+        manager.targets = vec![Target::new(
+            crate::target::TargetKind::ClaudeCode,
+            target_path.clone(),
+        )];
+
+        let status = manager.target_status("claude-code").unwrap();
+        assert!(!status.is_synced);
+        assert_eq!(status.missing_skills, vec!["status-skill"]);
+        assert!(!target_path.exists());
+
+        manager.sync_all();
+        assert!(manager.target_status("claude-code").unwrap().is_synced);
+        assert!(manager.target_status("missing").is_none());
+    }
+
+    #[test]
+    fn adopt_overwrite_reloads_the_skill() {
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let mut manager = SkillManager::with_config(config).unwrap();
+        manager.create_skill("adopted", "Central").unwrap();
+        manager.validate_all();
+
+        let target_path = temp.path().join("target-skills");
+        manager.targets = vec![Target::new(
+            crate::target::TargetKind::ClaudeCode,
+            target_path.clone(),
+        )];
+        let native = target_path.join("adopted");
+        std::fs::create_dir_all(&native).unwrap();
+        std::fs::write(
+            native.join(SKILL_FILE_NAME),
+            "---\nname: adopted\ndescription: Native\n---\n\nFrom the tool.\n",
+        )
+        .unwrap();
+
+        let candidates = manager.adoptable_skills();
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates[0].conflict.is_some());
+
+        let results = manager.adopt(&[AdoptSelection {
+            target_id: "claude-code".to_string(),
+            skill: "adopted".to_string(),
+            resolution: crate::importer::ConflictResolution::Overwrite,
+        }]);
+        assert!(results[0].is_success());
+        assert_eq!(
+            manager.get_skill("adopted").unwrap().meta.description,
+            "Native"
+        );
+        assert!(manager.adoptable_skills().is_empty());
+        assert!(manager.target_status("claude-code").unwrap().is_synced);
+    }
 }
@@ -0,0 +1,345 @@
+//! Ownership manifest for links created by AgentLoom
+//!
+//! Each target skills directory gets a `.agentloom-manifest.json` file that
+//! records the links AgentLoom created there. The syncer uses it to tell its
+//! own links apart from symlinks a user made by hand, so cleanup never removes
+//! something AgentLoom doesn't own. Files generated for non-folder target
+//! formats are recorded the same way.
+//!
+//! The central skills directory keeps a [`RenameLog`], so links left pointing
+//! at a skill's old folder can be repaired after it was renamed.
+
+use crate::error::{Error, Result};
+use crate::syncer::SyncStrategy;
+use crate::target::TargetFormat;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the manifest file inside a target's skills directory
+pub const MANIFEST_FILE_NAME: &str = ".agentloom-manifest.json";
+
+/// Name of the rename log inside the central skills directory
+pub const RENAME_LOG_FILE_NAME: &str = ".agentloom-renames.json";
+
+/// A link recorded in the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Where the link points (the skill directory in central storage)
+    pub destination: PathBuf,
+
+    /// When AgentLoom created the link
+    pub created_at: DateTime<Utc>,
+
+    /// How the skill was placed into the target
+    #[serde(default)]
+    pub strategy: SyncStrategy,
+
+    /// Content hash of a materialized copy or generated file at the time it was written
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+
+    /// Format of a generated file (skill folders for links and copies)
+    #[serde(default, skip_serializing_if = "crate::config::is_default")]
+    pub format: TargetFormat,
+}
+
+impl ManifestEntry {
+    /// Check if the entry is a file generated by a format renderer
+    pub fn is_generated(&self) -> bool {
+        !self.format.is_skill_folder()
+    }
+
+    /// Check if the entry is a materialized copy of a skill folder (not a link or generated file)
+    pub fn is_copy(&self) -> bool {
+        self.strategy.is_materialized() && !self.is_generated()
+    }
+}
+
+/// Links AgentLoom created in a single target skills directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Recorded links (key = link name, which is the skill name)
+    pub links: BTreeMap<String, ManifestEntry>,
+}
+
+impl Manifest {
+    /// Get the manifest path for a target skills directory
+    pub fn path_in(skills_path: &Path) -> PathBuf {
+        skills_path.join(MANIFEST_FILE_NAME)
+    }
+
+    /// Load the manifest from a target skills directory
+    ///
+    /// Returns an empty manifest if the file doesn't exist yet.
+    pub fn load(skills_path: &Path) -> Result<Self> {
+        let path = Self::path_in(skills_path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Save the manifest into a target skills directory
+    ///
+    /// An empty manifest removes the file so the directory is left clean.
+    pub fn save(&self, skills_path: &Path) -> Result<()> {
+        let path = Self::path_in(skills_path);
+
+        if self.links.is_empty() {
+            if path.exists() {
+                fs::remove_file(&path).map_err(|e| Error::io(&path, e))?;
+            }
+            return Ok(());
+        }
+
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&path, contents).map_err(|e| Error::io(&path, e))
+    }
+
+    /// Check if a link with this name was created by AgentLoom
+    pub fn owns(&self, name: &str) -> bool {
+        self.links.contains_key(name)
+    }
+
+    /// Record a link, keeping the original timestamp if it already points to the same place
+    ///
+    /// Returns true if the manifest changed.
+    pub fn record(&mut self, name: &str, destination: &Path) -> bool {
+        if self.links.get(name).is_some_and(|entry| {
+            entry.destination == destination && entry.strategy == SyncStrategy::Symlink
+        }) {
+            return false;
+        }
+
+        self.links.insert(
+            name.to_string(),
+            ManifestEntry {
+                destination: destination.to_path_buf(),
+                created_at: Utc::now(),
+                strategy: SyncStrategy::Symlink,
+                content_hash: None,
+                format: TargetFormat::SkillFolder,
+            },
+        );
+        true
+    }
+
+    /// Record a materialized copy of a skill along with its content hash
+    ///
+    /// Returns true if the manifest changed.
+    pub fn record_copy(
+        &mut self,
+        name: &str,
+        source: &Path,
+        strategy: SyncStrategy,
+        content_hash: String,
+    ) -> bool {
+        if let Some(entry) = self.links.get_mut(name) {
+            if entry.destination == source && entry.strategy == strategy {
+                if entry.content_hash.as_deref() == Some(content_hash.as_str()) {
+                    return false;
+                }
+                entry.content_hash = Some(content_hash);
+                return true;
+            }
+        }
+
+        self.links.insert(
+            name.to_string(),
+            ManifestEntry {
+                destination: source.to_path_buf(),
+                created_at: Utc::now(),
+                strategy,
+                content_hash: Some(content_hash),
+                format: TargetFormat::SkillFolder,
+            },
+        );
+        true
+    }
+
+    /// Record a file generated from `source` in a target format
+    ///
+    /// Returns true if the manifest changed.
+    pub fn record_generated(
+        &mut self,
+        name: &str,
+        source: &Path,
+        format: TargetFormat,
+        content_hash: String,
+    ) -> bool {
+        if let Some(entry) = self.links.get_mut(name) {
+            if entry.destination == source && entry.format == format {
+                if entry.content_hash.as_deref() == Some(content_hash.as_str()) {
+                    return false;
+                }
+                entry.content_hash = Some(content_hash);
+                return true;
+            }
+        }
+
+        self.links.insert(
+            name.to_string(),
+            ManifestEntry {
+                destination: source.to_path_buf(),
+                created_at: Utc::now(),
+                strategy: SyncStrategy::Copy,
+                content_hash: Some(content_hash),
+                format,
+            },
+        );
+        true
+    }
+
+    /// Record a file AgentLoom created to hold a managed section
+    ///
+    /// Only such files are deleted once their section is gone. Returns true
+    /// if the manifest changed.
+    pub fn record_section_file(&mut self, name: &str, source: &Path) -> bool {
+        if self.owns(name) {
+            return false;
+        }
+
+        self.links.insert(
+            name.to_string(),
+            ManifestEntry {
+                destination: source.to_path_buf(),
+                created_at: Utc::now(),
+                strategy: SyncStrategy::Section,
+                content_hash: None,
+                format: TargetFormat::SkillFolder,
+            },
+        );
+        true
+    }
+
+    /// Forget a link. Returns true if it was recorded.
+    pub fn forget(&mut self, name: &str) -> bool {
+        self.links.remove(name).is_some()
+    }
+}
+
+/// Skill folders renamed by AgentLoom (key = old folder name, value = new folder name)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RenameLog {
+    /// Recorded renames, kept pointing at each skill's latest name
+    pub renames: BTreeMap<String, String>,
+}
+
+impl RenameLog {
+    /// Load the rename log from the central skills directory
+    ///
+    /// Returns an empty log if the file doesn't exist yet.
+    pub fn load(skills_dir: &Path) -> Result<Self> {
+        let path = skills_dir.join(RENAME_LOG_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Save the rename log into the central skills directory
+    pub fn save(&self, skills_dir: &Path) -> Result<()> {
+        let path = skills_dir.join(RENAME_LOG_FILE_NAME);
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&path, contents).map_err(|e| Error::io(&path, e))
+    }
+
+    /// Record that a skill folder was renamed
+    ///
+    /// Earlier names of the skill are updated to point at the new one, and a
+    /// skill renamed back to an old name no longer counts as renamed.
+    pub fn record(&mut self, old: &str, new: &str) {
+        for current in self.renames.values_mut() {
+            if current == old {
+                *current = new.to_string();
+            }
+        }
+        self.renames.insert(old.to_string(), new.to_string());
+        self.renames.retain(|old, new| old != new);
+        self.renames.remove(new);
+    }
+
+    /// Get the current folder name of a skill that used to be called `old`
+    pub fn resolve(&self, old: &str) -> Option<&str> {
+        self.renames.get(old).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn load_returns_empty_when_missing() {
+        let temp = TempDir::new().unwrap();
+        let manifest = Manifest::load(temp.path()).unwrap();
+        assert!(manifest.links.is_empty());
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let mut manifest = Manifest::default();
+        assert!(manifest.record("my-skill", Path::new("/skills/my-skill")));
+
+        manifest.save(temp.path()).unwrap();
+        let loaded = Manifest::load(temp.path()).unwrap();
+
+        assert!(loaded.owns("my-skill"));
+        assert_eq!(
+            loaded.links["my-skill"].destination,
+            PathBuf::from("/skills/my-skill")
+        );
+    }
+
+    #[test]
+    fn record_keeps_timestamp_for_same_destination() {
+        let mut manifest = Manifest::default();
+        manifest.record("my-skill", Path::new("/skills/my-skill"));
+        let created_at = manifest.links["my-skill"].created_at;
+
+        assert!(!manifest.record("my-skill", Path::new("/skills/my-skill")));
+        assert_eq!(manifest.links["my-skill"].created_at, created_at);
+        assert!(manifest.record("my-skill", Path::new("/elsewhere/my-skill")));
+    }
+
+    #[test]
+    fn saving_empty_manifest_removes_file() {
+        let temp = TempDir::new().unwrap();
+        let mut manifest = Manifest::default();
+        manifest.record("my-skill", Path::new("/skills/my-skill"));
+        manifest.save(temp.path()).unwrap();
+        assert!(Manifest::path_in(temp.path()).exists());
+
+        manifest.forget("my-skill");
+        manifest.save(temp.path()).unwrap();
+        assert!(!Manifest::path_in(temp.path()).exists());
+    }
+
+    #[test]
+    fn rename_log_follows_chains() {
+        let temp = TempDir::new().unwrap();
+        let mut log = RenameLog::default();
+        log.record("a", "b");
+        log.record("b", "c");
+        assert_eq!(log.resolve("a"), Some("c"));
+        assert_eq!(log.resolve("b"), Some("c"));
+
+        // Renaming back to an old name drops it
+        log.record("c", "a");
+        assert_eq!(log.resolve("a"), None);
+        assert_eq!(log.resolve("b"), Some("a"));
+
+        log.save(temp.path()).unwrap();
+        let loaded = RenameLog::load(temp.path()).unwrap();
+        assert_eq!(loaded.resolve("c"), Some("a"));
+    }
+}
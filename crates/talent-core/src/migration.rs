@@ -1,6 +1,7 @@
 //! Migration module for upgrading from previous versions
 //!
-//! Handles automatic migration of skills from old directory locations.
+//! Handles automatic migration of skills from old directory locations, and
+//! upgrades config files to the current schema version one step at a time.
 
 use crate::error::{Error, Result};
 use crate::skill::SKILL_FILE_NAME;
@@ -17,6 +18,42 @@ const CURRENT_DIR_NAME: &str = ".agents";
 /// Skills subdirectory name
 const SKILLS_DIR: &str = "skills";
 
+/// Current config schema version
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Schema version of config files written before `config_version` existed
+const UNVERSIONED_CONFIG: u32 = 1;
+
+/// A step that upgrades a config file from one schema version to the next
+struct ConfigMigration {
+    /// Version this step upgrades from (to `from + 1`)
+    from: u32,
+
+    /// What the step changes
+    description: &'static str,
+
+    /// Rewrite the document in place
+    apply: fn(&mut toml_edit::Document),
+}
+
+/// Config migrations in order; each version needs exactly one step
+const CONFIG_MIGRATIONS: &[ConfigMigration] = &[ConfigMigration {
+    from: 1,
+    description:
+        "v1 -> v2: a skills_dir under the legacy ~/.agentloom is pointed at ~/.agents/skills",
+    apply: move_legacy_skills_dir,
+}];
+
+/// Result of migrating a config file
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConfigMigrationResult {
+    /// Version the file declared before migrating
+    pub from_version: u32,
+
+    /// Descriptions of the steps that were applied
+    pub applied: Vec<&'static str>,
+}
+
 /// Result of a migration operation
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct MigrationResult {
@@ -171,6 +208,71 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Upgrade a config document to [`CONFIG_VERSION`]
+///
+/// Files without `config_version` are treated as version 1. Files from a
+/// newer version are left untouched: this build can't know what changed.
+pub fn migrate_config(document: &mut toml_edit::Document) -> ConfigMigrationResult {
+    let from_version = match document.get("config_version") {
+        None => UNVERSIONED_CONFIG,
+        // Values of the wrong type are reported when the config is deserialized
+        Some(item) => match item.as_integer().and_then(|v| u32::try_from(v).ok()) {
+            Some(version) => version,
+            None => {
+                return ConfigMigrationResult {
+                    from_version: CONFIG_VERSION,
+                    applied: Vec::new(),
+                }
+            }
+        },
+    };
+
+    let mut result = ConfigMigrationResult {
+        from_version,
+        applied: Vec::new(),
+    };
+    if from_version >= CONFIG_VERSION {
+        return result;
+    }
+
+    for step in CONFIG_MIGRATIONS.iter().filter(|m| m.from >= from_version) {
+        (step.apply)(document);
+        result.applied.push(step.description);
+    }
+    document["config_version"] = toml_edit::value(i64::from(CONFIG_VERSION));
+
+    result
+}
+
+/// v1 -> v2: point skills_dir at the current directory if it still names the legacy one
+///
+/// `migrate_if_needed` moves the skills and deletes the legacy directory, so
+/// an old path would leave the config pointing at nothing.
+fn move_legacy_skills_dir(document: &mut toml_edit::Document) {
+    let (Some(legacy), Some(home)) = (legacy_skills_dir(), dirs::home_dir()) else {
+        return;
+    };
+    let current = home.join(CURRENT_DIR_NAME).join(SKILLS_DIR);
+
+    let Some(value) = document
+        .get_mut("skills_dir")
+        .and_then(toml_edit::Item::as_value_mut)
+    else {
+        return;
+    };
+    let Some(path) = value.as_str() else {
+        return;
+    };
+
+    let legacy_tilde = Path::new("~").join(LEGACY_DIR_NAME).join(SKILLS_DIR);
+    if Path::new(path) == legacy || Path::new(path) == legacy_tilde {
+        // Keep comments attached to the value
+        let decor = value.decor().clone();
+        *value = current.to_string_lossy().as_ref().into();
+        *value.decor_mut() = decor;
+    }
+}
+
 /// Get the legacy skills directory path
 pub fn legacy_skills_dir() -> Option<PathBuf> {
     dirs::home_dir().map(|h| h.join(LEGACY_DIR_NAME).join(SKILLS_DIR))
@@ -264,4 +366,58 @@ mod tests {
         assert_eq!(result.skills_count, 0);
         assert!(result.skill_names.is_empty());
     }
+
+    #[test]
+    fn migrate_config_upgrades_unversioned_files() {
+        let legacy = legacy_skills_dir().unwrap();
+        let mut document: toml_edit::Document = format!(
+            "skills_dir = \"{}\" # old home\n\n[targets.codex]\nenabled = true\n",
+            legacy.display()
+        )
+        .parse()
+        .unwrap();
+
+        let result = migrate_config(&mut document);
+        assert_eq!(result.from_version, 1);
+        assert_eq!(result.applied.len(), 1);
+
+        let current = dirs::home_dir()
+            .unwrap()
+            .join(CURRENT_DIR_NAME)
+            .join(SKILLS_DIR);
+        assert_eq!(
+            document["skills_dir"].as_str(),
+            Some(current.to_string_lossy().as_ref())
+        );
+        assert!(document.to_string().contains("# old home"));
+        assert_eq!(
+            document["config_version"].as_integer(),
+            Some(i64::from(CONFIG_VERSION))
+        );
+
+        // Already current: nothing to do
+        assert!(migrate_config(&mut document).applied.is_empty());
+    }
+
+    #[test]
+    fn migrate_config_leaves_newer_files_alone() {
+        let contents = "config_version = 99\nskills_dir = \"/skills\"\nnew_setting = 1\n";
+        let mut document: toml_edit::Document = contents.parse().unwrap();
+
+        let result = migrate_config(&mut document);
+        assert_eq!(result.from_version, 99);
+        assert!(result.applied.is_empty());
+        assert_eq!(document.to_string(), contents);
+    }
+
+    #[test]
+    fn config_migrations_cover_every_version() {
+        for (i, step) in CONFIG_MIGRATIONS.iter().enumerate() {
+            assert_eq!(step.from, UNVERSIONED_CONFIG + i as u32);
+        }
+        assert_eq!(
+            UNVERSIONED_CONFIG + CONFIG_MIGRATIONS.len() as u32,
+            CONFIG_VERSION
+        );
+    }
 }
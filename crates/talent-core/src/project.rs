@@ -0,0 +1,279 @@
+//! Project-scoped skills and targets
+//!
+//! Inside a git work tree, a repository can carry its own skills under
+//! `<repo>/.agents/skills` and settings in `<repo>/agentloom.toml`. Project
+//! skills are merged with the global library and synced into project-level
+//! target directories such as `<repo>/.claude/skills`.
+
+use crate::config::TargetConfig;
+use crate::error::{Error, Result};
+use crate::kinds::KindDefinition;
+use crate::skill::{discover_skills, Skill};
+use crate::target::Target;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project configuration file name (at the repository root)
+pub const PROJECT_CONFIG_FILE_NAME: &str = "agentloom.toml";
+
+/// Default project skills directory (relative to the repository root)
+pub const PROJECT_SKILLS_DIR: &str = ".agents/skills";
+
+/// Where a skill or target comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    /// The user's global library (`~/.agents/skills`) and user-level tool directories
+    #[default]
+    Global,
+    /// A repository's `.agents/skills` and project-level tool directories
+    Project,
+}
+
+impl Scope {
+    /// Get the display name for this scope
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Scope::Global => "global",
+            Scope::Project => "project",
+        }
+    }
+}
+
+/// Contents of a project's `agentloom.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectConfig {
+    /// Project skills directory, relative to the repository root
+    pub skills_dir: Option<PathBuf>,
+
+    /// Project target overrides (key = target kind id, paths relative to the repository root)
+    pub targets: HashMap<String, TargetConfig>,
+}
+
+/// A repository with project-scoped skills
+#[derive(Debug, Clone)]
+pub struct Project {
+    /// Repository root (the directory containing `.git`)
+    pub root: PathBuf,
+
+    /// Project skills directory
+    pub skills_dir: PathBuf,
+
+    /// Settings from `agentloom.toml` (defaults if the file doesn't exist)
+    pub config: ProjectConfig,
+}
+
+impl Project {
+    /// Find the project containing `start`, if any
+    ///
+    /// Walks up to the nearest git work tree root. A project is only returned
+    /// when that root has a `.agents/skills` directory or an `agentloom.toml`.
+    pub fn discover(start: &Path) -> Result<Option<Self>> {
+        let Some(root) = find_repo_root(start) else {
+            return Ok(None);
+        };
+
+        let config_path = root.join(PROJECT_CONFIG_FILE_NAME);
+        let has_config = config_path.exists();
+        let config = if has_config {
+            let contents = fs::read_to_string(&config_path).map_err(|e| Error::ConfigLoad {
+                path: config_path.clone(),
+                source: Box::new(e),
+            })?;
+            toml::from_str(&contents).map_err(|e| Error::ConfigLoad {
+                path: config_path.clone(),
+                source: Box::new(e),
+            })?
+        } else {
+            ProjectConfig::default()
+        };
+
+        let skills_dir = root.join(
+            config
+                .skills_dir
+                .as_deref()
+                .unwrap_or(Path::new(PROJECT_SKILLS_DIR)),
+        );
+
+        if !has_config && !skills_dir.is_dir() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            root,
+            skills_dir,
+            config,
+        }))
+    }
+
+    /// Discover the project's skills
+    pub fn discover_skills(&self) -> Result<Vec<Skill>> {
+        let mut skills = discover_skills(&self.skills_dir)?;
+        for skill in &mut skills {
+            skill.scope = Scope::Project;
+        }
+        Ok(skills)
+    }
+
+    /// Project-level targets for the given kinds
+    ///
+    /// A tool gets a project target when its directory (e.g. `.claude`) exists
+    /// in the repository, or when `agentloom.toml` configures it. Kinds without
+    /// a project directory are skipped.
+    pub fn targets(&self, kinds: &[KindDefinition]) -> Vec<Target> {
+        let mut targets = Vec::new();
+
+        for kind in kinds {
+            let Some(ref project_dir) = kind.project_dir else {
+                continue;
+            };
+            let target_config = self.config.targets.get(&kind.id);
+            let tool_dir = self.root.join(project_dir);
+
+            if target_config.is_none() && !tool_dir.is_dir() {
+                continue;
+            }
+
+            let skills_path = target_config
+                .and_then(|c| c.skills_path.as_ref())
+                .map(|p| self.root.join(p))
+                .unwrap_or_else(|| tool_dir.join(kind.skills_subdir()));
+
+            let mut target = Target::new_project(kind, skills_path);
+            if let Some(target_config) = target_config {
+                target.enabled = target_config.enabled;
+                target.strategy = target_config.strategy;
+                target.format = target_config.format;
+                target.section_style = target_config.section_style;
+                target.filter = target_config.filter.clone();
+            }
+            targets.push(target);
+        }
+
+        targets
+    }
+}
+
+/// Find the root of the git work tree containing `start`
+///
+/// `.git` is a directory in a normal checkout and a file in linked worktrees
+/// and submodules; either marks the root. The home directory is never a
+/// root: with dotfiles kept in a repository there, every directory under home
+/// would otherwise be part of one project whose tool directories are the
+/// user-level ones.
+pub(crate) fn find_repo_root(start: &Path) -> Option<PathBuf> {
+    repo_root_below(start, dirs::home_dir().as_deref())
+}
+
+fn repo_root_below(start: &Path, home: Option<&Path>) -> Option<PathBuf> {
+    let root = start.ancestors().find(|dir| dir.join(".git").exists())?;
+    if home.is_some_and(|home| same_dir(root, home)) {
+        return None;
+    }
+    Some(root.to_path_buf())
+}
+
+/// Check if two paths name the same directory, resolving symlinks when both exist
+pub(crate) fn same_dir(a: &Path, b: &Path) -> bool {
+    a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kinds::merged_kinds;
+    use crate::target::TargetKind;
+    use tempfile::TempDir;
+
+    fn create_repo(temp: &TempDir) -> PathBuf {
+        let root = temp.path().join("repo");
+        fs::create_dir_all(root.join(".git")).unwrap();
+        root
+    }
+
+    #[test]
+    fn no_project_outside_git() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join(".agents/skills")).unwrap();
+        // No .git anywhere above a temp dir
+        assert!(Project::discover(temp.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn home_directory_is_never_a_project_root() {
+        let temp = TempDir::new().unwrap();
+        let home = create_repo(&temp);
+        let nested = home.join("code/tool");
+        fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(repo_root_below(&nested, Some(&home)), None);
+        assert_eq!(repo_root_below(&nested, None), Some(home.clone()));
+
+        // Repositories inside home still count
+        fs::create_dir_all(nested.join(".git")).unwrap();
+        assert_eq!(repo_root_below(&nested, Some(&home)), Some(nested));
+    }
+
+    #[test]
+    fn no_project_without_skills_or_config() {
+        let temp = TempDir::new().unwrap();
+        let root = create_repo(&temp);
+        assert!(Project::discover(&root).unwrap().is_none());
+    }
+
+    #[test]
+    fn discovers_project_from_subdirectory() {
+        let temp = TempDir::new().unwrap();
+        let root = create_repo(&temp);
+        fs::create_dir_all(root.join(PROJECT_SKILLS_DIR)).unwrap();
+        Skill::create(&root.join(PROJECT_SKILLS_DIR), "repo-skill", "Repo skill").unwrap();
+
+        let nested = root.join("src/deep");
+        fs::create_dir_all(&nested).unwrap();
+
+        let project = Project::discover(&nested).unwrap().unwrap();
+        assert_eq!(project.root, root);
+
+        let skills = project.discover_skills().unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].scope, Scope::Project);
+    }
+
+    #[test]
+    fn project_targets_from_tool_dirs_and_config() {
+        let temp = TempDir::new().unwrap();
+        let root = create_repo(&temp);
+        fs::create_dir_all(root.join(".claude")).unwrap();
+        fs::write(
+            root.join(PROJECT_CONFIG_FILE_NAME),
+            r#"
+skills_dir = "skills"
+
+[targets.codex]
+enabled = true
+skills_path = "tools/codex-skills"
+"#,
+        )
+        .unwrap();
+
+        let project = Project::discover(&root).unwrap().unwrap();
+        assert_eq!(project.skills_dir, root.join("skills"));
+
+        let targets = project.targets(&merged_kinds(&Default::default()));
+        assert_eq!(targets.len(), 2);
+
+        let claude = targets
+            .iter()
+            .find(|t| t.kind == Some(TargetKind::ClaudeCode));
+        assert_eq!(claude.unwrap().skills_path, root.join(".claude/skills"));
+
+        let codex = targets.iter().find(|t| t.kind == Some(TargetKind::Codex));
+        let codex = codex.unwrap();
+        assert_eq!(codex.skills_path, root.join("tools/codex-skills"));
+        assert_eq!(codex.scope, Scope::Project);
+        assert_eq!(codex.id(), "project:codex");
+    }
+}
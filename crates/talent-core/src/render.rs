@@ -0,0 +1,422 @@
+//! Renderers for tools that don't read `SKILL.md` folders
+//!
+//! Each [`TargetFormat`](crate::target::TargetFormat) other than skill folders
+//! has a [`FormatRenderer`] here that turns skills into the files the tool
+//! reads. Every generated file carries an ownership marker, so the syncer can
+//! tell its own files apart from the user's even without a manifest.
+//!
+//! Section targets (see [`SyncStrategy::Section`](crate::syncer::SyncStrategy))
+//! don't own a whole file: they keep a block between [`SECTION_BEGIN`] and
+//! [`SECTION_END`] up to date inside a file such as `AGENTS.md` or `CLAUDE.md`.
+
+use crate::error::{Error, Result};
+use crate::skill::{Skill, SKILL_FILE_NAME};
+use crate::target::{FormatRenderer, RenderedFile};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Text that marks a file as generated by AgentLoom
+pub const GENERATED_MARKER: &str = "agentloom:generated";
+
+/// Check if file contents carry the ownership marker
+///
+/// Only whole marker lines count, so a file that merely mentions the marker
+/// isn't mistaken for ours.
+pub fn is_generated(contents: &str) -> bool {
+    contents.lines().any(|line| {
+        let line = line.trim();
+        line.strip_prefix("<!-- ")
+            .or_else(|| line.strip_prefix("# "))
+            .is_some_and(|rest| rest.starts_with(GENERATED_MARKER))
+    })
+}
+
+/// Check if a file exists and carries the ownership marker
+pub fn file_is_generated(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|contents| is_generated(&contents))
+}
+
+/// Build the marker sentence for a generated file
+fn marker(source: &str) -> String {
+    format!("{GENERATED_MARKER} from {source}; edits are overwritten on sync")
+}
+
+/// Cursor rules: one `<name>.mdc` file per skill
+///
+/// Cursor's frontmatter has `description`, `globs` and `alwaysApply`; rules
+/// are attached on demand based on their description.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CursorRuleRenderer;
+
+/// Cursor rule frontmatter
+#[derive(Serialize)]
+struct CursorFrontmatter<'a> {
+    description: &'a str,
+    globs: &'a str,
+    #[serde(rename = "alwaysApply")]
+    always_apply: bool,
+}
+
+impl FormatRenderer for CursorRuleRenderer {
+    fn render(&self, skills: &[&Skill]) -> Vec<RenderedFile> {
+        skills
+            .iter()
+            .map(|skill| {
+                let frontmatter = serde_yaml::to_string(&CursorFrontmatter {
+                    description: skill.description(),
+                    globs: "",
+                    always_apply: false,
+                })
+                .unwrap_or_default();
+
+                RenderedFile {
+                    name: format!("{}.mdc", skill.name()),
+                    source: skill.path.clone(),
+                    contents: format!(
+                        "---\n{}---\n<!-- {} -->\n\n{}\n",
+                        frontmatter,
+                        marker(skill.name()),
+                        skill.content.trim()
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Gemini CLI custom commands: one `<name>.toml` file per skill
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiCommandRenderer;
+
+/// Gemini command file contents
+#[derive(Serialize)]
+struct GeminiCommand<'a> {
+    description: &'a str,
+    prompt: &'a str,
+}
+
+impl FormatRenderer for GeminiCommandRenderer {
+    fn render(&self, skills: &[&Skill]) -> Vec<RenderedFile> {
+        skills
+            .iter()
+            .map(|skill| {
+                let command = toml::to_string_pretty(&GeminiCommand {
+                    description: skill.description(),
+                    prompt: skill.content.trim(),
+                })
+                .unwrap_or_default();
+
+                RenderedFile {
+                    name: format!("{}.toml", skill.name()),
+                    source: skill.path.clone(),
+                    contents: format!("# {}\n{}", marker(skill.name()), command),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A single `AGENTS.md` listing every skill with its description and location
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AgentsMdRenderer;
+
+/// File name of the aggregated skills list
+pub const AGENTS_MD_FILE_NAME: &str = "AGENTS.md";
+
+impl FormatRenderer for AgentsMdRenderer {
+    fn render(&self, skills: &[&Skill]) -> Vec<RenderedFile> {
+        // No skills, no file (a previously generated one is removed)
+        let Some(first) = skills.first() else {
+            return Vec::new();
+        };
+        let source = first
+            .path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(PathBuf::new);
+
+        let contents = format!(
+            "<!-- {} -->\n# Skills\n\n{}",
+            marker(&source.display().to_string()),
+            render_section(skills, SectionStyle::List)
+        );
+
+        vec![RenderedFile {
+            name: AGENTS_MD_FILE_NAME.to_string(),
+            source,
+            contents,
+        }]
+    }
+
+    fn aggregates(&self) -> bool {
+        true
+    }
+}
+
+/// Start of the block AgentLoom manages inside a markdown file
+pub const SECTION_BEGIN: &str = "<!-- agentloom:begin skills -->";
+
+/// End of the block AgentLoom manages inside a markdown file
+pub const SECTION_END: &str = "<!-- agentloom:end skills -->";
+
+/// How skills appear in a managed section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SectionStyle {
+    /// Each skill's name, description and `SKILL.md` path
+    #[default]
+    List,
+    /// Each skill's full instructions
+    Inline,
+}
+
+/// Render the skills for a managed section (without the markers)
+///
+/// Returns an empty string when there are no skills. Skill text that would
+/// read as a marker line is indented so it can't end the block early.
+pub fn render_section(skills: &[&Skill], style: SectionStyle) -> String {
+    if skills.is_empty() {
+        return String::new();
+    }
+
+    let mut body = match style {
+        SectionStyle::List => "Read a skill's file before using it.\n".to_string(),
+        SectionStyle::Inline => {
+            "Follow a skill's instructions when its description applies.\n".to_string()
+        }
+    };
+    for skill in skills {
+        body.push_str(&format!(
+            "\n## {}\n\n{}\n",
+            skill.name(),
+            escape_markers(skill.description())
+        ));
+        match style {
+            SectionStyle::List => body.push_str(&format!(
+                "\nFile: {}\n",
+                skill.path.join(SKILL_FILE_NAME).display()
+            )),
+            SectionStyle::Inline => {
+                let content = skill.content.trim();
+                if !content.is_empty() {
+                    body.push_str(&format!("\n{}\n", escape_markers(content)));
+                }
+            }
+        }
+    }
+    body
+}
+
+/// Check if a line is a section marker (markers only count on their own line)
+fn is_marker_line(line: &str, marker: &str) -> bool {
+    line.trim_end() == marker
+}
+
+/// Indent lines that would read as a section marker
+fn escape_markers(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            if is_marker_line(line, SECTION_BEGIN) || is_marker_line(line, SECTION_END) {
+                format!(" {line}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Find the first marker line at or after `from`, including its line break
+fn find_marker(document: &str, marker: &str, from: usize) -> Option<Range<usize>> {
+    let mut offset = from;
+    for line in document[from..].split_inclusive('\n') {
+        if is_marker_line(line, marker) {
+            return Some(offset..offset + line.len());
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Find the managed section in a document, including its markers and line break
+///
+/// `path` is only used for the error when the end marker is missing.
+fn find_section(document: &str, path: &Path) -> Result<Option<Range<usize>>> {
+    let Some(begin) = find_marker(document, SECTION_BEGIN, 0) else {
+        return Ok(None);
+    };
+    let Some(end) = find_marker(document, SECTION_END, begin.end) else {
+        return Err(Error::UnterminatedSection(path.to_path_buf()));
+    };
+    Ok(Some(begin.start..end.end))
+}
+
+/// Put `body` into the managed section of `document`
+///
+/// An existing section is replaced in place; otherwise one is appended. An
+/// empty body removes the section. Text outside the markers is kept as it is.
+pub fn splice_section(document: &str, body: &str, path: &Path) -> Result<String> {
+    let block = if body.is_empty() {
+        String::new()
+    } else {
+        format!("{SECTION_BEGIN}\n{body}{SECTION_END}\n")
+    };
+
+    if let Some(range) = find_section(document, path)? {
+        let (mut before, mut after) = (&document[..range.start], &document[range.end..]);
+        if block.is_empty() && before.ends_with("\n\n") {
+            // Drop the blank line that separated the section from the text before it
+            if after.is_empty() {
+                before = &before[..before.len() - 1];
+            } else if let Some(rest) = after.strip_prefix('\n') {
+                after = rest;
+            }
+        }
+        return Ok(format!("{before}{block}{after}"));
+    }
+
+    if block.is_empty() {
+        return Ok(document.to_string());
+    }
+
+    // Appended after a blank line, so the section doesn't run into the user's text
+    let separator = match document {
+        "" => "",
+        d if d.ends_with("\n\n") => "",
+        d if d.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+    Ok(format!("{document}{separator}{block}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_skill(temp: &TempDir) -> Skill {
+        let mut skill = Skill::create(temp.path(), "py-lint", "Lint Python files").unwrap();
+        // Colons need quoting in Cursor's YAML frontmatter
+        skill.meta.description = "Lint: Python files".to_string();
+        skill.content = "# Lint\n\nRun ruff.\n".to_string();
+        skill
+    }
+
+    #[test]
+    fn cursor_rule_has_frontmatter_and_marker() {
+        let temp = TempDir::new().unwrap();
+        let skill = test_skill(&temp);
+
+        let files = CursorRuleRenderer.render(&[&skill]);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, "py-lint.mdc");
+        assert_eq!(files[0].source, skill.path);
+
+        let contents = &files[0].contents;
+        assert!(contents.starts_with("---\ndescription: 'Lint: Python files'\n"));
+        assert!(contents.contains("alwaysApply: false\n---\n"));
+        assert!(contents.ends_with("# Lint\n\nRun ruff.\n"));
+        assert!(is_generated(contents));
+    }
+
+    #[test]
+    fn gemini_command_is_valid_toml() {
+        let temp = TempDir::new().unwrap();
+        let skill = test_skill(&temp);
+
+        let files = GeminiCommandRenderer.render(&[&skill]);
+        assert_eq!(files[0].name, "py-lint.toml");
+        assert!(is_generated(&files[0].contents));
+
+        let parsed: toml::Table = toml::from_str(&files[0].contents).unwrap();
+        assert_eq!(parsed["description"].as_str(), Some("Lint: Python files"));
+        assert_eq!(parsed["prompt"].as_str(), Some("# Lint\n\nRun ruff."));
+    }
+
+    #[test]
+    fn agents_md_lists_every_skill() {
+        let temp = TempDir::new().unwrap();
+        let first = test_skill(&temp);
+        let second = Skill::create(temp.path(), "rust-fmt", "Format Rust").unwrap();
+
+        let files = AgentsMdRenderer.render(&[&first, &second]);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, AGENTS_MD_FILE_NAME);
+        assert_eq!(files[0].source, temp.path());
+
+        let contents = &files[0].contents;
+        assert!(is_generated(contents));
+        assert!(contents.contains("## py-lint\n\nLint: Python files\n"));
+        assert!(contents.contains(&format!(
+            "File: {}",
+            second.path.join(SKILL_FILE_NAME).display()
+        )));
+
+        assert!(AgentsMdRenderer.render(&[]).is_empty());
+    }
+
+    #[test]
+    fn marker_must_be_its_own_line() {
+        assert!(is_generated("# agentloom:generated from x\nrest"));
+        assert!(!is_generated("Mentions agentloom:generated inline"));
+        assert!(!is_generated("plain file"));
+    }
+
+    #[test]
+    fn section_is_appended_replaced_and_removed() {
+        let temp = TempDir::new().unwrap();
+        let skill = test_skill(&temp);
+        let path = Path::new("AGENTS.md");
+        let document = "# Project\n\nOur rules.\n";
+
+        let body = render_section(&[&skill], SectionStyle::Inline);
+        assert!(body.contains("## py-lint\n\nLint: Python files\n\n# Lint\n\nRun ruff.\n"));
+
+        let with_section = splice_section(document, &body, path).unwrap();
+        assert!(with_section.starts_with(document));
+        assert!(with_section.ends_with(&format!("{SECTION_BEGIN}\n{body}{SECTION_END}\n")));
+
+        // Replacing only changes the block
+        let edited = with_section.replace("Our rules.", "Our edited rules.") + "\nFooter\n";
+        let listed = render_section(&[&skill], SectionStyle::List);
+        let replaced = splice_section(&edited, &listed, path).unwrap();
+        assert!(replaced.starts_with("# Project\n\nOur edited rules.\n\n"));
+        assert!(replaced.ends_with(&format!("{SECTION_END}\n\nFooter\n")));
+        assert!(replaced.contains(&format!("{SECTION_BEGIN}\n{listed}{SECTION_END}\n")));
+
+        // An empty body removes the block
+        let removed = splice_section(&replaced, "", path).unwrap();
+        assert_eq!(removed, "# Project\n\nOur edited rules.\n\nFooter\n");
+        assert_eq!(splice_section(&with_section, "", path).unwrap(), document);
+    }
+
+    #[test]
+    fn markers_inside_skill_text_do_not_end_the_section() {
+        let temp = TempDir::new().unwrap();
+        let mut skill = test_skill(&temp);
+        skill.content = format!(
+            "Blocks end with `{SECTION_END}`.\n\n```\n{SECTION_BEGIN}\n{SECTION_END}\n```\n"
+        );
+        let path = Path::new("CLAUDE.md");
+
+        let body = render_section(&[&skill], SectionStyle::Inline);
+        let document = splice_section("# Notes\n", &body, path).unwrap();
+        assert_eq!(document.matches(&format!("\n{SECTION_END}\n")).count(), 1);
+
+        // Syncing again replaces the whole block instead of leaving a tail behind
+        assert_eq!(splice_section(&document, &body, path).unwrap(), document);
+        assert_eq!(splice_section(&document, "", path).unwrap(), "# Notes\n");
+    }
+
+    #[test]
+    fn unterminated_section_is_an_error() {
+        let path = Path::new("CLAUDE.md");
+        let document = format!("intro\n{SECTION_BEGIN}\nhalf\n");
+        assert!(matches!(
+            splice_section(&document, "body\n", path),
+            Err(Error::UnterminatedSection(_))
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+//! Files bundled with a skill and the references SKILL.md makes to them
+//!
+//! Skill folders often ship `scripts/`, `references/` and `assets/` next to
+//! `SKILL.md`. References are found without a full markdown parser:
+//! - inline links and images: `[text](references/api.md)`
+//! - reference definitions: `[api]: references/api.md`
+//! - code spans naming a bundled path: `` `scripts/check.sh` ``
+//!
+//! Fenced code blocks are skipped, and URLs and `#anchors` are ignored.
+
+use crate::skill::SKILL_FILE_NAME;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Conventional folders for bundled files (per agentskills.io spec)
+pub const RESOURCE_DIRS: &[&str] = &["scripts", "references", "assets"];
+
+/// A relative file reference in SKILL.md
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// Target as written, without `#fragment` or `?query`
+    pub target: String,
+
+    /// Line in SKILL.md (1-based)
+    pub line: usize,
+}
+
+impl Reference {
+    /// Target as a path relative to the skill directory
+    pub fn path(&self) -> PathBuf {
+        PathBuf::from(self.target.replace("%20", " "))
+    }
+
+    /// Check if the target leaves the skill directory
+    pub fn escapes(&self) -> bool {
+        escapes(&self.path())
+    }
+}
+
+/// Find the relative file references in a SKILL.md file
+///
+/// Line numbers count from the start of the file, frontmatter included.
+pub fn find_references(skill_md: &str) -> Vec<Reference> {
+    let mut references = Vec::new();
+    let mut in_frontmatter = false;
+    let mut fence: Option<&str> = None;
+
+    for (index, line) in skill_md.lines().enumerate() {
+        let trimmed = line.trim_start();
+
+        // Frontmatter only at the very start
+        if index == 0 && trimmed.trim_end() == "---" {
+            in_frontmatter = true;
+            continue;
+        }
+        if in_frontmatter {
+            in_frontmatter = trimmed.trim_end() != "---";
+            continue;
+        }
+
+        match fence {
+            Some(marker) => {
+                if trimmed.starts_with(marker) {
+                    fence = None;
+                }
+                continue;
+            }
+            None => {
+                if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+                    fence = Some(marker);
+                    continue;
+                }
+            }
+        }
+
+        let mut add = |target: &str| {
+            if let Some(target) = clean_target(target) {
+                references.push(Reference {
+                    target,
+                    line: index + 1,
+                });
+            }
+        };
+
+        if let Some(target) = reference_definition(trimmed) {
+            add(target);
+            continue;
+        }
+        for target in inline_link_targets(line) {
+            add(target);
+        }
+        for span in code_spans(line) {
+            if is_bundled_path(span) {
+                add(span);
+            }
+        }
+    }
+
+    references
+}
+
+/// Files bundled with a skill, relative to its directory
+///
+/// SKILL.md, hidden files and folders, and top-level README and LICENSE
+/// files are left out.
+pub fn bundled_files(skill_dir: &Path) -> Vec<PathBuf> {
+    WalkDir::new(skill_dir)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| !e.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.path().strip_prefix(skill_dir).ok().map(Path::to_path_buf))
+        .filter(|relative| {
+            let top_level = relative.components().count() == 1;
+            let name = relative.to_string_lossy().to_uppercase();
+            !(top_level
+                && (name == SKILL_FILE_NAME.to_uppercase()
+                    || name.starts_with("README")
+                    || name.starts_with("LICENSE")))
+        })
+        .collect()
+}
+
+/// Check if a relative path leaves the directory it is relative to
+pub fn escapes(path: &Path) -> bool {
+    let mut depth = 0usize;
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => return true,
+            Component::ParentDir => match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => return true,
+            },
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+        }
+    }
+    false
+}
+
+/// Strip fragments and queries, dropping URLs and in-page anchors
+fn clean_target(target: &str) -> Option<String> {
+    let target = target.trim().trim_start_matches('<').trim_end_matches('>');
+    if target.is_empty() || target.starts_with('#') || target.contains(':') {
+        // Also drops Windows drive paths, which no skill should link to anyway
+        return None;
+    }
+    let end = target.find(['#', '?']).unwrap_or(target.len());
+    let target = target[..end].trim_start_matches("./");
+    (!target.is_empty()).then(|| target.to_string())
+}
+
+/// Target of a `[label]: target` line
+fn reference_definition(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix('[')?;
+    let (label, rest) = rest.split_once("]:")?;
+    if label.is_empty() || label.starts_with('^') {
+        return None;
+    }
+    rest.split_whitespace().next()
+}
+
+/// Targets of `[text](target)` and `![alt](target)` links on a line
+fn inline_link_targets(line: &str) -> Vec<&str> {
+    let mut targets = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("](") {
+        rest = &rest[start + 2..];
+        let end = rest.find(')').unwrap_or(rest.len());
+        let inside = &rest[..end];
+        // A title may follow the target: [text](path "title")
+        let target = if inside.starts_with('<') {
+            inside.split('>').next().unwrap_or(inside)
+        } else {
+            inside.split_whitespace().next().unwrap_or("")
+        };
+        targets.push(target);
+        rest = &rest[end..];
+    }
+    targets
+}
+
+/// Contents of single-backtick code spans on a line
+fn code_spans(line: &str) -> Vec<&str> {
+    line.split('`')
+        .enumerate()
+        .filter(|(i, _)| i % 2 == 1)
+        .map(|(_, span)| span.trim())
+        .collect()
+}
+
+/// Check if a code span names a file in one of the [`RESOURCE_DIRS`]
+fn is_bundled_path(span: &str) -> bool {
+    !span.contains(char::is_whitespace)
+        && RESOURCE_DIRS.iter().any(|dir| {
+            span.trim_start_matches("./")
+                .strip_prefix(dir)
+                .is_some_and(|rest| rest.len() > 1 && rest.starts_with('/'))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_links_definitions_and_code_spans() {
+        let skill_md = r#"---
+name: my-skill
+description: See [docs](references/ignored.md)
+---
+
+# My Skill
+
+Read [the API](references/api.md#auth "API") and ![logo](./assets/logo.png).
+Run `scripts/check.sh --all` or `scripts/check.sh`, not `cargo build`.
+Visit [the site](https://example.com) or [jump](#usage).
+
+```bash
+cat references/in-fence.md
+```
+
+[guide]: references/guide.md
+"#;
+        let references = find_references(skill_md);
+        let found: Vec<(&str, usize)> = references
+            .iter()
+            .map(|r| (r.target.as_str(), r.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("references/api.md", 8),
+                ("assets/logo.png", 8),
+                ("scripts/check.sh", 9),
+                ("references/guide.md", 16),
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes_detects_parent_and_absolute_paths() {
+        assert!(!escapes(Path::new("references/../assets/logo.png")));
+        assert!(escapes(Path::new("../other-skill/SKILL.md")));
+        assert!(escapes(Path::new("references/../../secret")));
+        assert!(escapes(Path::new("/etc/passwd")));
+    }
+}
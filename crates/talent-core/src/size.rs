@@ -0,0 +1,239 @@
+//! Size and token estimates for skills
+//!
+//! Skills are loaded into an agent's context: the frontmatter of every skill
+//! up front, the body when the skill is used, and reference files when the
+//! body points the agent at them. Each part is measured in bytes, lines and
+//! an approximate token count, so budgets can be enforced offline without a
+//! real tokenizer.
+
+use crate::resources;
+use crate::skill::Skill;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::ops::{Add, AddAssign};
+use std::path::{Path, PathBuf};
+
+/// Budgets for the parts of a skill, enforced by the `budget-*` validator rules
+///
+/// A budget of 0 means no limit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Budgets {
+    /// Estimated tokens of the frontmatter, which agents always load
+    pub frontmatter_tokens: usize,
+
+    /// Estimated tokens of the body, loaded when the skill is used
+    pub body_tokens: usize,
+
+    /// Lines of the body
+    pub body_lines: usize,
+
+    /// Estimated tokens of all files under `references/`
+    pub references_tokens: usize,
+}
+
+impl Default for Budgets {
+    fn default() -> Self {
+        Self {
+            frontmatter_tokens: 0,
+            body_tokens: 5000,
+            body_lines: 500,
+            references_tokens: 0,
+        }
+    }
+}
+
+/// Size of a piece of text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSize {
+    /// Length in bytes
+    pub bytes: usize,
+
+    /// Number of lines
+    pub lines: usize,
+
+    /// Approximate number of tokens (see [`estimate_tokens`])
+    pub tokens: usize,
+}
+
+impl TextSize {
+    /// Measure a piece of text
+    pub fn of(text: &str) -> Self {
+        Self {
+            bytes: text.len(),
+            lines: text.lines().count(),
+            tokens: estimate_tokens(text),
+        }
+    }
+}
+
+impl Add for TextSize {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            lines: self.lines + other.lines,
+            tokens: self.tokens + other.tokens,
+        }
+    }
+}
+
+impl AddAssign for TextSize {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// Size of each part of a skill
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillSize {
+    /// YAML frontmatter, without the `---` lines
+    pub frontmatter: TextSize,
+
+    /// Markdown after the frontmatter
+    pub body: TextSize,
+
+    /// Text files under `references/` (binary files are skipped)
+    pub references: TextSize,
+}
+
+impl SkillSize {
+    /// Measure all parts of a skill, reading SKILL.md and its references from disk
+    pub fn measure(skill: &Skill) -> Self {
+        let raw = skill.raw_content().ok();
+        Self::from_files(
+            skill,
+            raw.as_deref(),
+            &resources::bundled_files(&skill.path),
+        )
+    }
+
+    /// Measure a skill from SKILL.md as already read and its bundled files
+    /// (see [`resources::bundled_files`])
+    pub fn from_files(skill: &Skill, raw: Option<&str>, bundled: &[PathBuf]) -> Self {
+        Self {
+            frontmatter: frontmatter_size(skill, raw),
+            body: TextSize::of(&skill.content),
+            references: references_size(&skill.path, bundled),
+        }
+    }
+
+    /// Size of all parts together
+    pub fn total(&self) -> TextSize {
+        self.frontmatter + self.body + self.references
+    }
+}
+
+impl Add for SkillSize {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            frontmatter: self.frontmatter + other.frontmatter,
+            body: self.body + other.body,
+            references: self.references + other.references,
+        }
+    }
+}
+
+impl std::iter::Sum for SkillSize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Size of a skill's frontmatter as written in SKILL.md (`raw`)
+///
+/// Falls back to the parsed metadata when the file couldn't be read.
+pub fn frontmatter_size(skill: &Skill, raw: Option<&str>) -> TextSize {
+    let from_file = raw.and_then(|raw| {
+        let rest = raw.trim_start().strip_prefix("---")?;
+        let end = rest.find("\n---")?;
+        Some(TextSize::of(rest[..end].trim_matches('\n')))
+    });
+    from_file
+        .unwrap_or_else(|| TextSize::of(&serde_yaml::to_string(&skill.meta).unwrap_or_default()))
+}
+
+/// Size of the text files under a skill's `references/` folder
+///
+/// `bundled` lists the skill's files (see [`resources::bundled_files`]).
+pub fn references_size(skill_dir: &Path, bundled: &[PathBuf]) -> TextSize {
+    bundled
+        .iter()
+        .filter(|file| file.starts_with("references"))
+        .filter_map(|file| fs::read_to_string(skill_dir.join(file)).ok())
+        .map(|text| TextSize::of(&text))
+        .fold(TextSize::default(), Add::add)
+}
+
+/// Approximate the number of tokens a language model tokenizer produces
+///
+/// Runs of ASCII letters and digits count one token per four characters
+/// (at least one), other ASCII symbols one each, and non-ASCII characters
+/// one each. This tracks common BPE tokenizers within about 20% on English
+/// prose and markdown, which is close enough for budgets.
+pub fn estimate_tokens(text: &str) -> usize {
+    let mut tokens = 0;
+    let mut run: usize = 0;
+
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            run += 1;
+            continue;
+        }
+        tokens += run.div_ceil(4);
+        run = 0;
+        if !c.is_whitespace() {
+            tokens += 1;
+        }
+    }
+    tokens + run.div_ceil(4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::SKILL_FILE_NAME;
+    use tempfile::TempDir;
+
+    #[test]
+    fn estimate_tokens_counts_words_and_symbols() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("a"), 1);
+        assert_eq!(estimate_tokens("skill"), 2);
+        assert_eq!(estimate_tokens("Run the tests."), 5);
+        assert_eq!(estimate_tokens("`cargo test --all`"), 8);
+        assert_eq!(estimate_tokens("日本語"), 3);
+    }
+
+    #[test]
+    fn measure_splits_frontmatter_body_and_references() {
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("sized-skill");
+        fs::create_dir_all(skill_dir.join("references")).unwrap();
+        fs::write(
+            skill_dir.join(SKILL_FILE_NAME),
+            "---\nname: sized-skill\ndescription: Measured\n---\n\nLine one\nLine two\n",
+        )
+        .unwrap();
+        fs::write(skill_dir.join("references/api.md"), "# API\nDetails\n").unwrap();
+        fs::write(skill_dir.join("notes.md"), "Not a reference\n").unwrap();
+
+        let skill = Skill::load(&skill_dir).unwrap();
+        let size = SkillSize::measure(&skill);
+
+        assert_eq!(size.frontmatter.lines, 2);
+        assert_eq!(
+            size.frontmatter.bytes,
+            "name: sized-skill\ndescription: Measured".len()
+        );
+        assert_eq!(size.body.lines, skill.content.lines().count());
+        assert_eq!(size.references, TextSize::of("# API\nDetails\n"));
+        assert_eq!(
+            size.total().tokens,
+            size.frontmatter.tokens + size.body.tokens + size.references.tokens
+        );
+    }
+}
@@ -12,9 +12,14 @@
 //! ```
 
 use crate::error::{Error, Result};
+use crate::lint::{self, Diagnostic, Severity};
+use crate::project::Scope;
+use crate::size::SkillSize;
+use crate::tools::{parse_allowed_tools, AllowedTool};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use walkdir::WalkDir;
 
 /// The filename we look for in skill directories
@@ -332,6 +337,8 @@ pub enum ValidationStatus {
     Unknown,
     /// Passed validation
     Valid,
+    /// Passed validation, with warnings that don't block syncing
+    ValidWithWarnings,
     /// Failed validation
     Invalid,
 }
@@ -351,8 +358,14 @@ pub struct Skill {
     /// Current validation status
     pub validation_status: ValidationStatus,
 
-    /// Validation errors (if any)
-    pub validation_errors: Vec<String>,
+    /// Problems found while loading and validating (errors, warnings and notes)
+    pub diagnostics: Vec<Diagnostic>,
+
+    /// Whether the skill lives in the global library or a project
+    pub scope: Scope,
+
+    /// Size, measured on first use or while validating
+    pub(crate) size: OnceLock<SkillSize>,
 }
 
 impl Skill {
@@ -373,7 +386,9 @@ impl Skill {
             content,
             path: skill_dir.to_path_buf(),
             validation_status: ValidationStatus::Unknown,
-            validation_errors: Vec::new(),
+            diagnostics: Vec::new(),
+            scope: Scope::Global,
+            size: OnceLock::new(),
         })
     }
 
@@ -400,7 +415,13 @@ impl Skill {
                     content: String::new(),
                     path: skill_dir.to_path_buf(),
                     validation_status: ValidationStatus::Invalid,
-                    validation_errors: vec![format!("Cannot read SKILL.md: {}", e)],
+                    diagnostics: vec![Diagnostic::new(
+                        lint::UNREADABLE,
+                        Severity::Error,
+                        format!("Cannot read SKILL.md: {}", e),
+                    )],
+                    scope: Scope::Global,
+                    size: OnceLock::new(),
                 };
             }
         };
@@ -412,17 +433,20 @@ impl Skill {
                 content,
                 path: skill_dir.to_path_buf(),
                 validation_status: ValidationStatus::Unknown,
-                validation_errors: Vec::new(),
+                diagnostics: Vec::new(),
+                scope: Scope::Global,
+                size: OnceLock::new(),
             },
             Err(e) => {
                 // Parsing failed - try normalizing the frontmatter
                 let (partial_meta, raw_content, normalize_result) =
                     Self::parse_with_normalization(&contents, &folder_name);
 
-                let mut errors = vec![e.to_string()];
+                let mut diagnostic =
+                    Diagnostic::new(lint::FRONTMATTER_SYNTAX, Severity::Error, e.to_string());
                 if normalize_result.was_modified {
-                    errors.push(format!(
-                        "Frontmatter can be auto-fixed: {}",
+                    diagnostic.fix = Some(format!(
+                        "can be auto-fixed: {}",
                         normalize_result.fixes.join(", ")
                     ));
                 }
@@ -432,7 +456,9 @@ impl Skill {
                     content: raw_content,
                     path: skill_dir.to_path_buf(),
                     validation_status: ValidationStatus::Invalid,
-                    validation_errors: errors,
+                    diagnostics: vec![diagnostic],
+                    scope: Scope::Global,
+                    size: OnceLock::new(),
                 }
             }
         }
@@ -587,9 +613,42 @@ description: {}
         &self.meta.description
     }
 
-    /// Check if this skill has been validated successfully
+    /// Check if this skill has been validated successfully (warnings allowed)
     pub fn is_valid(&self) -> bool {
-        self.validation_status == ValidationStatus::Valid
+        matches!(
+            self.validation_status,
+            ValidationStatus::Valid | ValidationStatus::ValidWithWarnings
+        )
+    }
+
+    /// Get the well-formed entries of the `allowed-tools` field
+    ///
+    /// Malformed entries are left out; the validator reports them.
+    pub fn allowed_tools(&self) -> Vec<AllowedTool> {
+        self.meta
+            .allowed_tools
+            .as_deref()
+            .map(|value| {
+                parse_allowed_tools(value)
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Size of the skill's frontmatter, body and reference files
+    ///
+    /// Measured once and kept until the skill is saved or reloaded.
+    pub fn size(&self) -> SkillSize {
+        *self.size.get_or_init(|| SkillSize::measure(self))
+    }
+
+    /// Get the diagnostics of a given severity
+    pub fn diagnostics_of(&self, severity: Severity) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(move |d| d.severity == severity)
     }
 
     /// Get the raw content of the SKILL.md file
@@ -607,6 +666,7 @@ description: {}
 
         // Always write the file first - user should be able to save work-in-progress
         fs::write(&skill_file, content).map_err(|e| Error::io(&skill_file, e))?;
+        self.size = OnceLock::new();
 
         // Try to re-parse the frontmatter to update metadata
         // If parsing fails, keep old metadata but mark as invalid
@@ -616,13 +676,17 @@ description: {}
                 self.content = parsed_content;
                 // Reset validation status since content changed
                 self.validation_status = ValidationStatus::Unknown;
-                self.validation_errors.clear();
+                self.diagnostics.clear();
             }
             Err(e) => {
                 // Save succeeded but parsing failed - mark as invalid
                 self.content = content.to_string();
                 self.validation_status = ValidationStatus::Invalid;
-                self.validation_errors = vec![e.to_string()];
+                self.diagnostics = vec![Diagnostic::new(
+                    lint::FRONTMATTER_SYNTAX,
+                    Severity::Error,
+                    e.to_string(),
+                )];
             }
         }
 
@@ -680,11 +744,7 @@ description: {}
 
     /// Check if this skill has fixable frontmatter issues
     pub fn has_fixable_errors(&self) -> bool {
-        !self.validation_errors.is_empty()
-            && self
-                .validation_errors
-                .iter()
-                .any(|e| e.contains("can be auto-fixed"))
+        self.diagnostics.iter().any(|d| d.fix.is_some())
     }
 }
 
@@ -710,11 +770,13 @@ pub fn discover_skills(skills_dir: &Path) -> Result<Vec<Skill>> {
             if let Some(skill_dir) = path.parent() {
                 // Use lenient loading to capture errors instead of skipping
                 let skill = Skill::load_lenient(skill_dir);
-                if !skill.validation_errors.is_empty() {
+                if !skill.diagnostics.is_empty() {
+                    let messages: Vec<String> =
+                        skill.diagnostics.iter().map(ToString::to_string).collect();
                     eprintln!(
                         "Warning: Skill '{}' has errors: {}",
                         skill.folder_name(),
-                        skill.validation_errors.join(", ")
+                        messages.join(", ")
                     );
                 }
                 skills.push(skill);
@@ -851,6 +913,29 @@ description: No closing delimiter
         assert!(skills.is_empty());
     }
 
+    #[test]
+    fn size_is_kept_until_the_skill_is_saved() {
+        let temp = TempDir::new().unwrap();
+        let mut skill = Skill::create(temp.path(), "sized", "A sized skill").unwrap();
+        let references = skill.path.join("references");
+        fs::create_dir_all(&references).unwrap();
+        fs::write(references.join("api.md"), "# API\n").unwrap();
+
+        // Validation measures the skill from the files it reads anyway
+        let _ = crate::validator::Validator::new().validate(&mut skill);
+        assert_eq!(skill.size().references.bytes, "# API\n".len());
+
+        fs::write(references.join("api.md"), "# API\n\nMore details\n").unwrap();
+        assert_eq!(skill.size().references.bytes, "# API\n".len());
+
+        let raw = skill.raw_content().unwrap();
+        skill.save_content(&raw).unwrap();
+        assert_eq!(
+            skill.size().references.bytes,
+            "# API\n\nMore details\n".len()
+        );
+    }
+
     #[test]
     fn raw_content_returns_full_file() {
         let temp = TempDir::new().unwrap();
@@ -925,8 +1010,9 @@ Content here.
         assert_eq!(skill.name(), "broken-skill");
         assert_eq!(skill.description(), "A skill with invalid metadata");
         assert_eq!(skill.validation_status, ValidationStatus::Invalid);
-        assert!(!skill.validation_errors.is_empty());
-        assert!(skill.validation_errors[0].contains("metadata"));
+        assert_eq!(skill.diagnostics.len(), 1);
+        assert_eq!(skill.diagnostics[0].rule, lint::FRONTMATTER_SYNTAX);
+        assert!(skill.diagnostics[0].message.contains("metadata"));
     }
 
     #[test]
@@ -963,8 +1049,8 @@ Content
         let valid = skills.iter().find(|s| s.name() == "valid-skill").unwrap();
         let broken = skills.iter().find(|s| s.name() == "broken-skill").unwrap();
 
-        assert!(valid.validation_errors.is_empty());
-        assert!(!broken.validation_errors.is_empty());
+        assert!(valid.diagnostics.is_empty());
+        assert!(!broken.diagnostics.is_empty());
         assert_eq!(broken.validation_status, ValidationStatus::Invalid);
     }
 
@@ -2,14 +2,76 @@
 //!
 //! Creates symlinks from target CLI skills directories to the central skills storage.
 //! For example: `~/.claude/commands/my-skill` -> `~/.agents/skills/my-skill`
+//!
+//! Every link the syncer creates is recorded in the target's ownership manifest
+//! (see [`crate::manifest`]), and cleanup only ever removes links it owns.
+//! Changes to a target are journaled (see [`crate::journal`]) and rolled back
+//! together if any of them fails.
+//!
+//! Targets whose tool reads another format (see [`TargetFormat`]) get
+//! generated files instead of links. Those carry an ownership marker and are
+//! regenerated when their skill changes and removed when it's gone.
+//!
+//! Targets using [`SyncStrategy::Section`] point at a markdown file instead of
+//! a directory; the syncer keeps a managed block listing the skills inside it
+//! and never touches the text around the block.
+//!
+//! Native folders that stand where a skill should go are skipped by sync. They
+//! can be adopted on request: moved to `.agentloom-backups/` and replaced with
+//! the managed skill.
 
 use crate::error::{Error, Result};
+use crate::importer::{ConflictInfo, ConflictResolution};
+use crate::journal::Journal;
+use crate::manifest::{Manifest, RenameLog};
+use crate::render::{file_is_generated, render_section, splice_section, SECTION_BEGIN};
 use crate::skill::Skill;
-use crate::target::Target;
+use crate::target::{FormatRenderer, RenderedFile, SyncStatus, Target, TargetFormat};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// How skills are placed into a target's skills directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SyncStrategy {
+    /// Symlink (junction on Windows) to the central skill directory
+    #[default]
+    Symlink,
+    /// Real directories with every file hardlinked to the central copy
+    HardlinkTree,
+    /// Full copies, kept current using content hashes
+    Copy,
+    /// A managed block listing the skills inside one markdown file (the target path is the file)
+    Section,
+}
+
+impl SyncStrategy {
+    /// Whether this strategy writes real files into the target (instead of a link)
+    pub fn is_materialized(&self) -> bool {
+        matches!(self, SyncStrategy::HardlinkTree | SyncStrategy::Copy)
+    }
+
+    /// Whether skills go into a managed section of a single file
+    pub fn is_section(&self) -> bool {
+        matches!(self, SyncStrategy::Section)
+    }
+}
+
+/// How symlinks refer to the central skill directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkStyle {
+    /// The skill's absolute path
+    #[default]
+    Absolute,
+    /// A path relative to the target directory, so links keep working when the
+    /// home directory is restored or mounted somewhere else
+    Relative,
+}
 
 /// Result of syncing skills to a target
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,12 +91,33 @@ pub struct SyncResult {
     /// Skills that already had valid symlinks
     pub unchanged: Vec<String>,
 
+    /// Copies that were refreshed because the source skill changed
+    pub updated: Vec<String>,
+
+    /// Copies that were edited in the target and left alone instead of being overwritten
+    pub locally_modified: Vec<String>,
+
+    /// Strategy used for each synced skill
+    pub strategies: BTreeMap<String, SyncStrategy>,
+
+    /// Skills left out by the target's filter (unlinked if they were synced before)
+    pub filtered: Vec<String>,
+
     /// Skills that were skipped because a native (non-symlink) folder exists at the target.
     /// AgentLoom never deletes native content to protect user data.
     pub skipped_native: Vec<String>,
 
+    /// Links that aren't current skills but weren't created by AgentLoom, so were left alone
+    pub extra_items: Vec<String>,
+
     /// Errors encountered during sync
     pub errors: Vec<SyncError>,
+
+    /// Whether an error undid this sync's changes, leaving the target as it was
+    pub rolled_back: bool,
+
+    /// Whether a sync that was interrupted earlier was rolled back first
+    pub recovered: bool,
 }
 
 /// An error encountered during sync
@@ -56,8 +139,15 @@ impl SyncResult {
             created: Vec::new(),
             removed: Vec::new(),
             unchanged: Vec::new(),
+            updated: Vec::new(),
+            locally_modified: Vec::new(),
+            strategies: BTreeMap::new(),
+            filtered: Vec::new(),
             skipped_native: Vec::new(),
+            extra_items: Vec::new(),
             errors: Vec::new(),
+            rolled_back: false,
+            recovered: false,
         }
     }
 
@@ -66,9 +156,265 @@ impl SyncResult {
         self.errors.is_empty()
     }
 
-    /// Get total number of synced skills (created + unchanged)
+    /// Check if the sync created, updated or removed anything
+    pub fn has_changes(&self) -> bool {
+        !self.created.is_empty() || !self.updated.is_empty() || !self.removed.is_empty()
+    }
+
+    /// Get total number of synced skills (created + updated + unchanged)
     pub fn synced_count(&self) -> usize {
-        self.created.len() + self.unchanged.len()
+        self.created.len() + self.updated.len() + self.unchanged.len()
+    }
+
+    /// Add an error to the result
+    pub fn add_error(&mut self, skill: Option<&str>, message: impl Into<String>) {
+        self.errors.push(SyncError {
+            skill: skill.map(|s| s.to_string()),
+            message: message.into(),
+        });
+    }
+}
+
+/// A link the syncer intends to create or replace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedLink {
+    /// Skill name (also the name of the link inside the target directory)
+    pub skill: String,
+
+    /// Path the link should point to (the skill directory in central storage)
+    pub source_path: PathBuf,
+}
+
+/// Actions a sync would take for a single target, computed without touching the filesystem
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncPlan {
+    /// Target identifier
+    pub target_id: String,
+
+    /// Target display name
+    pub target_name: String,
+
+    /// Target skills directory the plan applies to
+    pub skills_path: PathBuf,
+
+    /// Strategy used to place skills into this target
+    pub strategy: SyncStrategy,
+
+    /// File layout of the target; for generated formats, entries are named by output file
+    pub format: TargetFormat,
+
+    /// Generated files to write (key = file name), for formats other than skill folders
+    #[serde(skip)]
+    pub rendered: BTreeMap<String, RenderedFile>,
+
+    /// Whether the target skills directory has to be created first
+    pub create_dir: bool,
+
+    /// Links that don't exist yet
+    pub create: Vec<PlannedLink>,
+
+    /// Existing links that point somewhere else (or nowhere) and will be replaced
+    pub broken: Vec<PlannedLink>,
+
+    /// Copies whose source skill changed and will be refreshed
+    pub update: Vec<PlannedLink>,
+
+    /// Copies that were edited in the target and will be left alone
+    pub locally_modified: Vec<String>,
+
+    /// Skills left out by the target's filter
+    pub filtered: Vec<String>,
+
+    /// Links to skills that no longer exist in the source
+    pub remove: Vec<String>,
+
+    /// Links that already point to the right skill
+    pub unchanged: Vec<String>,
+
+    /// Skills that will be skipped because a native (non-symlink) folder exists at the target
+    pub skipped_native: Vec<String>,
+
+    /// Links that aren't current skills but weren't created by AgentLoom (left alone)
+    pub extra_items: Vec<String>,
+
+    /// Problems found while planning
+    pub errors: Vec<SyncError>,
+}
+
+impl SyncPlan {
+    /// Create a new empty plan for a target
+    pub fn new(target: &Target) -> Self {
+        Self {
+            target_id: target.id().to_string(),
+            target_name: target.name().to_string(),
+            skills_path: target.skills_path.clone(),
+            strategy: target.strategy,
+            format: target.format,
+            rendered: BTreeMap::new(),
+            create_dir: false,
+            create: Vec::new(),
+            broken: Vec::new(),
+            update: Vec::new(),
+            locally_modified: Vec::new(),
+            filtered: Vec::new(),
+            remove: Vec::new(),
+            unchanged: Vec::new(),
+            skipped_native: Vec::new(),
+            extra_items: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Check if applying the plan would change anything on disk
+    pub fn has_changes(&self) -> bool {
+        self.create_dir
+            || !self.create.is_empty()
+            || !self.broken.is_empty()
+            || !self.update.is_empty()
+            || !self.remove.is_empty()
+    }
+
+    /// Add an error to the plan
+    pub fn add_error(&mut self, skill: Option<&str>, message: impl Into<String>) {
+        self.errors.push(SyncError {
+            skill: skill.map(|s| s.to_string()),
+            message: message.into(),
+        });
+    }
+}
+
+/// Result of repairing the links in a target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepairResult {
+    /// Target identifier
+    pub target_id: String,
+
+    /// Target display name
+    pub target_name: String,
+
+    /// Broken links pointed at the skill they belong to (key = link name, value = skill name)
+    pub relinked: BTreeMap<String, String>,
+
+    /// Broken managed links whose skill no longer exists
+    pub removed: Vec<String>,
+
+    /// Entries AgentLoom doesn't manage, reported and left alone
+    pub unmanaged: Vec<String>,
+
+    /// Errors encountered during repair
+    pub errors: Vec<SyncError>,
+}
+
+impl RepairResult {
+    /// Create a new empty repair result for a target
+    pub fn new(target: &Target) -> Self {
+        Self {
+            target_id: target.id().to_string(),
+            target_name: target.name().to_string(),
+            relinked: BTreeMap::new(),
+            removed: Vec::new(),
+            unmanaged: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Check if the repair was successful (no errors)
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Check if the repair changed anything
+    pub fn has_changes(&self) -> bool {
+        !self.relinked.is_empty() || !self.removed.is_empty()
+    }
+
+    /// Add an error to the result
+    pub fn add_error(&mut self, skill: Option<&str>, message: impl Into<String>) {
+        self.errors.push(SyncError {
+            skill: skill.map(|s| s.to_string()),
+            message: message.into(),
+        });
+    }
+}
+
+/// Name of the folder inside a target skills directory that keeps adopted native folders
+pub const ADOPT_BACKUP_DIR_NAME: &str = ".agentloom-backups";
+
+/// A native folder in a target that stands where a managed skill should be
+#[derive(Debug, Clone, Serialize)]
+pub struct AdoptCandidate {
+    /// Target identifier
+    pub target_id: String,
+
+    /// Target display name
+    pub target_name: String,
+
+    /// Skill name (also the name of the folder in the target)
+    pub skill: String,
+
+    /// Native folder in the target
+    pub native_path: PathBuf,
+
+    /// Set if the native folder differs from the central copy
+    pub conflict: Option<ConflictInfo>,
+}
+
+/// User's choice for adopting a native folder
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdoptSelection {
+    /// Target the native folder is in
+    pub target_id: String,
+
+    /// Skill name
+    pub skill: String,
+
+    /// `Import` adopts a folder identical to the central copy, `Overwrite`
+    /// first replaces the central copy with the native folder, `Skip` leaves it
+    pub resolution: ConflictResolution,
+}
+
+/// Result of adopting native folders in a target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdoptResult {
+    /// Target identifier
+    pub target_id: String,
+
+    /// Target display name
+    pub target_name: String,
+
+    /// Skills whose native folder was replaced with the managed skill
+    pub adopted: Vec<String>,
+
+    /// Adopted skills whose central copy was replaced with the native folder first
+    pub overwritten: Vec<String>,
+
+    /// Skills left alone
+    pub skipped: Vec<String>,
+
+    /// Where each replaced native folder was moved (key = skill name)
+    pub backups: BTreeMap<String, PathBuf>,
+
+    /// Errors encountered while adopting
+    pub errors: Vec<SyncError>,
+}
+
+impl AdoptResult {
+    /// Create a new empty adopt result for a target
+    pub fn new(target: &Target) -> Self {
+        Self {
+            target_id: target.id().to_string(),
+            target_name: target.name().to_string(),
+            adopted: Vec::new(),
+            overwritten: Vec::new(),
+            skipped: Vec::new(),
+            backups: BTreeMap::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Check if adopting was successful (no errors)
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
     }
 
     /// Add an error to the result
@@ -83,13 +429,21 @@ impl SyncResult {
 /// Syncer for managing symlinks between Talent skills and target CLIs
 ///
 /// Safety: The syncer never deletes native (non-symlink) content in target directories.
-/// Only symlinks managed by AgentLoom are created, updated, or removed.
+/// Only symlinks managed by AgentLoom are created, updated, or removed. A symlink
+/// counts as managed if it is recorded in the target's manifest or points into
+/// the central skills directory.
 pub struct Syncer {
     /// Whether to remove stale symlinks (links to skills that no longer exist)
     pub remove_stale: bool,
 
     /// Whether to create target directories if they don't exist
     pub create_dirs: bool,
+
+    /// Central skills directory; links pointing inside it are treated as managed
+    pub skills_dir: Option<PathBuf>,
+
+    /// How new links refer to their skill (existing links of either style are kept)
+    pub link_style: LinkStyle,
 }
 
 impl Default for Syncer {
@@ -97,6 +451,8 @@ impl Default for Syncer {
         Self {
             remove_stale: true,
             create_dirs: true,
+            skills_dir: None,
+            link_style: LinkStyle::default(),
         }
     }
 }
@@ -107,69 +463,800 @@ impl Syncer {
         Self::default()
     }
 
+    /// Create a syncer that treats links into `skills_dir` as managed
+    pub fn with_skills_dir(skills_dir: PathBuf) -> Self {
+        Self {
+            skills_dir: Some(skills_dir),
+            ..Self::default()
+        }
+    }
+
     /// Sync skills to a single target
+    ///
+    /// Computes a [`SyncPlan`] and applies it.
     pub fn sync_target(&self, target: &Target, skills: &[Skill]) -> SyncResult {
-        let mut result = SyncResult::new(target);
+        // Recover first so the plan sees the target as it was before an interrupted run
+        let recovered = self.recover(target).unwrap_or(false);
+        let plan = self.plan(target, skills);
+        let mut result = self.apply_plan(target, &plan);
+        result.recovered |= recovered;
+        result
+    }
+
+    /// Sync only the named skills to a target
+    ///
+    /// Used for incremental syncs: `skills` is the full list of skills, and
+    /// `names` the ones that changed. Named skills missing from `skills` were
+    /// deleted and have their entries removed.
+    pub fn sync_skills(
+        &self,
+        target: &Target,
+        skills: &[Skill],
+        names: &HashSet<&str>,
+    ) -> SyncResult {
+        let recovered = self.recover(target).unwrap_or(false);
+        let plan = self.plan_skills(target, skills, names);
+        let mut result = self.apply_plan(target, &plan);
+        result.recovered |= recovered;
+        result
+    }
+
+    /// Roll back a sync of this target that was interrupted before it finished
+    ///
+    /// Returns true if an interrupted sync was found.
+    pub fn recover(&self, target: &Target) -> Result<bool> {
+        // Section targets are written in one atomic step and never journaled
+        if target.strategy.is_section() {
+            return Ok(false);
+        }
+        match Journal::load(&target.skills_path)? {
+            Some(journal) => journal.rollback(self).map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Compute what syncing skills to a target would do, without touching the filesystem
+    pub fn plan(&self, target: &Target, skills: &[Skill]) -> SyncPlan {
+        self.plan_scoped(target, skills, None)
+    }
+
+    /// Compute what syncing only the named skills to a target would do
+    pub fn plan_skills(
+        &self,
+        target: &Target,
+        skills: &[Skill],
+        names: &HashSet<&str>,
+    ) -> SyncPlan {
+        self.plan_scoped(target, skills, Some(names))
+    }
+
+    /// Build a plan for every skill, or only the skills in `scope`
+    fn plan_scoped(
+        &self,
+        target: &Target,
+        skills: &[Skill],
+        scope: Option<&HashSet<&str>>,
+    ) -> SyncPlan {
+        let mut plan = SyncPlan::new(target);
+
+        // Generated formats are cheap to render and may aggregate skills, so always plan them whole
+        let scope = scope.filter(|_| target.format.is_skill_folder());
 
         // Skip disabled targets
         if !target.enabled {
+            return plan;
+        }
+
+        if target.strategy.is_section() {
+            self.plan_section(target, skills, &mut plan);
+            return plan;
+        }
+
+        // A missing skills directory is either created on apply or reported
+        if !target.skills_dir_exists() {
+            if self.create_dirs {
+                plan.create_dir = true;
+            } else {
+                plan.add_error(None, "Skills directory does not exist");
+                return plan;
+            }
+        }
+
+        // Ownership manifest; without it nothing counts as ours, which is the safe default
+        let manifest = Manifest::load(&target.skills_path).unwrap_or_else(|e| {
+            plan.add_error(None, format!("Failed to read manifest: {e}"));
+            Manifest::default()
+        });
+
+        // Narrow down to the skills this target wants
+        let Some((selected, filtered)) = select_skills(target, skills, scope, &mut plan) else {
+            return plan;
+        };
+
+        // Get set of skill names we want to sync
+        let skill_names: HashSet<&str> = selected.iter().map(|s| s.name()).collect();
+
+        // Work out what to do for each skill, or each generated file
+        let rendered_names = match target.format.renderer() {
+            Some(renderer) => self.plan_rendered(target, renderer, &selected, &mut plan),
+            None => {
+                for skill in selected {
+                    let link_path = target.skill_link_path(skill.name());
+                    let planned = PlannedLink {
+                        skill: skill.name().to_string(),
+                        source_path: skill.path.clone(),
+                    };
+
+                    let state = self.inspect_entry(
+                        &manifest,
+                        skill.name(),
+                        &link_path,
+                        &skill.path,
+                        target.strategy,
+                    );
+                    match state {
+                        Ok(EntryState::Missing) => plan.create.push(planned),
+                        Ok(EntryState::Correct) => plan.unchanged.push(planned.skill),
+                        Ok(EntryState::Mismatched) => plan.broken.push(planned),
+                        Ok(EntryState::Outdated) => plan.update.push(planned),
+                        Ok(EntryState::LocallyModified) => {
+                            plan.locally_modified.push(planned.skill)
+                        }
+                        Ok(EntryState::Native) => plan.skipped_native.push(planned.skill),
+                        Err(e) => plan.add_error(Some(skill.name()), e.to_string()),
+                    }
+                }
+                HashSet::new()
+            }
+        };
+        let keep: HashSet<&str> = if target.format.is_skill_folder() {
+            skill_names
+        } else {
+            rendered_names.iter().map(String::as_str).collect()
+        };
+
+        // Nothing to clean up in a directory that doesn't exist yet
+        if plan.create_dir {
+            return plan;
+        }
+
+        // Find stale symlinks across the whole directory if enabled (this covers filtered skills)
+        if scope.is_none() && self.remove_stale {
+            if let Err(e) = self.plan_stale_removals(target, &manifest, &keep, &mut plan) {
+                plan.add_error(None, format!("Failed to clean stale symlinks: {e}"));
+            }
+            return plan;
+        }
+
+        // Filtered-out skills are unlinked even when stale cleanup is off
+        let mut to_unlink: Vec<&str> = filtered.iter().map(|s| s.name()).collect();
+
+        // Scoped skills that no longer exist are stale
+        if let Some(names) = scope.filter(|_| self.remove_stale) {
+            let existing: HashSet<&str> = skills.iter().map(|s| s.name()).collect();
+            to_unlink.extend(names.iter().filter(|n| !existing.contains(*n)));
+        }
+
+        for name in to_unlink {
+            let path = target.skill_link_path(name);
+            let Ok(metadata) = path.symlink_metadata() else {
+                continue;
+            };
+            if let Err(e) = self.plan_removal(&manifest, name, &path, &metadata, &mut plan) {
+                plan.add_error(Some(name), e.to_string());
+            }
+        }
+
+        plan
+    }
+
+    /// Apply a previously computed plan to a target
+    ///
+    /// Each entry is re-checked when it is written, so a plan that has gone
+    /// slightly out of date never overwrites native content. If any change
+    /// fails, the ones already made are rolled back.
+    pub fn apply_plan(&self, target: &Target, plan: &SyncPlan) -> SyncResult {
+        let mut result = SyncResult::new(target);
+        result.errors.extend(plan.errors.iter().cloned());
+        result.unchanged.extend(plan.unchanged.iter().cloned());
+        result
+            .skipped_native
+            .extend(plan.skipped_native.iter().cloned());
+        result.extra_items.extend(plan.extra_items.iter().cloned());
+        result.filtered.extend(plan.filtered.iter().cloned());
+        result
+            .locally_modified
+            .extend(plan.locally_modified.iter().cloned());
+
+        if target.strategy.is_section() {
+            self.apply_section(target, plan, &mut result);
             return result;
         }
 
         // Ensure target skills directory exists
-        if self.create_dirs {
+        if plan.create_dir {
             if let Err(e) = target.ensure_skills_dir() {
                 result.add_error(None, format!("Failed to create skills directory: {e}"));
                 return result;
             }
         } else if !target.skills_dir_exists() {
-            result.add_error(None, "Skills directory does not exist");
+            // Disabled target or missing directory, already reported by the plan
             return result;
         }
 
-        // Get set of skill names we want to sync
-        let skill_names: HashSet<&str> = skills.iter().map(|s| s.name()).collect();
-
-        // Create symlinks for each skill
-        for skill in skills {
-            let link_path = target.skill_link_path(skill.name());
-            let target_path = &skill.path;
+        // Undo whatever an interrupted earlier sync left half done
+        match self.recover(target) {
+            Ok(recovered) => result.recovered = recovered,
+            Err(e) => {
+                result.add_error(None, format!("Failed to roll back interrupted sync: {e}"));
+                return result;
+            }
+        }
 
-            match self.create_symlink(&link_path, target_path) {
-                Ok(SymlinkAction::Created) => {
-                    result.created.push(skill.name().to_string());
-                }
-                Ok(SymlinkAction::Unchanged) => {
-                    result.unchanged.push(skill.name().to_string());
-                }
-                Ok(SymlinkAction::SkippedNative) => {
-                    result.skipped_native.push(skill.name().to_string());
+        let mut manifest = Manifest::load(&target.skills_path).unwrap_or_else(|e| {
+            result.add_error(
+                None,
+                format!("Failed to read manifest, starting fresh: {e}"),
+            );
+            Manifest::default()
+        });
+        let errors_before = result.errors.len();
+        let mut journal = Journal::begin(&target.skills_path);
+        let mut manifest_changed = match target.format.renderer() {
+            Some(_) => self.apply_rendered(target, plan, &mut manifest, &mut journal, &mut result),
+            None => self.apply_links(target, plan, &mut manifest, &mut journal, &mut result),
+        };
+
+        // Remove stale entries
+        for name in &plan.remove {
+            let path = target.skill_link_path(name);
+            if let Err(e) = journal.record(self, &path) {
+                result.add_error(Some(name), format!("Failed to journal change: {e}"));
+                continue;
+            }
+            match self.remove_entry(&manifest, name, &path) {
+                Ok(()) => {
+                    manifest_changed |= manifest.forget(name);
+                    result.removed.push(name.clone());
                 }
                 Err(e) => {
-                    result.add_error(Some(skill.name()), e.to_string());
+                    result.add_error(Some(name), format!("Failed to remove stale symlink: {e}"));
                 }
             }
         }
 
-        // Remove stale symlinks if enabled
-        if self.remove_stale {
-            if let Err(e) = self.remove_stale_symlinks(target, &skill_names, &mut result) {
-                result.add_error(None, format!("Failed to clean stale symlinks: {e}"));
+        if manifest_changed {
+            let saved = journal
+                .record(self, &Manifest::path_in(&target.skills_path))
+                .and_then(|()| manifest.save(&target.skills_path));
+            if let Err(e) = saved {
+                result.add_error(None, format!("Failed to update manifest: {e}"));
             }
         }
 
-        result
-    }
+        // All or nothing: keep the changes only if every one of them worked
+        if result.errors.len() == errors_before {
+            if let Err(e) = journal.commit() {
+                result.add_error(None, format!("Failed to clear sync journal: {e}"));
+            }
+            return result;
+        }
 
-    /// Sync skills to all targets
-    pub fn sync_all(&self, targets: &[Target], skills: &[Skill]) -> Vec<SyncResult> {
-        targets
-            .iter()
-            .map(|target| self.sync_target(target, skills))
-            .collect()
+        let undone = !journal.is_empty();
+        if let Err(e) = journal.rollback(self) {
+            result.add_error(
+                None,
+                format!("Failed to roll back, will retry on next sync: {e}"),
+            );
+            return result;
+        }
+        if undone {
+            result.rolled_back = true;
+            result.created.clear();
+            result.updated.clear();
+            result.removed.clear();
+            result.strategies.clear();
+            if plan.create_dir {
+                // Only succeeds if the directory is empty again
+                let _ = fs::remove_dir(&target.skills_path);
+            }
+        }
+
+        result
     }
 
-    /// Create a symlink, handling existing paths
+    /// Place skill folders into a target: write new and outdated entries and record correct ones
+    ///
+    /// Returns true if the manifest changed.
+    fn apply_links(
+        &self,
+        target: &Target,
+        plan: &SyncPlan,
+        manifest: &mut Manifest,
+        journal: &mut Journal,
+        result: &mut SyncResult,
+    ) -> bool {
+        let mut manifest_changed = false;
+
+        // Entries that are already correct are ours too
+        for name in &plan.unchanged {
+            let path = target.skill_link_path(name);
+            let mut used = target.strategy;
+            let recorded = match target.strategy {
+                SyncStrategy::Symlink => self
+                    .resolve_link(&path)
+                    .map(|dest| manifest.record(name, &dest)),
+                _ => match (manifest.links.get(name).cloned(), hash_dir(&path)) {
+                    (Some(entry), Ok(hash)) => {
+                        used = entry.strategy;
+                        Some(manifest.record_copy(name, &entry.destination, entry.strategy, hash))
+                    }
+                    _ => None,
+                },
+            };
+            if let Some(changed) = recorded {
+                manifest_changed |= changed;
+            }
+            result.strategies.insert(name.clone(), used);
+        }
+
+        // Create new entries, replace broken ones and refresh outdated copies
+        let to_write = plan
+            .create
+            .iter()
+            .chain(&plan.broken)
+            .map(|link| (link, false))
+            .chain(plan.update.iter().map(|link| (link, true)));
+
+        for (link, is_update) in to_write {
+            let path = target.skill_link_path(&link.skill);
+            if let Err(e) = journal.record(self, &path) {
+                result.add_error(Some(&link.skill), format!("Failed to journal change: {e}"));
+                continue;
+            }
+
+            match self.write_entry(
+                manifest,
+                &link.skill,
+                &path,
+                &link.source_path,
+                target.strategy,
+            ) {
+                Ok(Some((SymlinkAction::Created, used))) => {
+                    manifest_changed = true;
+                    result.strategies.insert(link.skill.clone(), used);
+                    if is_update {
+                        result.updated.push(link.skill.clone());
+                    } else {
+                        result.created.push(link.skill.clone());
+                    }
+                }
+                Ok(Some((SymlinkAction::Unchanged, used))) => {
+                    // Nothing changed on disk; a stale journal entry would only be restored as is
+                    let _ = journal.discard_last();
+                    manifest_changed |= manifest.record(&link.skill, &link.source_path);
+                    result.strategies.insert(link.skill.clone(), used);
+                    result.unchanged.push(link.skill.clone());
+                }
+                Ok(Some((SymlinkAction::SkippedNative, _))) | Ok(None) => {
+                    let _ = journal.discard_last();
+                    result.skipped_native.push(link.skill.clone());
+                }
+                Err(e) => {
+                    result.add_error(Some(&link.skill), e.to_string());
+                }
+            }
+        }
+
+        manifest_changed
+    }
+
+    /// Write a target's generated files and record them in the manifest
+    ///
+    /// Returns true if the manifest changed.
+    fn apply_rendered(
+        &self,
+        target: &Target,
+        plan: &SyncPlan,
+        manifest: &mut Manifest,
+        journal: &mut Journal,
+        result: &mut SyncResult,
+    ) -> bool {
+        let mut manifest_changed = false;
+
+        // Files that are already current are ours too
+        for name in &plan.unchanged {
+            if let Some(file) = plan.rendered.get(name) {
+                manifest_changed |= manifest.record_generated(
+                    name,
+                    &file.source,
+                    target.format,
+                    hash_contents(file.contents.as_bytes()),
+                );
+            }
+        }
+
+        let to_write = plan
+            .create
+            .iter()
+            .map(|link| (link, false))
+            .chain(plan.update.iter().map(|link| (link, true)));
+
+        for (link, is_update) in to_write {
+            let Some(file) = plan.rendered.get(&link.skill) else {
+                continue;
+            };
+            let path = target.skills_path.join(&file.name);
+            if let Err(e) = journal.record(self, &path) {
+                result.add_error(Some(&link.skill), format!("Failed to journal change: {e}"));
+                continue;
+            }
+
+            match write_generated(&path, &file.contents) {
+                Ok(true) => {
+                    manifest_changed |= manifest.record_generated(
+                        &file.name,
+                        &file.source,
+                        target.format,
+                        hash_contents(file.contents.as_bytes()),
+                    );
+                    if is_update {
+                        result.updated.push(link.skill.clone());
+                    } else {
+                        result.created.push(link.skill.clone());
+                    }
+                }
+                Ok(false) => {
+                    let _ = journal.discard_last();
+                    result.skipped_native.push(link.skill.clone());
+                }
+                Err(e) => result.add_error(Some(&link.skill), e.to_string()),
+            }
+        }
+
+        manifest_changed
+    }
+
+    /// Render skills for a generated format and plan each output file
+    ///
+    /// Returns the names of the files the target should have.
+    fn plan_rendered(
+        &self,
+        target: &Target,
+        renderer: &dyn FormatRenderer,
+        skills: &[&Skill],
+        plan: &mut SyncPlan,
+    ) -> HashSet<String> {
+        let mut names = HashSet::new();
+
+        for file in renderer.render(skills) {
+            let path = target.skills_path.join(&file.name);
+            let planned = PlannedLink {
+                skill: file.name.clone(),
+                source_path: file.source.clone(),
+            };
+
+            match path.symlink_metadata() {
+                Err(_) => plan.create.push(planned),
+                // Links and directories aren't generated files
+                Ok(metadata) if !metadata.is_file() => plan.skipped_native.push(planned.skill),
+                Ok(_) => match fs::read_to_string(&path) {
+                    Ok(existing) if existing == file.contents => plan.unchanged.push(planned.skill),
+                    Ok(existing) if crate::render::is_generated(&existing) => {
+                        plan.update.push(planned)
+                    }
+                    // A file of the user's (or one they took over by removing the marker)
+                    _ => plan.skipped_native.push(planned.skill),
+                },
+            }
+
+            names.insert(file.name.clone());
+            plan.rendered.insert(file.name.clone(), file);
+        }
+
+        names
+    }
+
+    /// Plan the managed section of a section target
+    ///
+    /// The whole block is one entry, named after the file.
+    fn plan_section(&self, target: &Target, skills: &[Skill], plan: &mut SyncPlan) {
+        let path = &target.skills_path;
+        let Some((selected, _)) = select_skills(target, skills, None, plan) else {
+            return;
+        };
+        if path.is_dir() {
+            plan.add_error(
+                None,
+                format!("{} is a directory, not a file", path.display()),
+            );
+            return;
+        }
+
+        let name = section_entry_name(target);
+        let body = render_section(&selected, target.section_style);
+        let planned = PlannedLink {
+            skill: name.clone(),
+            source_path: self.skills_dir.clone().unwrap_or_default(),
+        };
+
+        let existing = match fs::read_to_string(path) {
+            Ok(existing) => Some(existing),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => {
+                plan.add_error(None, Error::io(path, e).to_string());
+                return;
+            }
+        };
+
+        match existing {
+            // Nothing to list and nowhere to list it
+            None if body.is_empty() => return,
+            None => {
+                if !path.parent().is_some_and(Path::exists) {
+                    if self.create_dirs {
+                        plan.create_dir = true;
+                    } else {
+                        plan.add_error(None, "Directory for the section file does not exist");
+                        return;
+                    }
+                }
+                plan.create.push(planned);
+            }
+            Some(existing) => match splice_section(&existing, &body, path) {
+                Ok(updated) if updated == existing => {
+                    if !body.is_empty() {
+                        plan.unchanged.push(name.clone());
+                    }
+                }
+                Ok(_) if body.is_empty() => plan.remove.push(name.clone()),
+                Ok(_) if existing.contains(SECTION_BEGIN) => plan.update.push(planned),
+                Ok(_) => plan.create.push(planned),
+                Err(e) => {
+                    plan.add_error(None, e.to_string());
+                    return;
+                }
+            },
+        }
+
+        plan.rendered.insert(
+            name.clone(),
+            RenderedFile {
+                name,
+                source: self.skills_dir.clone().unwrap_or_default(),
+                contents: body,
+            },
+        );
+    }
+
+    /// Write the managed section planned for a section target
+    ///
+    /// The file is read again and spliced at write time so edits made outside
+    /// the block since planning are kept. A file AgentLoom created is recorded
+    /// in the manifest beside it, and deleted again once its block is gone.
+    fn apply_section(&self, target: &Target, plan: &SyncPlan, result: &mut SyncResult) {
+        if !plan.has_changes() {
+            return;
+        }
+        if plan.create_dir {
+            if let Err(e) = target.ensure_skills_dir() {
+                result.add_error(None, format!("Failed to create directory: {e}"));
+                return;
+            }
+        }
+
+        let path = &target.skills_path;
+        let name = section_entry_name(target);
+        let body = plan
+            .rendered
+            .get(&name)
+            .map(|file| file.contents.as_str())
+            .unwrap_or_default();
+
+        let written = fs::read_to_string(path)
+            .map(Some)
+            .or_else(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => Ok(None),
+                _ => Err(Error::io(path, e)),
+            })
+            .and_then(|existing| {
+                let mut manifest = Manifest::load(section_manifest_dir(path))?;
+                let owned = existing.is_none() || manifest.owns(&name);
+                write_section(path, existing.as_deref().unwrap_or_default(), body, owned)?;
+                let changed = if path.exists() {
+                    let source = self.skills_dir.clone().unwrap_or_default();
+                    existing.is_none() && manifest.record_section_file(&name, &source)
+                } else {
+                    manifest.forget(&name)
+                };
+                if changed {
+                    manifest.save(section_manifest_dir(path))?;
+                }
+                Ok(())
+            });
+        if let Err(e) = written {
+            result.add_error(Some(&name), e.to_string());
+            return;
+        }
+
+        let names =
+            |links: &[PlannedLink]| links.iter().map(|l| l.skill.clone()).collect::<Vec<_>>();
+        result.created.extend(names(&plan.create));
+        result.updated.extend(names(&plan.update));
+        result.removed.extend(plan.remove.iter().cloned());
+    }
+
+    /// Sync skills to all targets
+    pub fn sync_all(&self, targets: &[Target], skills: &[Skill]) -> Vec<SyncResult> {
+        targets
+            .iter()
+            .map(|target| self.sync_target(target, skills))
+            .collect()
+    }
+
+    /// Plan a sync of skills to all targets
+    pub fn plan_all(&self, targets: &[Target], skills: &[Skill]) -> Vec<SyncPlan> {
+        targets
+            .iter()
+            .map(|target| self.plan(target, skills))
+            .collect()
+    }
+
+    /// Inspect what currently sits at a skill's path in a target, without modifying it
+    fn inspect_entry(
+        &self,
+        manifest: &Manifest,
+        name: &str,
+        path: &Path,
+        source_path: &Path,
+        strategy: SyncStrategy,
+    ) -> Result<EntryState> {
+        let metadata = match path.symlink_metadata() {
+            Ok(m) => m,
+            Err(_) => return Ok(EntryState::Missing),
+        };
+
+        if self.is_link(&metadata, path) {
+            if strategy != SyncStrategy::Symlink {
+                // A link where we want a copy gets replaced
+                return Ok(EntryState::Mismatched);
+            }
+
+            self.read_link_target(path)
+                .map_err(|e| Error::io(path, e))?;
+
+            return if self.links_to(path, source_path) {
+                Ok(EntryState::Correct)
+            } else {
+                Ok(EntryState::Mismatched)
+            };
+        }
+
+        // A real directory is only ours if the manifest says we materialized it
+        let Some(entry) = manifest.links.get(name).filter(|e| e.is_copy()) else {
+            return Ok(EntryState::Native);
+        };
+
+        let current_hash = hash_dir(path)?;
+        let unmodified = entry.content_hash.as_deref() == Some(current_hash.as_str());
+
+        if strategy == SyncStrategy::Symlink {
+            // Switching from a copy back to a symlink, unless the copy was edited
+            return Ok(if unmodified {
+                EntryState::Mismatched
+            } else {
+                EntryState::LocallyModified
+            });
+        }
+
+        let source_hash = hash_dir(source_path)?;
+        if current_hash == source_hash {
+            // A hardlink tree that fell back to a copy stays as it is
+            let fell_back =
+                strategy == SyncStrategy::HardlinkTree && entry.strategy == SyncStrategy::Copy;
+            if entry.strategy == strategy || fell_back {
+                Ok(EntryState::Correct)
+            } else {
+                Ok(EntryState::Mismatched)
+            }
+        } else if unmodified {
+            Ok(EntryState::Outdated)
+        } else {
+            Ok(EntryState::LocallyModified)
+        }
+    }
+
+    /// Write a skill into a target using the given strategy
+    ///
+    /// Returns the action taken and the strategy actually used (hardlink trees
+    /// fall back to copies when hardlinking isn't possible), or `None` if native
+    /// content was in the way.
+    fn write_entry(
+        &self,
+        manifest: &mut Manifest,
+        name: &str,
+        path: &Path,
+        source_path: &Path,
+        strategy: SyncStrategy,
+    ) -> Result<Option<(SymlinkAction, SyncStrategy)>> {
+        // Clear out a copy we materialized earlier; links are handled below
+        let is_link = path
+            .symlink_metadata()
+            .map(|m| self.is_link(&m, path))
+            .unwrap_or(false);
+        if !is_link && path.exists() {
+            if !manifest.links.get(name).is_some_and(|e| e.is_copy()) {
+                return Ok(None);
+            }
+            fs::remove_dir_all(path).map_err(|e| Error::io(path, e))?;
+        }
+
+        if strategy == SyncStrategy::Symlink {
+            let action = self.create_symlink(path, source_path)?;
+            if action == SymlinkAction::Created {
+                manifest.record(name, source_path);
+            }
+            return Ok(Some((action, SyncStrategy::Symlink)));
+        }
+
+        if is_link {
+            self.remove_link(path).map_err(|e| Error::SymlinkRemove {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            })?;
+        }
+
+        let used = match strategy {
+            SyncStrategy::HardlinkTree => match hardlink_tree(source_path, path) {
+                Ok(()) => SyncStrategy::HardlinkTree,
+                Err(_) => {
+                    // Hardlinks can't cross filesystems; fall back to a plain copy
+                    let _ = fs::remove_dir_all(path);
+                    copy_tree(source_path, path)?;
+                    SyncStrategy::Copy
+                }
+            },
+            _ => {
+                copy_tree(source_path, path)?;
+                SyncStrategy::Copy
+            }
+        };
+
+        let hash = hash_dir(path)?;
+        manifest.record_copy(name, source_path, used, hash);
+        Ok(Some((SymlinkAction::Created, used)))
+    }
+
+    /// Remove a stale entry: a link, or a copy recorded in the manifest
+    fn remove_entry(&self, manifest: &Manifest, name: &str, path: &Path) -> Result<()> {
+        let is_link = path
+            .symlink_metadata()
+            .map(|m| self.is_link(&m, path))
+            .unwrap_or(false);
+
+        if is_link {
+            return self.remove_link(path).map_err(|e| Error::SymlinkRemove {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            });
+        }
+
+        // Generated files are only removed while they still carry the marker
+        if path.is_file() {
+            if file_is_generated(path) {
+                fs::remove_file(path).map_err(|e| Error::io(path, e))?;
+            }
+            return Ok(());
+        }
+
+        if manifest.links.get(name).is_some_and(|e| e.is_copy()) {
+            fs::remove_dir_all(path).map_err(|e| Error::io(path, e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a symlink, handling existing paths
     ///
     /// If a non-symlink (native content) exists at `link_path`, it is skipped
     /// to protect user data. AgentLoom never deletes native skill folders.
@@ -183,11 +1270,10 @@ impl Syncer {
 
             if self.is_link(&metadata, link_path) {
                 // Check if it points to the correct target
-                let current_target = self
-                    .read_link_target(link_path)
+                self.read_link_target(link_path)
                     .map_err(|e| Error::io(link_path, e))?;
 
-                if current_target == target_path {
+                if self.links_to(link_path, target_path) {
                     return Ok(SymlinkAction::Unchanged);
                 }
 
@@ -205,15 +1291,45 @@ impl Syncer {
         }
 
         // Create the symlink
-        self.do_create_symlink(link_path, target_path)?;
+        let destination = self.link_destination(link_path, target_path);
+        self.do_create_symlink(link_path, &destination)?;
         Ok(SymlinkAction::Created)
     }
 
+    /// What a new link at `link_path` should store to reach `source_path`
+    ///
+    /// Relative links are computed from the real (symlink-resolved) target
+    /// directory, since that is where the OS resolves them from. Junctions on
+    /// Windows can only hold absolute paths.
+    fn link_destination(&self, link_path: &Path, source_path: &Path) -> PathBuf {
+        if self.link_style == LinkStyle::Relative && cfg!(unix) {
+            let relative = link_path.parent().and_then(|dir| {
+                let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+                let source = source_path
+                    .canonicalize()
+                    .unwrap_or_else(|_| source_path.to_path_buf());
+                relative_path(&dir, &source)
+            });
+            if let Some(relative) = relative {
+                return relative;
+            }
+        }
+        source_path.to_path_buf()
+    }
+
+    /// Check if a link leads to `source_path`, whether it is stored absolute or relative
+    fn links_to(&self, link_path: &Path, source_path: &Path) -> bool {
+        let Some(dest) = self.resolve_link(link_path) else {
+            return false;
+        };
+        dest == source_path || same_existing_path(&dest, source_path)
+    }
+
     /// Actually create the symlink (platform-specific)
     ///
     /// On Unix, creates a symbolic link.
     /// On Windows, creates a directory junction (no admin privileges required).
-    fn do_create_symlink(&self, link_path: &Path, target_path: &Path) -> Result<()> {
+    pub(crate) fn do_create_symlink(&self, link_path: &Path, target_path: &Path) -> Result<()> {
         #[cfg(unix)]
         std::os::unix::fs::symlink(target_path, link_path).map_err(|e| Error::SymlinkCreate {
             link_source: target_path.to_path_buf(),
@@ -261,12 +1377,12 @@ impl Syncer {
 
     /// Check if a path is a symlink or junction (platform-specific)
     #[cfg(unix)]
-    fn is_link(&self, metadata: &std::fs::Metadata, _path: &Path) -> bool {
+    pub(crate) fn is_link(&self, metadata: &std::fs::Metadata, _path: &Path) -> bool {
         metadata.file_type().is_symlink()
     }
 
     #[cfg(windows)]
-    fn is_link(&self, metadata: &std::fs::Metadata, path: &Path) -> bool {
+    pub(crate) fn is_link(&self, metadata: &std::fs::Metadata, path: &Path) -> bool {
         // On Windows, is_symlink() returns true for both symlinks and junctions
         // Also check junction::exists for junctions not detected as symlinks
         metadata.file_type().is_symlink() || junction::exists(path).unwrap_or(false)
@@ -274,12 +1390,12 @@ impl Syncer {
 
     /// Read the target of a symlink or junction (platform-specific)
     #[cfg(unix)]
-    fn read_link_target(&self, path: &Path) -> std::io::Result<std::path::PathBuf> {
+    pub(crate) fn read_link_target(&self, path: &Path) -> std::io::Result<std::path::PathBuf> {
         fs::read_link(path)
     }
 
     #[cfg(windows)]
-    fn read_link_target(&self, path: &Path) -> std::io::Result<std::path::PathBuf> {
+    pub(crate) fn read_link_target(&self, path: &Path) -> std::io::Result<std::path::PathBuf> {
         // Try junction first, then symlink
         if junction::exists(path).unwrap_or(false) {
             junction::get_target(path)
@@ -324,12 +1440,16 @@ impl Syncer {
         Ok(())
     }
 
-    /// Remove symlinks/junctions for skills that no longer exist
-    fn remove_stale_symlinks(
+    /// Find symlinks/junctions (and copies) for skills that no longer exist
+    ///
+    /// Only entries AgentLoom owns are planned for removal; any other stale link
+    /// is reported in `extra_items`, and edited copies in `locally_modified`.
+    fn plan_stale_removals(
         &self,
         target: &Target,
+        manifest: &Manifest,
         current_skills: &HashSet<&str>,
-        result: &mut SyncResult,
+        plan: &mut SyncPlan,
     ) -> Result<()> {
         let entries = fs::read_dir(&target.skills_path)
             .map_err(|e| Error::read_dir(&target.skills_path, e))?;
@@ -337,47 +1457,113 @@ impl Syncer {
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
 
-            // Only process symlinks/junctions
             let metadata = match path.symlink_metadata() {
                 Ok(m) => m,
                 Err(_) => continue,
             };
 
-            if !self.is_link(&metadata, &path) {
-                continue;
-            }
-
-            // Get the skill name from the symlink name
+            // Get the skill name from the entry name
             let skill_name = match path.file_name().and_then(|n| n.to_str()) {
                 Some(name) => name,
                 None => continue,
             };
 
-            // If this skill is not in our current set, remove the symlink
-            if !current_skills.contains(skill_name) {
-                match self.remove_link(&path) {
-                    Ok(()) => {
-                        result.removed.push(skill_name.to_string());
-                    }
-                    Err(e) => {
-                        result.add_error(
-                            Some(skill_name),
-                            format!("Failed to remove stale symlink: {e}"),
-                        );
-                    }
-                }
+            // Skills we still sync are handled by the main plan
+            if current_skills.contains(skill_name) {
+                continue;
+            }
+
+            self.plan_removal(manifest, skill_name, &path, &metadata, plan)?;
+        }
+
+        Ok(())
+    }
+
+    /// Plan the removal of an entry for a skill that is no longer synced
+    ///
+    /// Only links and unedited copies that AgentLoom owns are removed; anything
+    /// else is reported and left alone.
+    fn plan_removal(
+        &self,
+        manifest: &Manifest,
+        name: &str,
+        path: &Path,
+        metadata: &fs::Metadata,
+        plan: &mut SyncPlan,
+    ) -> Result<()> {
+        if self.is_link(metadata, path) {
+            if self.is_managed_link(manifest, name, path) {
+                plan.remove.push(name.to_string());
+            } else {
+                plan.extra_items.push(name.to_string());
+            }
+            return Ok(());
+        }
+
+        // Files are only ours if they carry the generated marker
+        if metadata.is_file() {
+            if file_is_generated(path) {
+                plan.remove.push(name.to_string());
+            }
+            return Ok(());
+        }
+
+        // Real directories are only touched if we materialized them and they weren't edited
+        if let Some(recorded) = manifest.links.get(name).filter(|e| e.is_copy()) {
+            let current_hash = hash_dir(path)?;
+            if recorded.content_hash.as_deref() == Some(current_hash.as_str()) {
+                plan.remove.push(name.to_string());
+            } else {
+                plan.locally_modified.push(name.to_string());
             }
         }
 
         Ok(())
     }
 
-    /// Remove all symlinks/junctions for a target (used when disabling a target)
+    /// Check if a link was created by AgentLoom
+    ///
+    /// A link is managed if the manifest records it, or if it resolves inside
+    /// the central skills directory.
+    fn is_managed_link(&self, manifest: &Manifest, name: &str, link_path: &Path) -> bool {
+        if manifest.owns(name) {
+            return true;
+        }
+
+        let Some(ref skills_dir) = self.skills_dir else {
+            return false;
+        };
+
+        self.resolve_link(link_path)
+            .is_some_and(|dest| is_inside(&dest, skills_dir))
+    }
+
+    /// Remove all managed symlinks/junctions for a target (used when disabling a target)
+    ///
+    /// Unedited copies made by the copy strategies are removed too. Links
+    /// AgentLoom didn't create are left in place.
     pub fn remove_all_symlinks(&self, target: &Target) -> Result<Vec<String>> {
         if !target.skills_path.exists() {
             return Ok(Vec::new());
         }
 
+        // Section targets only lose their managed block
+        if target.strategy.is_section() {
+            let path = &target.skills_path;
+            let existing = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+            if !existing.contains(SECTION_BEGIN) {
+                return Ok(Vec::new());
+            }
+            let name = section_entry_name(target);
+            let mut manifest = Manifest::load(section_manifest_dir(path))?;
+            write_section(path, &existing, "", manifest.owns(&name))?;
+            if !path.exists() && manifest.forget(&name) {
+                manifest.save(section_manifest_dir(path))?;
+            }
+            return Ok(vec![name]);
+        }
+
+        let mut manifest = Manifest::load(&target.skills_path)?;
         let mut removed = Vec::new();
 
         let entries = fs::read_dir(&target.skills_path)
@@ -386,251 +1572,2032 @@ impl Syncer {
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
 
-            // Only process symlinks/junctions
             let metadata = match path.symlink_metadata() {
                 Ok(m) => m,
                 Err(_) => continue,
             };
 
-            if !self.is_link(&metadata, &path) {
-                continue;
-            }
-
             let name = path
                 .file_name()
                 .and_then(|n| n.to_str())
                 .unwrap_or_default()
                 .to_string();
 
-            self.remove_link(&path).map_err(|e| Error::SymlinkRemove {
-                path: path.clone(),
-                message: e.to_string(),
-            })?;
+            if self.is_link(&metadata, &path) {
+                if !self.is_managed_link(&manifest, &name, &path) {
+                    continue;
+                }
+            } else if metadata.is_file() {
+                if !file_is_generated(&path) {
+                    continue;
+                }
+            } else {
+                // Copies are only removed if we made them and they weren't edited
+                let unmodified = manifest
+                    .links
+                    .get(&name)
+                    .filter(|e| e.is_copy())
+                    .is_some_and(|e| hash_dir(&path).ok().as_deref() == e.content_hash.as_deref());
+                if !unmodified {
+                    continue;
+                }
+            }
 
+            self.remove_entry(&manifest, &name, &path)?;
+
+            manifest.forget(&name);
             removed.push(name);
         }
 
+        manifest.save(&target.skills_path)?;
+
         Ok(removed)
     }
-}
 
-/// Action taken when creating a symlink
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SymlinkAction {
-    /// New symlink was created
-    Created,
-    /// Symlink already existed and pointed to correct target
-    Unchanged,
-    /// Existing non-symlink (native content) was skipped to protect user data
-    SkippedNative,
-}
+    /// Link a single skill into a target (using its sync strategy) and record it in the manifest
+    ///
+    /// For generated formats the skill's files are rendered instead; aggregated
+    /// files and managed sections are left for the next full sync. Skills the
+    /// target's filter leaves out aren't linked.
+    pub fn link_skill(&self, target: &Target, name: &str, skill_path: &Path) -> Result<()> {
+        if target.strategy.is_section() {
+            return Ok(());
+        }
+        if !target
+            .filter
+            .compile()?
+            .matches(&Skill::load_lenient(skill_path))
+        {
+            return Ok(());
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::skill::Skill;
-    use crate::target::TargetKind;
-    use std::path::PathBuf;
-    use tempfile::TempDir;
+        let path = target.skill_link_path(name);
+        let mut manifest = Manifest::load(&target.skills_path)?;
 
-    fn create_test_skill(skills_dir: &Path, name: &str) -> Skill {
-        Skill::create(skills_dir, name, "Test skill").unwrap()
-    }
+        if let Some(renderer) = target.format.renderer() {
+            if renderer.aggregates() {
+                return Ok(());
+            }
+            let skill = Skill::load(skill_path)?;
+            for file in renderer.render(&[&skill]) {
+                let path = target.skills_path.join(&file.name);
+                if !write_generated(&path, &file.contents)? {
+                    return Err(Error::NotASymlink(path));
+                }
+                manifest.record_generated(
+                    &file.name,
+                    &file.source,
+                    target.format,
+                    hash_contents(file.contents.as_bytes()),
+                );
+            }
+            return manifest.save(&target.skills_path);
+        }
 
-    fn create_test_target(temp_dir: &Path) -> Target {
-        let skills_path = temp_dir.join("target-skills");
-        Target::new(TargetKind::ClaudeCode, skills_path)
+        match self.write_entry(&mut manifest, name, &path, skill_path, target.strategy)? {
+            Some((SymlinkAction::SkippedNative, _)) | None => Err(Error::NotASymlink(path)),
+            Some(_) => manifest.save(&target.skills_path),
+        }
     }
 
-    #[test]
-    fn sync_creates_symlinks() {
-        let temp = TempDir::new().unwrap();
-        let skills_dir = temp.path().join("skills");
-        std::fs::create_dir_all(&skills_dir).unwrap();
+    /// Remove a single skill's link (or copy) from a target, if AgentLoom manages it
+    ///
+    /// Native content and hand-made links are never touched. Returns true if
+    /// something was removed.
+    pub fn unlink_skill(&self, target: &Target, name: &str) -> Result<bool> {
+        if target.strategy.is_section() {
+            return Ok(false);
+        }
+        if let Some(renderer) = target.format.renderer() {
+            return self.unlink_generated(target, renderer, name);
+        }
 
-        let skill = create_test_skill(&skills_dir, "my-skill");
-        let target = create_test_target(temp.path());
+        let path = target.skill_link_path(name);
+        let Ok(metadata) = path.symlink_metadata() else {
+            return Ok(false);
+        };
+
+        let mut manifest = Manifest::load(&target.skills_path)?;
+        let managed = if self.is_link(&metadata, &path) {
+            self.is_managed_link(&manifest, name, &path)
+        } else {
+            // Edited copies are kept so local changes aren't lost
+            manifest
+                .links
+                .get(name)
+                .filter(|e| e.is_copy())
+                .is_some_and(|e| hash_dir(&path).ok().as_deref() == e.content_hash.as_deref())
+        };
+        if !managed {
+            return Ok(false);
+        }
+
+        self.remove_entry(&manifest, name, &path)?;
+
+        if manifest.forget(name) {
+            manifest.save(&target.skills_path)?;
+        }
+        Ok(true)
+    }
+}
+
+impl Syncer {
+    /// Compare a target with the skills it should have, without changing anything
+    ///
+    /// Skill folder targets are checked entry by entry; for generated formats
+    /// and section targets the status is read off a sync plan.
+    pub fn status(&self, target: &Target, skills: &[Skill]) -> SyncStatus {
+        let mut status = SyncStatus::default();
+        if !target.enabled {
+            return status.finish();
+        }
+
+        if !target.format.is_skill_folder() || target.strategy.is_section() {
+            let plan = self.plan(target, skills);
+            let pending = plan.create.iter().chain(&plan.broken).chain(&plan.update);
+            status.missing_skills = pending.map(|link| link.skill.clone()).collect();
+            status.extra_items = plan.remove;
+            status.shadowed = plan.skipped_native;
+            status.unmanaged = plan.extra_items;
+            return status.finish();
+        }
+
+        // An invalid filter is reported by sync; here every skill counts
+        let expected: Vec<&Skill> = match target.filter.compile() {
+            Ok(matcher) => skills.iter().filter(|s| matcher.matches(s)).collect(),
+            Err(_) => skills.iter().collect(),
+        };
+        let manifest = Manifest::load(&target.skills_path).unwrap_or_default();
+
+        for skill in &expected {
+            let name = skill.name().to_string();
+            let path = target.skill_link_path(&name);
+            let Ok(metadata) = path.symlink_metadata() else {
+                status.missing_skills.push(name);
+                continue;
+            };
+
+            if self.is_link(&metadata, &path) {
+                match self.resolve_link(&path) {
+                    Some(dest) if !dest.exists() => status.broken_links.push(name),
+                    Some(dest) if !self.is_in_skills_dir(&dest) => {
+                        status.points_elsewhere.push(name)
+                    }
+                    Some(_) if !self.links_to(&path, &skill.path) => status.extra_items.push(name),
+                    _ => {}
+                }
+            } else if let Some(entry) = manifest.links.get(&name).filter(|e| e.is_copy()) {
+                // Edited copies are left alone by sync, so they aren't outdated
+                let current_hash = hash_dir(&path).ok();
+                if current_hash.is_none() || current_hash != entry.content_hash {
+                    status.locally_modified.push(name);
+                } else if current_hash != hash_dir(&skill.path).ok() {
+                    status.outdated.push(name);
+                }
+            } else {
+                // A native folder shadowing the skill
+                status.shadowed.push(name);
+            }
+        }
+
+        // Everything else is either ours to clean up or left alone
+        let expected_names: HashSet<&str> = expected.iter().map(|s| s.name()).collect();
+        let mut others: Vec<String> = fs::read_dir(&target.skills_path)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .filter_map(|e| e.file_name().to_str().map(str::to_string))
+                    .filter(|name| {
+                        !name.starts_with('.') && !expected_names.contains(name.as_str())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        others.sort();
+
+        for name in others {
+            let path = target.skill_link_path(&name);
+            let is_link = path
+                .symlink_metadata()
+                .is_ok_and(|m| self.is_link(&m, &path));
+            match self.resolve_link(&path).filter(|_| is_link) {
+                // Links the user made stay theirs, even broken ones
+                Some(_) if !self.is_managed_link(&manifest, &name, &path) => {
+                    status.unmanaged.push(name)
+                }
+                Some(dest) if !dest.exists() => status.broken_links.push(name),
+                Some(dest) if !self.is_in_skills_dir(&dest) => status.points_elsewhere.push(name),
+                Some(_) => status.extra_items.push(name),
+                // Copies and generated files we made are removed by sync
+                None if manifest.links.get(&name).is_some_and(|e| e.is_copy())
+                    || (path.is_file() && file_is_generated(&path)) =>
+                {
+                    status.extra_items.push(name)
+                }
+                None => status.unmanaged.push(name),
+            }
+        }
+
+        status.finish()
+    }
+
+    /// Read a link and make its destination absolute
+    fn resolve_link(&self, path: &Path) -> Option<PathBuf> {
+        let dest = self.read_link_target(path).ok()?;
+        if dest.is_absolute() {
+            return Some(dest);
+        }
+        // The OS resolves relative links from the real parent directory
+        let parent = path.parent()?;
+        let parent = parent
+            .canonicalize()
+            .unwrap_or_else(|_| parent.to_path_buf());
+        Some(normalize_lexically(&parent.join(dest)))
+    }
+
+    /// Check if a path lies inside the central skills directory (always true if none is set)
+    fn is_in_skills_dir(&self, path: &Path) -> bool {
+        self.skills_dir
+            .as_deref()
+            .is_none_or(|dir| is_inside(path, dir))
+    }
+
+    /// Repair broken links in a target
+    ///
+    /// A managed link whose destination is gone is pointed at the skill it
+    /// belongs to now: the skill with the link's name, the skill its old folder
+    /// was renamed to (see [`RenameLog`]), or one whose folder is named like the
+    /// link or its old destination (the skills directory moved). Links no skill matches are removed, and
+    /// items AgentLoom doesn't manage are only reported.
+    pub fn repair(&self, target: &Target, skills: &[Skill]) -> RepairResult {
+        let mut result = RepairResult::new(target);
+
+        // Only skill folder targets hold links
+        if !target.enabled
+            || !target.format.is_skill_folder()
+            || target.strategy.is_section()
+            || !target.skills_path.is_dir()
+        {
+            return result;
+        }
+
+        let matcher = match target.filter.compile() {
+            Ok(matcher) => matcher,
+            Err(e) => {
+                result.add_error(None, e.to_string());
+                return result;
+            }
+        };
+        let selected: Vec<&Skill> = skills.iter().filter(|s| matcher.matches(s)).collect();
+        let renames = self
+            .skills_dir
+            .as_deref()
+            .and_then(|dir| RenameLog::load(dir).ok())
+            .unwrap_or_default();
+
+        let mut manifest = match Manifest::load(&target.skills_path) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                result.add_error(None, format!("Failed to read manifest: {e}"));
+                return result;
+            }
+        };
+        let mut names: Vec<String> = match fs::read_dir(&target.skills_path) {
+            Ok(entries) => entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| e.file_name().to_str().map(str::to_string))
+                .filter(|name| !name.starts_with('.'))
+                .collect(),
+            Err(e) => {
+                result.add_error(None, Error::read_dir(&target.skills_path, e).to_string());
+                return result;
+            }
+        };
+        names.sort();
+
+        let mut manifest_changed = false;
+        for name in names {
+            let path = target.skill_link_path(&name);
+            let Ok(metadata) = path.symlink_metadata() else {
+                continue;
+            };
+
+            if !self.is_link(&metadata, &path) {
+                // Copies and generated files we made are fine; anything else is the user's
+                if !manifest.owns(&name) {
+                    result.unmanaged.push(name);
+                }
+                continue;
+            }
+            if !self.is_managed_link(&manifest, &name, &path) {
+                result.unmanaged.push(name);
+                continue;
+            }
+
+            let (Ok(destination), Some(resolved)) =
+                (self.read_link_target(&path), self.resolve_link(&path))
+            else {
+                continue;
+            };
+            if resolved.exists() {
+                continue;
+            }
+
+            // The skill the link belongs to now, unless it's already linked under its own name
+            let old_folder = destination.file_name().and_then(|n| n.to_str());
+            let renamed = old_folder.and_then(|f| renames.resolve(f));
+            let skill = selected
+                .iter()
+                .find(|s| s.name() == name)
+                .or_else(|| selected.iter().find(|s| Some(s.folder_name()) == renamed))
+                .or_else(|| {
+                    selected
+                        .iter()
+                        .find(|s| s.folder_name() == name || Some(s.folder_name()) == old_folder)
+                })
+                .filter(|s| {
+                    s.name() == name || target.skill_link_path(s.name()).symlink_metadata().is_err()
+                });
+
+            let repaired = match skill {
+                Some(skill) => self.relink(target, &mut manifest, &name, skill).map(|()| {
+                    result
+                        .relinked
+                        .insert(name.clone(), skill.name().to_string());
+                }),
+                None => self
+                    .remove_link(&path)
+                    .map_err(|e| Error::SymlinkRemove {
+                        path: path.clone(),
+                        message: e.to_string(),
+                    })
+                    .map(|()| {
+                        manifest.forget(&name);
+                        result.removed.push(name.clone());
+                    }),
+            };
+            match repaired {
+                Ok(()) => manifest_changed = true,
+                Err(e) => result.add_error(Some(&name), e.to_string()),
+            }
+        }
+
+        if manifest_changed {
+            if let Err(e) = manifest.save(&target.skills_path) {
+                result.add_error(None, format!("Failed to update manifest: {e}"));
+            }
+        }
+
+        result
+    }
+
+    /// Replace a broken link with the skill it belongs to, named after the skill
+    fn relink(
+        &self,
+        target: &Target,
+        manifest: &mut Manifest,
+        name: &str,
+        skill: &Skill,
+    ) -> Result<()> {
+        let path = target.skill_link_path(name);
+        if skill.name() != name {
+            self.remove_link(&path).map_err(|e| Error::SymlinkRemove {
+                path: path.clone(),
+                message: e.to_string(),
+            })?;
+            manifest.forget(name);
+        }
+
+        let new_path = target.skill_link_path(skill.name());
+        match self.write_entry(
+            manifest,
+            skill.name(),
+            &new_path,
+            &skill.path,
+            target.strategy,
+        )? {
+            Some((SymlinkAction::SkippedNative, _)) | None => Err(Error::NotASymlink(new_path)),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Find native folders that keep skills out of a target
+    ///
+    /// Each one is compared with the skill's central copy; a folder with
+    /// different contents comes back with a conflict.
+    pub fn adoptable(&self, target: &Target, skills: &[Skill]) -> Vec<AdoptCandidate> {
+        if !target.format.is_skill_folder() || target.strategy.is_section() {
+            return Vec::new();
+        }
+
+        let plan = self.plan(target, skills);
+        plan.skipped_native
+            .iter()
+            .filter_map(|name| {
+                let skill = skills.iter().find(|s| s.name() == name)?;
+                let native_path = target.skill_link_path(name);
+                // Only folders can stand in for a skill
+                if !native_path.is_dir() {
+                    return None;
+                }
+
+                let identical = matches!(
+                    (hash_dir(&native_path), hash_dir(&skill.path)),
+                    (Ok(native), Ok(central)) if native == central
+                );
+                Some(AdoptCandidate {
+                    target_id: target.id().to_string(),
+                    target_name: target.name().to_string(),
+                    skill: name.clone(),
+                    native_path,
+                    conflict: (!identical).then(|| ConflictInfo {
+                        existing_path: skill.path.clone(),
+                        existing_description: skill.meta.description.clone(),
+                    }),
+                })
+            })
+            .collect()
+    }
+
+    /// Replace native folders in a target with the managed skill
+    ///
+    /// Selections for other targets are ignored. Each native folder is moved
+    /// to [`ADOPT_BACKUP_DIR_NAME`] before the skill takes its place; with
+    /// [`ConflictResolution::Overwrite`] the central copy is replaced with the
+    /// native folder first (and also kept as a backup).
+    pub fn adopt(
+        &self,
+        target: &Target,
+        skills: &[Skill],
+        selections: &[AdoptSelection],
+    ) -> AdoptResult {
+        let mut result = AdoptResult::new(target);
+        let selections: Vec<&AdoptSelection> = selections
+            .iter()
+            .filter(|s| s.target_id == target.id())
+            .collect();
+        if selections.is_empty() {
+            return result;
+        }
+
+        let candidates = self.adoptable(target, skills);
+        let mut manifest = match Manifest::load(&target.skills_path) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                result.add_error(None, format!("Failed to read manifest: {e}"));
+                return result;
+            }
+        };
+
+        let mut manifest_changed = false;
+        for selection in selections {
+            let name = selection.skill.as_str();
+            let candidate = candidates.iter().find(|c| c.skill == name);
+            let skill = skills.iter().find(|s| s.name() == name);
+            let (Some(candidate), Some(skill)) = (candidate, skill) else {
+                result.add_error(Some(name), "No native folder to adopt");
+                continue;
+            };
+
+            let overwrite = match (selection.resolution, &candidate.conflict) {
+                (ConflictResolution::Skip, _) => {
+                    result.skipped.push(name.to_string());
+                    continue;
+                }
+                (ConflictResolution::Import, Some(_)) => {
+                    result.add_error(
+                        Some(name),
+                        "Native folder differs from the central copy (skip it or overwrite the central copy)",
+                    );
+                    continue;
+                }
+                (ConflictResolution::Import, None) => false,
+                (ConflictResolution::Overwrite, conflict) => conflict.is_some(),
+            };
+
+            match self.adopt_folder(target, &mut manifest, skill, overwrite) {
+                Ok(backup) => {
+                    manifest_changed = true;
+                    result.adopted.push(name.to_string());
+                    if overwrite {
+                        result.overwritten.push(name.to_string());
+                    }
+                    result.backups.insert(name.to_string(), backup);
+                }
+                Err(e) => result.add_error(Some(name), e.to_string()),
+            }
+        }
+
+        if manifest_changed {
+            if let Err(e) = manifest.save(&target.skills_path) {
+                result.add_error(None, format!("Failed to update manifest: {e}"));
+            }
+        }
+
+        result
+    }
+
+    /// Move a skill's native folder into the backup directory and place the skill instead
+    ///
+    /// Returns where the native folder was moved. If the skill can't be
+    /// placed, the native folder is put back.
+    fn adopt_folder(
+        &self,
+        target: &Target,
+        manifest: &mut Manifest,
+        skill: &Skill,
+        overwrite: bool,
+    ) -> Result<PathBuf> {
+        let path = target.skill_link_path(skill.name());
+        let backup_dir = target.skills_path.join(ADOPT_BACKUP_DIR_NAME);
+        fs::create_dir_all(&backup_dir).map_err(|e| Error::create_dir(&backup_dir, e))?;
+
+        if overwrite {
+            // Keep the central copy being replaced, then swap the native contents in
+            let central_backup = unique_path(&backup_dir, &format!("{}.central", skill.name()));
+            copy_tree(&skill.path, &central_backup)?;
+            replace_tree(&path, &skill.path)?;
+        }
+
+        // Same directory tree, so this is a plain rename
+        let backup = unique_path(&backup_dir, skill.name());
+        fs::rename(&path, &backup).map_err(|e| Error::io(&path, e))?;
+
+        let placed = self.write_entry(manifest, skill.name(), &path, &skill.path, target.strategy);
+        match placed {
+            Ok(Some((action, _))) if action != SymlinkAction::SkippedNative => Ok(backup),
+            failed => {
+                // Clear out anything half written and put the native folder back
+                if let Ok(metadata) = path.symlink_metadata() {
+                    let _ = if self.is_link(&metadata, &path) {
+                        self.remove_link(&path)
+                    } else {
+                        fs::remove_dir_all(&path)
+                    };
+                }
+                fs::rename(&backup, &path).map_err(|e| Error::io(&backup, e))?;
+                Err(failed.err().unwrap_or(Error::NotASymlink(path)))
+            }
+        }
+    }
+
+    /// Remove the files generated from a single skill
+    ///
+    /// Aggregated files are left for the next full sync.
+    fn unlink_generated(
+        &self,
+        target: &Target,
+        renderer: &dyn FormatRenderer,
+        name: &str,
+    ) -> Result<bool> {
+        if renderer.aggregates() {
+            return Ok(false);
+        }
+
+        let mut manifest = Manifest::load(&target.skills_path)?;
+        let files: Vec<String> = manifest
+            .links
+            .iter()
+            .filter(|(_, e)| e.is_generated() && e.destination.file_name() == Some(name.as_ref()))
+            .map(|(file, _)| file.clone())
+            .collect();
+
+        let mut removed = false;
+        for file in files {
+            let path = target.skills_path.join(&file);
+            if path.is_file() && file_is_generated(&path) {
+                fs::remove_file(&path).map_err(|e| Error::io(&path, e))?;
+                removed = true;
+            }
+            manifest.forget(&file);
+        }
+
+        manifest.save(&target.skills_path)?;
+        Ok(removed)
+    }
+}
+
+/// Pick the skills a target wants, recording the ones its filter leaves out
+///
+/// Returns `None` (with the error recorded) if the filter doesn't compile.
+fn select_skills<'a>(
+    target: &Target,
+    skills: &'a [Skill],
+    scope: Option<&HashSet<&str>>,
+    plan: &mut SyncPlan,
+) -> Option<(Vec<&'a Skill>, Vec<&'a Skill>)> {
+    let matcher = match target.filter.compile() {
+        Ok(matcher) => matcher,
+        Err(e) => {
+            plan.add_error(None, e.to_string());
+            return None;
+        }
+    };
+    let (selected, filtered): (Vec<&Skill>, Vec<&Skill>) = skills
+        .iter()
+        .filter(|s| scope.is_none_or(|names| names.contains(s.name())))
+        .partition(|s| matcher.matches(s));
+    plan.filtered = filtered.iter().map(|s| s.name().to_string()).collect();
+    Some((selected, filtered))
+}
+
+/// Name under which a section target's block is reported (the file name)
+fn section_entry_name(target: &Target) -> String {
+    target
+        .skills_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| target.id().to_string())
+}
+
+/// Directory holding the manifest for a section target's file
+fn section_manifest_dir(path: &Path) -> &Path {
+    path.parent().unwrap_or(Path::new("."))
+}
+
+/// Splice a managed section into a file's contents and write the result
+///
+/// A file AgentLoom created (`owned`) is deleted instead if nothing but
+/// whitespace would be left; the user's own files keep whatever remains. The
+/// write is atomic, which is why section targets need no journal.
+fn write_section(path: &Path, existing: &str, body: &str, owned: bool) -> Result<()> {
+    let updated = splice_section(existing, body, path)?;
+    if updated == existing {
+        return Ok(());
+    }
+    if owned && updated.trim().is_empty() {
+        if path.exists() {
+            fs::remove_file(path).map_err(|e| Error::io(path, e))?;
+        }
+        return Ok(());
+    }
+
+    // Written beside the file and renamed over it, so a failed write never leaves half a file
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp = path.with_file_name(format!(".{file_name}.agentloom-tmp"));
+    fs::write(&temp, updated)
+        .and_then(|()| fs::rename(&temp, path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&temp);
+            Error::io(path, e)
+        })
+}
+
+/// Write a generated file unless something that isn't ours is in the way
+///
+/// Returns false (and writes nothing) if the path holds a link, a directory
+/// or a file without the ownership marker.
+fn write_generated(path: &Path, contents: &str) -> Result<bool> {
+    if let Ok(metadata) = path.symlink_metadata() {
+        if !metadata.is_file() || !file_is_generated(path) {
+            return Ok(false);
+        }
+    }
+    fs::write(path, contents).map_err(|e| Error::io(path, e))?;
+    Ok(true)
+}
+
+/// What currently exists at a skill's path in a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryState {
+    /// Nothing exists at the path
+    Missing,
+    /// A link to the expected skill, or an up-to-date copy
+    Correct,
+    /// A link pointing somewhere else (or nowhere), or an entry using a different strategy
+    Mismatched,
+    /// A copy we made that the source has since moved past
+    Outdated,
+    /// A copy we made that was edited in place
+    LocallyModified,
+    /// Native (non-symlink) content that must not be touched
+    Native,
+}
+
+/// Compute a content hash over every file in a directory
+///
+/// The hash covers relative paths and file contents in a stable order, so two
+/// trees with the same files hash the same regardless of where they live.
+pub fn hash_dir(dir: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+
+    for entry in WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry.map_err(|e| {
+            let path = e.path().unwrap_or(dir).to_path_buf();
+            Error::io(path, std::io::Error::other(e.to_string()))
+        })?;
+
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+        let contents = fs::read(entry.path()).map_err(|e| Error::io(entry.path(), e))?;
+
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(&contents);
+    }
+
+    Ok(hex(&hasher.finalize()))
+}
+
+/// Compute a content hash over a single file's contents
+pub fn hash_contents(contents: &[u8]) -> String {
+    hex(&Sha256::digest(contents))
+}
+
+/// Format a digest as lowercase hex
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Recursively copy a directory and its contents
+pub(crate) fn copy_tree(src: &Path, dst: &Path) -> Result<()> {
+    fs::create_dir_all(dst).map_err(|e| Error::create_dir(dst, e))?;
+
+    for entry in fs::read_dir(src).map_err(|e| Error::read_dir(src, e))? {
+        let entry = entry.map_err(|e| Error::read_dir(src, e))?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if src_path.is_dir() {
+            copy_tree(&src_path, &dst_path)?;
+        } else {
+            fs::copy(&src_path, &dst_path).map_err(|e| Error::io(&src_path, e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace a directory's contents with a copy of another directory
+///
+/// The copy is made next to `dst` first, so a failed copy leaves `dst` as it was.
+fn replace_tree(src: &Path, dst: &Path) -> Result<()> {
+    let name = dst.file_name().and_then(|n| n.to_str()).unwrap_or("skill");
+    let staging = dst.with_file_name(format!(".{name}.agentloom-tmp"));
+    let _ = fs::remove_dir_all(&staging);
+    if let Err(e) = copy_tree(src, &staging) {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    fs::remove_dir_all(dst).map_err(|e| Error::io(dst, e))?;
+    fs::rename(&staging, dst).map_err(|e| Error::io(dst, e))
+}
+
+/// Check if `path` lies inside `dir`, comparing real paths when both exist
+fn is_inside(path: &Path, dir: &Path) -> bool {
+    path.starts_with(dir)
+        || matches!(
+            (path.canonicalize(), dir.canonicalize()),
+            (Ok(path), Ok(dir)) if path.starts_with(&dir)
+        )
+}
+
+/// Check if two existing paths are the same file or directory once symlinks are resolved
+fn same_existing_path(a: &Path, b: &Path) -> bool {
+    matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+/// Resolve `.` and `..` components without touching the filesystem
+fn normalize_lexically(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// The path that leads from directory `from` to `to`, if both are absolute and share a root
+fn relative_path(from: &Path, to: &Path) -> Option<PathBuf> {
+    use std::path::Component;
+
+    if !from.is_absolute() || !to.is_absolute() {
+        return None;
+    }
+    let (from, to) = (normalize_lexically(from), normalize_lexically(to));
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    // Different drives or prefixes have no relative path between them
+    if from.first() != to.first() {
+        return None;
+    }
+
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut relative: PathBuf = from[common..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .collect();
+    relative.extend(&to[common..]);
+    Some(relative)
+}
+
+/// A path in `dir` named `name`, or `name-1`, `name-2`... if that is taken
+fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    let mut path = dir.join(name);
+    let mut n = 1;
+    while path.symlink_metadata().is_ok() {
+        path = dir.join(format!("{name}-{n}"));
+        n += 1;
+    }
+    path
+}
+
+/// Recreate a directory structure, hardlinking every file
+fn hardlink_tree(src: &Path, dst: &Path) -> Result<()> {
+    fs::create_dir_all(dst).map_err(|e| Error::create_dir(dst, e))?;
+
+    for entry in fs::read_dir(src).map_err(|e| Error::read_dir(src, e))? {
+        let entry = entry.map_err(|e| Error::read_dir(src, e))?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if src_path.is_dir() {
+            hardlink_tree(&src_path, &dst_path)?;
+        } else {
+            fs::hard_link(&src_path, &dst_path).map_err(|e| Error::io(&dst_path, e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Action taken when creating a symlink
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SymlinkAction {
+    /// New symlink was created
+    Created,
+    /// Symlink already existed and pointed to correct target
+    Unchanged,
+    /// Existing non-symlink (native content) was skipped to protect user data
+    SkippedNative,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::{Skill, SKILL_FILE_NAME};
+    use crate::target::TargetKind;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn create_test_skill(skills_dir: &Path, name: &str) -> Skill {
+        Skill::create(skills_dir, name, "Test skill").unwrap()
+    }
+
+    fn create_test_target(temp_dir: &Path) -> Target {
+        let skills_path = temp_dir.join("target-skills");
+        Target::new(TargetKind::ClaudeCode, skills_path)
+    }
+
+    #[test]
+    fn sync_creates_symlinks() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+        let result = syncer.sync_target(&target, &[skill]);
+
+        assert!(result.is_success());
+        assert_eq!(result.created.len(), 1);
+        assert_eq!(result.created[0], "my-skill");
+        assert!(target.skill_link_path("my-skill").exists());
+    }
+
+    #[test]
+    fn sync_detects_unchanged_symlinks() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+
+        // First sync - creates symlink
+        let result1 = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert_eq!(result1.created.len(), 1);
+
+        // Second sync - should be unchanged
+        let result2 = syncer.sync_target(&target, &[skill]);
+        assert!(result2.created.is_empty());
+        assert_eq!(result2.unchanged.len(), 1);
+        assert_eq!(result2.unchanged[0], "my-skill");
+    }
+
+    #[test]
+    fn sync_removes_stale_symlinks() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill1 = create_test_skill(&skills_dir, "skill-one");
+        let skill2 = create_test_skill(&skills_dir, "skill-two");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+
+        // Sync both skills
+        let result1 = syncer.sync_target(&target, &[skill1.clone(), skill2]);
+        assert_eq!(result1.created.len(), 2);
+
+        // Sync with only one skill - should remove the other
+        let result2 = syncer.sync_target(&target, &[skill1]);
+        assert_eq!(result2.removed.len(), 1);
+        assert_eq!(result2.removed[0], "skill-two");
+        assert!(!target.skill_link_path("skill-two").exists());
+        assert!(target.skill_link_path("skill-one").exists());
+    }
+
+    #[test]
+    fn sync_skips_native_folder() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        // Create target directory and a real folder where symlink should go
+        std::fs::create_dir_all(&target.skills_path).unwrap();
+        let conflict_path = target.skill_link_path("my-skill");
+        std::fs::create_dir_all(&conflict_path).unwrap();
+
+        let syncer = Syncer::new();
+        let result = syncer.sync_target(&target, &[skill]);
+
+        // Native folder should be skipped, not deleted
+        assert!(result.is_success());
+        assert_eq!(result.skipped_native.len(), 1);
+        assert_eq!(result.skipped_native[0], "my-skill");
+        assert!(conflict_path.exists()); // Native folder preserved
+    }
+
+    #[test]
+    fn sync_preserves_native_folder_with_content() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        // Create target directory and a real folder where symlink should go
+        std::fs::create_dir_all(&target.skills_path).unwrap();
+        let conflict_path = target.skill_link_path("my-skill");
+        std::fs::create_dir_all(&conflict_path).unwrap();
+
+        // Create a file inside to simulate existing native skill
+        std::fs::write(conflict_path.join("test.txt"), "test content").unwrap();
+
+        let syncer = Syncer::new();
+        let result = syncer.sync_target(&target, &[skill]);
+
+        assert!(result.is_success());
+        assert_eq!(result.skipped_native.len(), 1);
+        assert_eq!(result.skipped_native[0], "my-skill");
+
+        // The native folder and its content should be preserved
+        assert!(conflict_path.exists());
+        assert!(conflict_path.join("test.txt").exists());
+        let content = std::fs::read_to_string(conflict_path.join("test.txt")).unwrap();
+        assert_eq!(content, "test content");
+    }
+
+    #[test]
+    fn sync_skips_disabled_target() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let mut target = create_test_target(temp.path());
+        target.enabled = false;
+
+        let syncer = Syncer::new();
+        let result = syncer.sync_target(&target, &[skill]);
+
+        assert!(result.is_success());
+        assert!(result.created.is_empty());
+        assert!(!target.skill_link_path("my-skill").exists());
+    }
+
+    #[test]
+    fn sync_all_targets() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+
+        let target1 = Target::new(TargetKind::ClaudeCode, temp.path().join("target1"));
+        let target2 = Target::new(TargetKind::Codex, temp.path().join("target2"));
+
+        let syncer = Syncer::new();
+        let results = syncer.sync_all(&[target1, target2], &[skill]);
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.is_success()));
+        assert!(results.iter().all(|r| r.created.len() == 1));
+    }
+
+    #[test]
+    fn remove_all_symlinks() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill1 = create_test_skill(&skills_dir, "skill-one");
+        let skill2 = create_test_skill(&skills_dir, "skill-two");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+
+        // Sync skills first
+        syncer.sync_target(&target, &[skill1, skill2]);
+        assert!(target.skill_link_path("skill-one").exists());
+        assert!(target.skill_link_path("skill-two").exists());
+
+        // Remove all
+        let removed = syncer.remove_all_symlinks(&target).unwrap();
+        assert_eq!(removed.len(), 2);
+        assert!(!target.skill_link_path("skill-one").exists());
+        assert!(!target.skill_link_path("skill-two").exists());
+    }
+
+    #[test]
+    fn plan_does_not_touch_filesystem() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+        let plan = syncer.plan(&target, &[skill]);
+
+        assert!(plan.create_dir);
+        assert!(plan.has_changes());
+        assert_eq!(plan.create.len(), 1);
+        assert_eq!(plan.create[0].skill, "my-skill");
+        assert!(!target.skills_path.exists());
+    }
+
+    #[test]
+    fn plan_reports_broken_stale_and_native() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let linked = create_test_skill(&skills_dir, "linked");
+        let broken = create_test_skill(&skills_dir, "broken");
+        let native = create_test_skill(&skills_dir, "native");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, std::slice::from_ref(&linked));
+
+        std::fs::create_dir_all(target.skill_link_path("native")).unwrap();
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(temp.path().join("gone"), target.skill_link_path("broken"))
+                .unwrap();
+            std::os::unix::fs::symlink(
+                skills_dir.join("old-skill"),
+                target.skill_link_path("old-skill"),
+            )
+            .unwrap();
+        }
+
+        let plan = syncer.plan(&target, &[linked, broken, native]);
+
+        assert!(!plan.create_dir);
+        assert_eq!(plan.unchanged, vec!["linked"]);
+        assert_eq!(plan.skipped_native, vec!["native"]);
+        #[cfg(unix)]
+        {
+            assert_eq!(plan.broken.len(), 1);
+            assert_eq!(plan.broken[0].skill, "broken");
+            assert_eq!(plan.remove, vec!["old-skill"]);
+            // Planning leaves the broken link in place
+            assert!(target.skill_link_path("broken").is_symlink());
+        }
+    }
+
+    #[test]
+    fn apply_plan_executes_planned_actions() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill1 = create_test_skill(&skills_dir, "skill-one");
+        let skill2 = create_test_skill(&skills_dir, "skill-two");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+        syncer.sync_target(&target, &[skill1.clone(), skill2]);
+
+        let plan = syncer.plan(&target, std::slice::from_ref(&skill1));
+        assert_eq!(plan.remove, vec!["skill-two"]);
+        assert!(target.skill_link_path("skill-two").exists());
+
+        let result = syncer.apply_plan(&target, &plan);
+        assert!(result.is_success());
+        assert_eq!(result.removed, vec!["skill-two"]);
+        assert_eq!(result.unchanged, vec!["skill-one"]);
+        assert!(!target.skill_link_path("skill-two").exists());
+    }
+
+    #[test]
+    fn plan_errors_when_dir_missing_and_creation_disabled() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer {
+            create_dirs: false,
+            ..Syncer::default()
+        };
+        let plan = syncer.plan(&target, &[skill]);
+
+        assert!(!plan.has_changes());
+        assert_eq!(plan.errors.len(), 1);
+        assert!(plan.errors[0].message.contains("does not exist"));
+    }
+
+    #[test]
+    fn sync_records_links_in_manifest() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::new();
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+
+        let manifest = Manifest::load(&target.skills_path).unwrap();
+        assert!(manifest.owns("my-skill"));
+        assert_eq!(manifest.links["my-skill"].destination, skill.path);
+
+        // Removing the skill drops it from the manifest again
+        syncer.sync_target(&target, &[]);
+        let manifest = Manifest::load(&target.skills_path).unwrap();
+        assert!(!manifest.owns("my-skill"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn sync_leaves_unmanaged_symlinks_alone() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        let elsewhere = temp.path().join("elsewhere");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+        std::fs::create_dir_all(&elsewhere).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let target = create_test_target(temp.path());
+        std::fs::create_dir_all(&target.skills_path).unwrap();
+
+        let user_link = target.skill_link_path("user-link");
+        std::os::unix::fs::symlink(&elsewhere, &user_link).unwrap();
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        let result = syncer.sync_target(&target, &[skill]);
+
+        assert!(result.is_success());
+        assert!(result.removed.is_empty());
+        assert_eq!(result.extra_items, vec!["user-link"]);
+        assert!(user_link.is_symlink());
+
+        let removed = syncer.remove_all_symlinks(&target).unwrap();
+        assert_eq!(removed, vec!["my-skill"]);
+        assert!(user_link.is_symlink());
+        assert!(!Manifest::path_in(&target.skills_path).exists());
+    }
+
+    #[test]
+    fn copy_strategy_materializes_and_updates() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let mut target = create_test_target(temp.path());
+        target.strategy = SyncStrategy::Copy;
 
         let syncer = Syncer::new();
-        let result = syncer.sync_target(&target, &[skill]);
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
 
         assert!(result.is_success());
-        assert_eq!(result.created.len(), 1);
-        assert_eq!(result.created[0], "my-skill");
-        assert!(target.skill_link_path("my-skill").exists());
+        assert_eq!(result.created, vec!["my-skill"]);
+        assert_eq!(result.strategies["my-skill"], SyncStrategy::Copy);
+
+        let copy = target.skill_link_path("my-skill");
+        assert!(!copy.is_symlink());
+        assert!(copy.join(SKILL_FILE_NAME).exists());
+
+        // Unchanged source leaves the copy alone
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert_eq!(result.unchanged, vec!["my-skill"]);
+
+        // A changed source refreshes the copy
+        std::fs::write(skill.path.join("notes.md"), "new file").unwrap();
+        let plan = syncer.plan(&target, std::slice::from_ref(&skill));
+        assert_eq!(plan.update.len(), 1);
+
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert_eq!(result.updated, vec!["my-skill"]);
+        assert_eq!(
+            std::fs::read_to_string(copy.join("notes.md")).unwrap(),
+            "new file"
+        );
+
+        // Removing the skill removes the unedited copy
+        let result = syncer.sync_target(&target, &[]);
+        assert_eq!(result.removed, vec!["my-skill"]);
+        assert!(!copy.exists());
     }
 
     #[test]
-    fn sync_detects_unchanged_symlinks() {
+    fn copy_strategy_reports_local_edits() {
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
         let skill = create_test_skill(&skills_dir, "my-skill");
-        let target = create_test_target(temp.path());
+        let mut target = create_test_target(temp.path());
+        target.strategy = SyncStrategy::Copy;
 
         let syncer = Syncer::new();
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
 
-        // First sync - creates symlink
-        let result1 = syncer.sync_target(&target, std::slice::from_ref(&skill));
-        assert_eq!(result1.created.len(), 1);
+        let edited = target.skill_link_path("my-skill").join("local.md");
+        std::fs::write(&edited, "local change").unwrap();
+        std::fs::write(skill.path.join("notes.md"), "upstream change").unwrap();
 
-        // Second sync - should be unchanged
-        let result2 = syncer.sync_target(&target, &[skill]);
-        assert!(result2.created.is_empty());
-        assert_eq!(result2.unchanged.len(), 1);
-        assert_eq!(result2.unchanged[0], "my-skill");
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert!(result.is_success());
+        assert_eq!(result.locally_modified, vec!["my-skill"]);
+        assert!(result.updated.is_empty());
+        assert!(edited.exists());
+
+        // Edited copies survive removal of the skill too
+        let result = syncer.sync_target(&target, &[]);
+        assert!(result.removed.is_empty());
+        assert_eq!(result.locally_modified, vec!["my-skill"]);
+        assert!(edited.exists());
     }
 
+    #[cfg(unix)]
     #[test]
-    fn sync_removes_stale_symlinks() {
+    fn hardlink_tree_shares_files_with_source() {
+        use std::os::unix::fs::MetadataExt;
+
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
-        let skill1 = create_test_skill(&skills_dir, "skill-one");
-        let skill2 = create_test_skill(&skills_dir, "skill-two");
-        let target = create_test_target(temp.path());
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let mut target = create_test_target(temp.path());
+        target.strategy = SyncStrategy::HardlinkTree;
 
         let syncer = Syncer::new();
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert!(result.is_success());
+        assert_eq!(result.strategies["my-skill"], SyncStrategy::HardlinkTree);
 
-        // Sync both skills
-        let result1 = syncer.sync_target(&target, &[skill1.clone(), skill2]);
-        assert_eq!(result1.created.len(), 2);
+        let source = std::fs::metadata(skill.path.join(SKILL_FILE_NAME)).unwrap();
+        let linked =
+            std::fs::metadata(target.skill_link_path("my-skill").join(SKILL_FILE_NAME)).unwrap();
+        assert_eq!(source.ino(), linked.ino());
 
-        // Sync with only one skill - should remove the other
-        let result2 = syncer.sync_target(&target, &[skill1]);
-        assert_eq!(result2.removed.len(), 1);
-        assert_eq!(result2.removed[0], "skill-two");
-        assert!(!target.skill_link_path("skill-two").exists());
-        assert!(target.skill_link_path("skill-one").exists());
+        let result = syncer.sync_target(&target, &[skill]);
+        assert_eq!(result.unchanged, vec!["my-skill"]);
     }
 
     #[test]
-    fn sync_skips_native_folder() {
+    fn switching_back_to_symlink_replaces_copy() {
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
         let skill = create_test_skill(&skills_dir, "my-skill");
-        let target = create_test_target(temp.path());
-
-        // Create target directory and a real folder where symlink should go
-        std::fs::create_dir_all(&target.skills_path).unwrap();
-        let conflict_path = target.skill_link_path("my-skill");
-        std::fs::create_dir_all(&conflict_path).unwrap();
+        let mut target = create_test_target(temp.path());
+        target.strategy = SyncStrategy::Copy;
 
         let syncer = Syncer::new();
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+
+        target.strategy = SyncStrategy::Symlink;
         let result = syncer.sync_target(&target, &[skill]);
 
-        // Native folder should be skipped, not deleted
         assert!(result.is_success());
-        assert_eq!(result.skipped_native.len(), 1);
-        assert_eq!(result.skipped_native[0], "my-skill");
-        assert!(conflict_path.exists()); // Native folder preserved
+        assert_eq!(result.strategies["my-skill"], SyncStrategy::Symlink);
+        assert!(target.skill_link_path("my-skill").is_symlink());
     }
 
     #[test]
-    fn sync_preserves_native_folder_with_content() {
+    fn filtered_skills_are_skipped_and_unlinked() {
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
-        let skill = create_test_skill(&skills_dir, "my-skill");
-        let target = create_test_target(temp.path());
+        let public = create_test_skill(&skills_dir, "public-skill");
+        let internal = create_test_skill(&skills_dir, "internal-skill");
+        let skills = vec![public, internal];
+        let mut target = create_test_target(temp.path());
 
-        // Create target directory and a real folder where symlink should go
-        std::fs::create_dir_all(&target.skills_path).unwrap();
-        let conflict_path = target.skill_link_path("my-skill");
-        std::fs::create_dir_all(&conflict_path).unwrap();
+        let syncer = Syncer {
+            remove_stale: false,
+            ..Syncer::default()
+        };
+        let result = syncer.sync_target(&target, &skills);
+        assert_eq!(result.created.len(), 2);
 
-        // Create a file inside to simulate existing native skill
-        std::fs::write(conflict_path.join("test.txt"), "test content").unwrap();
+        target.filter.exclude = vec!["internal-*".to_string()];
+        let result = syncer.sync_target(&target, &skills);
 
-        let syncer = Syncer::new();
-        let result = syncer.sync_target(&target, &[skill]);
+        assert!(result.is_success());
+        assert_eq!(result.filtered, vec!["internal-skill"]);
+        assert_eq!(result.removed, vec!["internal-skill"]);
+        assert_eq!(result.unchanged, vec!["public-skill"]);
+        assert!(!target.skill_link_path("internal-skill").exists());
+        assert!(target.skill_link_path("public-skill").exists());
+    }
+
+    #[test]
+    fn invalid_filter_is_reported() {
+        let temp = TempDir::new().unwrap();
+        let mut target = create_test_target(temp.path());
+        target.filter.include = vec!["[oops".to_string()];
+
+        let plan = Syncer::new().plan(&target, &[]);
+        assert_eq!(plan.errors.len(), 1);
+    }
+
+    #[test]
+    fn synced_count_calculation() {
+        let target = Target::new(TargetKind::ClaudeCode, PathBuf::from("/test"));
+        let mut result = SyncResult::new(&target);
+
+        result.created.push("skill-1".to_string());
+        result.created.push("skill-2".to_string());
+        result.unchanged.push("skill-3".to_string());
+
+        assert_eq!(result.synced_count(), 3);
+    }
+
+    #[test]
+    fn generated_format_writes_updates_and_removes_files() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let mut skill = create_test_skill(&skills_dir, "my-skill");
+        let other = create_test_skill(&skills_dir, "other");
+        let mut target = create_test_target(temp.path());
+        target.format = TargetFormat::CursorRule;
 
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        let result = syncer.sync_target(&target, &[skill.clone(), other.clone()]);
         assert!(result.is_success());
-        assert_eq!(result.skipped_native.len(), 1);
-        assert_eq!(result.skipped_native[0], "my-skill");
+        assert_eq!(result.created, vec!["my-skill.mdc", "other.mdc"]);
 
-        // The native folder and its content should be preserved
-        assert!(conflict_path.exists());
-        assert!(conflict_path.join("test.txt").exists());
-        let content = std::fs::read_to_string(conflict_path.join("test.txt")).unwrap();
-        assert_eq!(content, "test content");
+        let rule = target.skills_path.join("my-skill.mdc");
+        assert!(rule.is_file());
+        let manifest = Manifest::load(&target.skills_path).unwrap();
+        assert!(manifest.links["my-skill.mdc"].is_generated());
+
+        // Unchanged skills leave their files alone
+        let result = syncer.sync_target(&target, &[skill.clone(), other.clone()]);
+        assert_eq!(result.unchanged.len(), 2);
+        assert!(!result.has_changes());
+
+        // A changed skill regenerates its file
+        skill.content = "# Updated\n".to_string();
+        let result = syncer.sync_target(&target, &[skill.clone(), other]);
+        assert_eq!(result.updated, vec!["my-skill.mdc"]);
+        assert!(fs::read_to_string(&rule).unwrap().contains("# Updated"));
+
+        // A removed skill loses its file
+        let result = syncer.sync_target(&target, &[skill]);
+        assert_eq!(result.removed, vec!["other.mdc"]);
+        assert!(!target.skills_path.join("other.mdc").exists());
     }
 
     #[test]
-    fn sync_skips_disabled_target() {
+    fn generated_format_never_touches_unmarked_files() {
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
         let skill = create_test_skill(&skills_dir, "my-skill");
         let mut target = create_test_target(temp.path());
-        target.enabled = false;
+        target.format = TargetFormat::AgentsMd;
+        std::fs::create_dir_all(&target.skills_path).unwrap();
+
+        // The user's own AGENTS.md and an unrelated note
+        let agents = target.skills_path.join("AGENTS.md");
+        std::fs::write(&agents, "# My instructions\n").unwrap();
+        let note = target.skills_path.join("notes.md");
+        std::fs::write(&note, "mine\n").unwrap();
 
         let syncer = Syncer::new();
         let result = syncer.sync_target(&target, &[skill]);
+        assert_eq!(result.skipped_native, vec!["AGENTS.md"]);
+        assert_eq!(fs::read_to_string(&agents).unwrap(), "# My instructions\n");
+
+        // With no skills left, only generated files would be removed
+        let result = syncer.sync_target(&target, &[]);
+        assert!(result.removed.is_empty());
+        assert!(agents.exists());
+        assert!(note.exists());
+    }
+
+    #[test]
+    fn unlink_skill_removes_generated_file() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let mut target = create_test_target(temp.path());
+        target.format = TargetFormat::GeminiCommand;
+
+        let syncer = Syncer::new();
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+        let command = target.skills_path.join("my-skill.toml");
+        assert!(command.is_file());
+
+        assert!(syncer.unlink_skill(&target, "my-skill").unwrap());
+        assert!(!command.exists());
+
+        syncer.link_skill(&target, "my-skill", &skill.path).unwrap();
+        assert!(command.is_file());
+    }
+
+    #[test]
+    fn link_skill_respects_the_target_filter() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let kept = create_test_skill(&skills_dir, "kept");
+        let excluded = create_test_skill(&skills_dir, "excluded");
+        let mut target = create_test_target(temp.path());
+        target.filter.exclude = vec!["excl*".to_string()];
+        std::fs::create_dir_all(&target.skills_path).unwrap();
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.link_skill(&target, "kept", &kept.path).unwrap();
+        syncer
+            .link_skill(&target, "excluded", &excluded.path)
+            .unwrap();
+
+        assert!(target.skill_link_path("kept").exists());
+        assert!(target
+            .skill_link_path("excluded")
+            .symlink_metadata()
+            .is_err());
+    }
+
+    #[test]
+    fn section_strategy_keeps_text_outside_the_block() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
 
+        let mut skill = create_test_skill(&skills_dir, "my-skill");
+        let agents = temp.path().join("AGENTS.md");
+        std::fs::write(&agents, "# My instructions\n").unwrap();
+        let mut target = Target::new_folder(agents.clone(), "agents".into(), "Agents".into());
+        target.strategy = SyncStrategy::Section;
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
         assert!(result.is_success());
-        assert!(result.created.is_empty());
-        assert!(!target.skill_link_path("my-skill").exists());
+        assert_eq!(result.created, vec!["AGENTS.md"]);
+        let contents = fs::read_to_string(&agents).unwrap();
+        assert!(contents.starts_with("# My instructions\n"));
+        assert!(contents.contains("## my-skill"));
+        assert!(contents.contains(&skill.path.join(SKILL_FILE_NAME).display().to_string()));
+
+        // Unchanged skills leave the file alone
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert_eq!(result.unchanged, vec!["AGENTS.md"]);
+        assert!(!result.has_changes());
+
+        // Edits outside the block survive a rewrite of the block
+        let edited = fs::read_to_string(&agents).unwrap() + "\nFooter\n";
+        std::fs::write(&agents, edited).unwrap();
+        skill.meta.description = "Changed".to_string();
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert_eq!(result.updated, vec!["AGENTS.md"]);
+        let contents = fs::read_to_string(&agents).unwrap();
+        assert!(contents.contains("Changed"));
+        assert!(contents.ends_with("\nFooter\n"));
+
+        // With no skills left the block goes, the user's text stays
+        let result = syncer.sync_target(&target, &[]);
+        assert_eq!(result.removed, vec!["AGENTS.md"]);
+        assert_eq!(
+            fs::read_to_string(&agents).unwrap(),
+            "# My instructions\n\nFooter\n"
+        );
     }
 
     #[test]
-    fn sync_all_targets() {
+    fn section_strategy_keeps_an_empty_file_the_user_made() {
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
         let skill = create_test_skill(&skills_dir, "my-skill");
+        let claude = temp.path().join("CLAUDE.md");
+        std::fs::write(&claude, "").unwrap();
+        let mut target = Target::new_folder(claude.clone(), "claude-md".into(), "CLAUDE.md".into());
+        target.strategy = SyncStrategy::Section;
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert!(fs::read_to_string(&claude).unwrap().contains("## my-skill"));
+
+        let result = syncer.sync_target(&target, &[]);
+        assert_eq!(result.removed, vec!["CLAUDE.md"]);
+        assert_eq!(fs::read_to_string(&claude).unwrap(), "");
+
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert_eq!(
+            syncer.remove_all_symlinks(&target).unwrap(),
+            vec!["CLAUDE.md"]
+        );
+        assert_eq!(fs::read_to_string(&claude).unwrap(), "");
+        assert!(!Manifest::path_in(temp.path()).exists());
+    }
 
-        let target1 = Target::new(TargetKind::ClaudeCode, temp.path().join("target1"));
-        let target2 = Target::new(TargetKind::Codex, temp.path().join("target2"));
+    #[test]
+    fn section_strategy_inlines_and_creates_file() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let claude = temp.path().join("repo").join("CLAUDE.md");
+        let mut target = Target::new_folder(claude.clone(), "claude-md".into(), "CLAUDE.md".into());
+        target.strategy = SyncStrategy::Section;
+        target.section_style = crate::render::SectionStyle::Inline;
 
         let syncer = Syncer::new();
-        let results = syncer.sync_all(&[target1, target2], &[skill]);
+        let plan = syncer.plan(&target, std::slice::from_ref(&skill));
+        assert!(plan.create_dir);
+        assert!(!claude.exists());
 
-        assert_eq!(results.len(), 2);
-        assert!(results.iter().all(|r| r.is_success()));
-        assert!(results.iter().all(|r| r.created.len() == 1));
+        let result = syncer.apply_plan(&target, &plan);
+        assert!(result.is_success());
+        assert!(fs::read_to_string(&claude)
+            .unwrap()
+            .contains(skill.content.trim()));
+
+        let repo = temp.path().join("repo");
+        assert!(Manifest::load(&repo).unwrap().owns("CLAUDE.md"));
+
+        // Disabling the target removes the file it created
+        assert_eq!(
+            syncer.remove_all_symlinks(&target).unwrap(),
+            vec!["CLAUDE.md"]
+        );
+        assert!(!claude.exists());
+        assert!(!Manifest::path_in(&repo).exists());
     }
 
     #[test]
-    fn remove_all_symlinks() {
+    fn failed_apply_rolls_back_the_target() {
         let temp = TempDir::new().unwrap();
         let skills_dir = temp.path().join("skills");
         std::fs::create_dir_all(&skills_dir).unwrap();
 
-        let skill1 = create_test_skill(&skills_dir, "skill-one");
-        let skill2 = create_test_skill(&skills_dir, "skill-two");
+        let a = create_test_skill(&skills_dir, "a");
+        let b = create_test_skill(&skills_dir, "b");
+        let c = create_test_skill(&skills_dir, "c");
+        let mut target = create_test_target(temp.path());
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        let result = syncer.sync_target(&target, &[a.clone(), b.clone(), c]);
+        assert_eq!(result.created.len(), 3);
+        let manifest_before = fs::read_to_string(Manifest::path_in(&target.skills_path)).unwrap();
+
+        // Switch to copies and drop `c`, then lose `b`'s source before applying
+        target.strategy = SyncStrategy::Copy;
+        let plan = syncer.plan(&target, &[a.clone(), b.clone()]);
+        assert_eq!(plan.broken.len(), 2);
+        assert_eq!(plan.remove, vec!["c"]);
+        fs::remove_dir_all(&b.path).unwrap();
+
+        let result = syncer.apply_plan(&target, &plan);
+        assert!(!result.is_success());
+        assert!(result.rolled_back);
+        assert!(result.created.is_empty() && result.removed.is_empty());
+
+        // Everything is back as it was
+        for name in ["a", "b", "c"] {
+            let link = target.skill_link_path(name);
+            assert_eq!(fs::read_link(&link).unwrap(), skills_dir.join(name));
+        }
+        assert_eq!(
+            fs::read_to_string(Manifest::path_in(&target.skills_path)).unwrap(),
+            manifest_before
+        );
+        assert!(!Journal::dir_in(&target.skills_path).exists());
+    }
+
+    #[test]
+    fn interrupted_sync_is_recovered_on_next_run() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
         let target = create_test_target(temp.path());
 
         let syncer = Syncer::new();
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+        let link = target.skill_link_path("my-skill");
 
-        // Sync skills first
-        syncer.sync_target(&target, &[skill1, skill2]);
-        assert!(target.skill_link_path("skill-one").exists());
-        assert!(target.skill_link_path("skill-two").exists());
+        // A run that journaled a change and died halfway through it
+        let mut journal = Journal::begin(&target.skills_path);
+        journal.record(&syncer, &link).unwrap();
+        fs::remove_file(&link).unwrap();
+        fs::create_dir_all(&link).unwrap();
+        drop(journal);
 
-        // Remove all
-        let removed = syncer.remove_all_symlinks(&target).unwrap();
-        assert_eq!(removed.len(), 2);
-        assert!(!target.skill_link_path("skill-one").exists());
-        assert!(!target.skill_link_path("skill-two").exists());
+        let result = syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert!(result.is_success());
+        assert!(result.recovered);
+        assert!(!result.rolled_back);
+        assert_eq!(result.unchanged, vec!["my-skill"]);
+        assert_eq!(fs::read_link(&link).unwrap(), skill.path);
+        assert!(!Journal::dir_in(&target.skills_path).exists());
     }
 
     #[test]
-    fn synced_count_calculation() {
-        let target = Target::new(TargetKind::ClaudeCode, PathBuf::from("/test"));
-        let mut result = SyncResult::new(&target);
+    #[cfg(unix)]
+    fn repair_relinks_renamed_skills_and_removes_dangling_links() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
 
-        result.created.push("skill-1".to_string());
-        result.created.push("skill-2".to_string());
-        result.unchanged.push("skill-3".to_string());
+        let old = create_test_skill(&skills_dir, "old-name");
+        let moved = create_test_skill(&skills_dir, "moved");
+        let gone = create_test_skill(&skills_dir, "gone");
+        let target = create_test_target(temp.path());
 
-        assert_eq!(result.synced_count(), 3);
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, &[old, moved, gone.clone()]);
+
+        // A user link, plus renames and a deletion that left links dangling
+        let mine = target.skill_link_path("mine");
+        std::os::unix::fs::symlink(temp.path().join("elsewhere"), &mine).unwrap();
+        fs::rename(skills_dir.join("old-name"), skills_dir.join("new-name")).unwrap();
+        let mut renames = RenameLog::default();
+        renames.record("old-name", "new-name");
+        renames.save(&skills_dir).unwrap();
+        let mut renamed = Skill::load(&skills_dir.join("new-name")).unwrap();
+        renamed.meta.name = "new-name".to_string();
+        fs::rename(skills_dir.join("moved"), skills_dir.join("moved-folder")).unwrap();
+        let moved = Skill::load(&skills_dir.join("moved-folder")).unwrap();
+        fs::remove_dir_all(&gone.path).unwrap();
+
+        let result = syncer.repair(&target, &[renamed.clone(), moved.clone()]);
+        assert!(result.is_success());
+        assert_eq!(result.relinked["old-name"], "new-name");
+        assert_eq!(result.relinked["moved"], "moved");
+        assert_eq!(result.removed, vec!["gone"]);
+        assert_eq!(result.unmanaged, vec!["mine"]);
+
+        assert_eq!(
+            fs::read_link(target.skill_link_path("new-name")).unwrap(),
+            renamed.path
+        );
+        assert!(target
+            .skill_link_path("old-name")
+            .symlink_metadata()
+            .is_err());
+        assert_eq!(
+            fs::read_link(target.skill_link_path("moved")).unwrap(),
+            moved.path
+        );
+        assert!(mine.symlink_metadata().is_ok());
+
+        let manifest = Manifest::load(&target.skills_path).unwrap();
+        assert!(manifest.owns("new-name") && !manifest.owns("old-name") && !manifest.owns("gone"));
+
+        // Nothing left to repair
+        let result = syncer.repair(&target, &[renamed, moved]);
+        assert!(!result.has_changes());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn status_sorts_entries_into_categories() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let linked = create_test_skill(&skills_dir, "linked");
+        let missing = create_test_skill(&skills_dir, "missing");
+        let shadowed = create_test_skill(&skills_dir, "shadowed");
+        let elsewhere = create_test_skill(&skills_dir, "elsewhere");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, std::slice::from_ref(&linked));
+
+        // A native folder, a link out of the skills dir, a dangling link and a stray file
+        std::fs::create_dir_all(target.skill_link_path("shadowed")).unwrap();
+        let outside = temp.path().join("outside");
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, target.skill_link_path("elsewhere")).unwrap();
+        std::os::unix::fs::symlink(skills_dir.join("gone"), target.skill_link_path("gone"))
+            .unwrap();
+        std::fs::write(target.skill_link_path("notes.txt"), "mine").unwrap();
+
+        let skills = [linked, missing, shadowed, elsewhere];
+        let status = syncer.status(&target, &skills);
+        assert!(!status.is_synced);
+        assert_eq!(status.missing_skills, vec!["missing"]);
+        assert!(status.extra_items.is_empty());
+        assert_eq!(status.shadowed, vec!["shadowed"]);
+        assert_eq!(status.unmanaged, vec!["notes.txt"]);
+        assert_eq!(status.points_elsewhere, vec!["elsewhere"]);
+        assert_eq!(status.broken_links, vec!["gone"]);
+        assert!(status.outdated.is_empty());
+
+        // Nothing was changed
+        assert!(target
+            .skill_link_path("missing")
+            .symlink_metadata()
+            .is_err());
+        assert_eq!(status.issues_summary().len(), 4);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn status_reports_native_entries_without_going_out_of_sync() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+        let skill = create_test_skill(&skills_dir, "managed");
+        let target = create_test_target(temp.path());
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+
+        // A skill the tool installed itself and a link the user made
+        std::fs::create_dir_all(target.skill_link_path("my-native")).unwrap();
+        let outside = temp.path().join("outside");
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, target.skill_link_path("user-link")).unwrap();
+
+        let status = syncer.status(&target, std::slice::from_ref(&skill));
+        assert!(status.is_synced, "{status:?}");
+        assert_eq!(status.unmanaged, vec!["my-native", "user-link"]);
+        assert!(status.issues_summary().is_empty());
+
+        // A broken link the user made is still theirs
+        std::os::unix::fs::symlink("/nonexistent/x", target.skill_link_path("user-broken"))
+            .unwrap();
+        let status = syncer.status(&target, std::slice::from_ref(&skill));
+        assert!(status.is_synced, "{status:?}");
+        assert!(status.broken_links.is_empty());
+        assert_eq!(
+            status.unmanaged,
+            vec!["my-native", "user-broken", "user-link"]
+        );
+
+        // A managed link for a skill that is gone still needs a sync
+        std::os::unix::fs::symlink(&skill.path, target.skill_link_path("renamed")).unwrap();
+        let status = syncer.status(&target, std::slice::from_ref(&skill));
+        assert!(!status.is_synced);
+        assert_eq!(status.extra_items, vec!["renamed"]);
+    }
+
+    #[test]
+    fn status_flags_outdated_copies() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let mut target = create_test_target(temp.path());
+        target.strategy = SyncStrategy::Copy;
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+        assert!(
+            syncer
+                .status(&target, std::slice::from_ref(&skill))
+                .is_synced
+        );
+
+        std::fs::write(skill.path.join("notes.md"), "new").unwrap();
+        let status = syncer.status(&target, std::slice::from_ref(&skill));
+        assert_eq!(status.outdated, vec!["my-skill"]);
+        assert!(status.locally_modified.is_empty());
+    }
+
+    #[test]
+    fn status_reports_edited_copies_without_going_out_of_sync() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+
+        let skill = create_test_skill(&skills_dir, "my-skill");
+        let mut target = create_test_target(temp.path());
+        target.strategy = SyncStrategy::Copy;
+
+        let syncer = Syncer::with_skills_dir(skills_dir.clone());
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+
+        // Sync keeps the edit, so status must not ask for another sync
+        std::fs::write(target.skill_link_path("my-skill").join("notes.md"), "mine").unwrap();
+        std::fs::write(skill.path.join("notes.md"), "new").unwrap();
+        syncer.sync_target(&target, std::slice::from_ref(&skill));
+
+        let status = syncer.status(&target, std::slice::from_ref(&skill));
+        assert!(status.is_synced, "{status:?}");
+        assert_eq!(status.locally_modified, vec!["my-skill"]);
+        assert!(status.outdated.is_empty());
+    }
+
+    #[test]
+    fn adopt_replaces_identical_native_folders_and_reports_conflicts() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        fs::create_dir_all(&skills_dir).unwrap();
+        let same = create_test_skill(&skills_dir, "same");
+        let changed = create_test_skill(&skills_dir, "changed");
+        let skills = [same.clone(), changed.clone()];
+        let target = create_test_target(temp.path());
+
+        // Native copies made by hand; one of them edited since
+        fs::create_dir_all(&target.skills_path).unwrap();
+        copy_tree(&same.path, &target.skill_link_path("same")).unwrap();
+        copy_tree(&changed.path, &target.skill_link_path("changed")).unwrap();
+        let native_file = target.skill_link_path("changed").join(SKILL_FILE_NAME);
+        let edited = fs::read_to_string(&native_file).unwrap() + "\nEdited in the tool.\n";
+        fs::write(&native_file, &edited).unwrap();
+
+        let syncer = Syncer::new();
+        let candidates = syncer.adoptable(&target, &skills);
+        assert_eq!(candidates.len(), 2);
+        let conflict = |name: &str| {
+            candidates
+                .iter()
+                .find(|c| c.skill == name)
+                .unwrap()
+                .conflict
+                .is_some()
+        };
+        assert!(!conflict("same"));
+        assert!(conflict("changed"));
+
+        let select = |skill: &str, resolution| AdoptSelection {
+            target_id: target.id().to_string(),
+            skill: skill.to_string(),
+            resolution,
+        };
+        let result = syncer.adopt(
+            &target,
+            &skills,
+            &[
+                select("same", ConflictResolution::Import),
+                select("changed", ConflictResolution::Import),
+            ],
+        );
+        assert_eq!(result.adopted, vec!["same"]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            fs::read_link(target.skill_link_path("same")).unwrap(),
+            same.path
+        );
+        assert!(result.backups["same"].join(SKILL_FILE_NAME).exists());
+        // The conflicting folder is left alone
+        assert_eq!(fs::read_to_string(&native_file).unwrap(), edited);
+
+        // Overwrite takes the native version into central storage
+        let result = syncer.adopt(
+            &target,
+            &skills,
+            &[select("changed", ConflictResolution::Overwrite)],
+        );
+        assert!(result.is_success());
+        assert_eq!(result.overwritten, vec!["changed"]);
+        assert_eq!(
+            fs::read_to_string(changed.path.join(SKILL_FILE_NAME)).unwrap(),
+            edited
+        );
+        assert!(target
+            .skills_path
+            .join(ADOPT_BACKUP_DIR_NAME)
+            .join("changed.central")
+            .exists());
+
+        let sync = syncer.sync_target(&target, &skills);
+        assert!(sync.skipped_native.is_empty());
+        assert_eq!(sync.unchanged.len(), 2);
+        assert!(syncer.status(&target, &skills).is_synced);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_links_survive_moving_the_home_directory() {
+        let temp = TempDir::new().unwrap();
+        let setup = |home: &Path| {
+            let skills_dir = home.join(".agents").join("skills");
+            let skill = Skill::load(&skills_dir.join("portable")).ok();
+            let target = Target::new(TargetKind::ClaudeCode, home.join(".claude").join("skills"));
+            let mut syncer = Syncer::with_skills_dir(skills_dir);
+            syncer.link_style = LinkStyle::Relative;
+            (skill, target, syncer)
+        };
+
+        let home = temp.path().join("home");
+        fs::create_dir_all(home.join(".agents").join("skills")).unwrap();
+        create_test_skill(&home.join(".agents").join("skills"), "portable");
+        let (skill, target, syncer) = setup(&home);
+        let skills = [skill.unwrap()];
+        assert_eq!(
+            syncer.sync_target(&target, &skills).created,
+            vec!["portable"]
+        );
+        let link = target.skill_link_path("portable");
+        assert_eq!(
+            fs::read_link(&link).unwrap(),
+            Path::new("../../.agents/skills/portable")
+        );
+
+        // Restored somewhere else, the link still works and nothing needs redoing
+        let moved = temp.path().join("restored");
+        fs::rename(&home, &moved).unwrap();
+        let (skill, target, syncer) = setup(&moved);
+        let skills = [skill.unwrap()];
+        assert!(syncer.status(&target, &skills).is_synced);
+        let result = syncer.sync_target(&target, &skills);
+        assert!(result.created.is_empty());
+        assert_eq!(result.unchanged, vec!["portable"]);
+
+        // An absolute link to the same skill counts as unchanged too
+        let link = target.skill_link_path("portable");
+        fs::remove_file(&link).unwrap();
+        std::os::unix::fs::symlink(&skills[0].path, &link).unwrap();
+        assert_eq!(
+            syncer.sync_target(&target, &skills).unchanged,
+            vec!["portable"]
+        );
+        assert_eq!(fs::read_link(&link).unwrap(), skills[0].path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_path_walks_up_to_the_common_ancestor() {
+        assert_eq!(
+            relative_path(
+                Path::new("/home/u/.claude/skills"),
+                Path::new("/home/u/.agents/skills/a")
+            ),
+            Some(PathBuf::from("../../.agents/skills/a"))
+        );
+        assert_eq!(
+            relative_path(Path::new("/a/b"), Path::new("/a/b/c")),
+            Some(PathBuf::from("c"))
+        );
+        assert_eq!(relative_path(Path::new("relative"), Path::new("/a")), None);
+        assert_eq!(
+            normalize_lexically(Path::new("/a/b/../c/./d")),
+            PathBuf::from("/a/c/d")
+        );
     }
 }
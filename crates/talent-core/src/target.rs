@@ -2,10 +2,20 @@
 //!
 //! Targets are AI CLI tools that support skills/commands.
 //! This module handles detecting installed CLIs and resolving their skills directories.
+//! Where each tool keeps its files is described by its [`KindDefinition`].
 
 use crate::error::{Error, Result};
+use crate::filter::SkillFilter;
+use crate::kinds::{builtin_kinds, find_executable, merged_kinds, KindDefinition};
+use crate::project::Scope;
+use crate::render::{AgentsMdRenderer, CursorRuleRenderer, GeminiCommandRenderer, SectionStyle};
+use crate::skill::Skill;
+use crate::syncer::SyncStrategy;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 
 /// Known target CLI tools
 /// See https://agentskills.io for the full list of compatible agents
@@ -59,20 +69,7 @@ impl TargetKind {
 
     /// Get the display name for this target
     pub fn display_name(&self) -> &'static str {
-        match self {
-            TargetKind::ClaudeCode => "Claude Code",
-            TargetKind::Codex => "Codex",
-            TargetKind::Gemini => "Gemini",
-            TargetKind::Cursor => "Cursor",
-            TargetKind::Amp => "Amp",
-            TargetKind::Goose => "Goose",
-            TargetKind::RooCode => "Roo Code",
-            TargetKind::OpenCode => "OpenCode",
-            TargetKind::Vibe => "Vibe",
-            TargetKind::Firebender => "Firebender",
-            TargetKind::Mux => "Mux",
-            TargetKind::Autohand => "Autohand",
-        }
+        self.definition().name()
     }
 
     /// Get the identifier string for this target
@@ -93,62 +90,114 @@ impl TargetKind {
         }
     }
 
+    /// Look up a known target kind by its identifier
+    pub fn from_id(id: &str) -> Option<TargetKind> {
+        TargetKind::all().iter().find(|k| k.id() == id).copied()
+    }
+
+    /// Get the built-in definition for this kind (from `builtin_kinds.toml`)
+    pub fn definition(&self) -> &'static KindDefinition {
+        &builtin_kinds()[self.id()]
+    }
+
     /// Get the config directory path for this target
-    ///
-    /// On Windows, some tools use %APPDATA% or %LOCALAPPDATA% instead of home directory.
-    /// On Unix, all tools use ~/.toolname convention.
-    fn config_dir(&self) -> Option<PathBuf> {
-        #[cfg(windows)]
-        {
-            match self {
-                // Tools that use %LOCALAPPDATA% on Windows
-                TargetKind::Codex => dirs::data_local_dir().map(|p| p.join("Codex")),
-
-                // Tools that use %APPDATA% on Windows
-                TargetKind::Cursor => dirs::config_dir().map(|p| p.join("Cursor")),
-
-                // Tools that use home directory on Windows (Unix-style)
-                TargetKind::ClaudeCode => dirs::home_dir().map(|p| p.join(".claude")),
-                TargetKind::Gemini => dirs::home_dir().map(|p| p.join(".gemini")),
-                TargetKind::Amp => dirs::home_dir().map(|p| p.join(".amp")),
-                TargetKind::Goose => dirs::home_dir().map(|p| p.join(".goose")),
-                TargetKind::RooCode => dirs::home_dir().map(|p| p.join(".roo-code")),
-                TargetKind::OpenCode => dirs::home_dir().map(|p| p.join(".opencode")),
-                TargetKind::Vibe => dirs::home_dir().map(|p| p.join(".vibe")),
-                TargetKind::Firebender => dirs::home_dir().map(|p| p.join(".firebender")),
-                TargetKind::Mux => dirs::home_dir().map(|p| p.join(".mux")),
-                TargetKind::Autohand => dirs::home_dir().map(|p| p.join(".autohand")),
-            }
+    pub fn config_dir(&self) -> Option<PathBuf> {
+        self.definition().config_dir()
+    }
+
+    /// Get the tool's directory name inside a project (e.g. `.claude`)
+    pub fn project_dir_name(&self) -> &'static str {
+        self.definition().project_dir.as_deref().unwrap_or_default()
+    }
+
+    /// Get the skills subdirectory name within the config dir
+    pub fn skills_subdir(&self) -> &'static str {
+        self.definition().skills_subdir()
+    }
+}
+
+/// The file layout a target's tool reads skills in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TargetFormat {
+    /// `SKILL.md` folders, placed using the target's sync strategy
+    #[default]
+    SkillFolder,
+    /// Cursor rules: one `<name>.mdc` file per skill
+    CursorRule,
+    /// Gemini CLI custom commands: one `<name>.toml` file per skill
+    GeminiCommand,
+    /// A single `AGENTS.md` listing every skill
+    AgentsMd,
+}
+
+impl TargetFormat {
+    /// Get all formats
+    pub fn all() -> &'static [TargetFormat] {
+        &[
+            TargetFormat::SkillFolder,
+            TargetFormat::CursorRule,
+            TargetFormat::GeminiCommand,
+            TargetFormat::AgentsMd,
+        ]
+    }
+
+    /// Get the identifier string for this format
+    pub fn id(&self) -> &'static str {
+        match self {
+            TargetFormat::SkillFolder => "skill-folder",
+            TargetFormat::CursorRule => "cursor-rule",
+            TargetFormat::GeminiCommand => "gemini-command",
+            TargetFormat::AgentsMd => "agents-md",
         }
+    }
 
-        #[cfg(not(windows))]
-        {
-            // On Unix, all tools use ~/.toolname convention
-            let dir_name = match self {
-                TargetKind::ClaudeCode => ".claude",
-                TargetKind::Codex => ".codex",
-                TargetKind::Gemini => ".gemini",
-                TargetKind::Cursor => ".cursor",
-                TargetKind::Amp => ".amp",
-                TargetKind::Goose => ".goose",
-                TargetKind::RooCode => ".roo-code",
-                TargetKind::OpenCode => ".opencode",
-                TargetKind::Vibe => ".vibe",
-                TargetKind::Firebender => ".firebender",
-                TargetKind::Mux => ".mux",
-                TargetKind::Autohand => ".autohand",
-            };
-            dirs::home_dir().map(|p| p.join(dir_name))
+    /// Whether skills are placed as plain folders (nothing is rendered)
+    pub fn is_skill_folder(&self) -> bool {
+        matches!(self, TargetFormat::SkillFolder)
+    }
+
+    /// Get the renderer that generates this format's files
+    ///
+    /// Skill folders have none: they are linked or copied as they are.
+    pub fn renderer(&self) -> Option<&'static dyn FormatRenderer> {
+        match self {
+            TargetFormat::SkillFolder => None,
+            TargetFormat::CursorRule => Some(&CursorRuleRenderer),
+            TargetFormat::GeminiCommand => Some(&GeminiCommandRenderer),
+            TargetFormat::AgentsMd => Some(&AgentsMdRenderer),
         }
     }
+}
 
-    /// Get the skills subdirectory name within the config dir
-    fn skills_subdir(&self) -> &'static str {
-        // All targets use the standard "skills" directory
-        "skills"
+/// Turns skills into the files a tool reads
+///
+/// Generated files must carry the ownership marker (see
+/// [`render::is_generated`](crate::render::is_generated)); the syncer only
+/// overwrites or removes files that have it.
+pub trait FormatRenderer: Sync {
+    /// Render the selected skills into files inside the target directory
+    fn render(&self, skills: &[&Skill]) -> Vec<RenderedFile>;
+
+    /// Whether all skills share one output file (so it can't be updated one skill at a time)
+    fn aggregates(&self) -> bool {
+        false
     }
 }
 
+/// A file generated for a target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedFile {
+    /// File name inside the target directory
+    pub name: String,
+
+    /// Where it was generated from (the skill directory, or the skills directory for aggregated files)
+    pub source: PathBuf,
+
+    /// File contents, including the ownership marker
+    pub contents: String,
+}
+
 /// A detected or configured target CLI
 #[derive(Debug, Clone)]
 pub struct Target {
@@ -169,6 +218,110 @@ pub struct Target {
 
     /// Custom display name for folder targets (used when kind is None)
     pub custom_name: Option<String>,
+
+    /// How skills are placed into this target
+    pub strategy: SyncStrategy,
+
+    /// Which file layout the tool reads
+    pub format: TargetFormat,
+
+    /// How skills are written into the managed block of a section target
+    pub section_style: SectionStyle,
+
+    /// Which skills this target receives
+    pub filter: SkillFilter,
+
+    /// Whether this is a user-level or project-level target
+    pub scope: Scope,
+
+    /// The tool's executable (None for folders and tools without a CLI)
+    pub binary: Option<TargetBinary>,
+}
+
+/// The executable behind a target and the version it reports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetBinary {
+    /// Executable name looked up on `PATH`
+    pub name: String,
+
+    /// Where the executable was found (None if it isn't on `PATH`)
+    pub path: Option<PathBuf>,
+
+    /// Version reported by the probe (None until probed, or if the probe failed)
+    pub version: Option<String>,
+
+    /// Arguments that make the executable print its version
+    #[serde(skip)]
+    version_args: Vec<String>,
+}
+
+impl TargetBinary {
+    /// Look up the executable of a kind on `PATH`
+    pub fn locate(definition: &KindDefinition) -> Option<Self> {
+        let name = definition.binary.clone()?;
+        Some(Self {
+            path: find_executable(&name),
+            name,
+            version: None,
+            version_args: definition.version_args(),
+        })
+    }
+
+    /// Check if the executable was found
+    pub fn is_found(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Run the version probe (e.g. `claude --version`) and record the result
+    ///
+    /// The first non-empty line of output is kept. Executables that don't
+    /// answer within [`VERSION_PROBE_TIMEOUT`] are killed.
+    pub fn probe_version(&mut self) -> Option<&str> {
+        let path = self.path.as_ref()?;
+        self.version = run_version_probe(path, &self.version_args);
+        self.version.as_deref()
+    }
+}
+
+/// How long a version probe may take before it's abandoned
+pub const VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Run `path args...` and return the first non-empty line it prints
+fn run_version_probe(path: &Path, args: &[String]) -> Option<String> {
+    let mut child = Command::new(path)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+
+    let deadline = Instant::now() + VERSION_PROBE_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(20)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    }
+
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Some tools print their version to stderr
+    [output.stdout, output.stderr].iter().find_map(|stream| {
+        String::from_utf8_lossy(stream)
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string)
+    })
 }
 
 impl Target {
@@ -181,6 +334,12 @@ impl Target {
             enabled: true,
             custom_id: None,
             custom_name: None,
+            strategy: SyncStrategy::default(),
+            format: TargetFormat::default(),
+            section_style: SectionStyle::default(),
+            filter: SkillFilter::default(),
+            scope: Scope::Global,
+            binary: TargetBinary::locate(kind.definition()),
         }
     }
 
@@ -193,33 +352,70 @@ impl Target {
             enabled: true,
             custom_id: Some(id),
             custom_name: Some(name),
+            strategy: SyncStrategy::default(),
+            format: TargetFormat::default(),
+            section_style: SectionStyle::default(),
+            filter: SkillFilter::default(),
+            scope: Scope::Global,
+            binary: None,
+        }
+    }
+
+    /// Create a target for a kind definition with a specific skills path
+    ///
+    /// Built-in kinds keep their `TargetKind`; declared kinds become folder
+    /// targets identified by the kind id.
+    pub fn from_definition(definition: &KindDefinition, skills_path: PathBuf) -> Self {
+        match definition.builtin_kind() {
+            // A declared override may rename a built-in kind
+            Some(kind) => Self {
+                custom_name: (definition.name() != kind.display_name())
+                    .then(|| definition.name().to_string()),
+                binary: TargetBinary::locate(definition),
+                ..Self::new(kind, skills_path)
+            },
+            None => Self {
+                binary: TargetBinary::locate(definition),
+                ..Self::new_folder(
+                    skills_path,
+                    definition.id.clone(),
+                    definition.name().to_string(),
+                )
+            },
         }
     }
 
-    /// Try to auto-detect a target by checking for its config directory
+    /// Create a project-level target for a known or declared tool
+    pub fn new_project(definition: &KindDefinition, skills_path: PathBuf) -> Self {
+        Self {
+            custom_id: Some(format!("project:{}", definition.id)),
+            custom_name: Some(format!("{} (project)", definition.name())),
+            scope: Scope::Project,
+            ..Self::from_definition(definition, skills_path)
+        }
+    }
+
+    /// Try to auto-detect a built-in target using its detection rules
     pub fn detect(kind: TargetKind) -> Option<Self> {
-        let config_dir = kind.config_dir()?;
-
-        if config_dir.exists() {
-            let skills_path = config_dir.join(kind.skills_subdir());
-            Some(Self {
-                kind: Some(kind),
-                skills_path,
-                auto_detected: true,
-                enabled: true,
-                custom_id: None,
-                custom_name: None,
-            })
-        } else {
-            None
+        Self::detect_definition(kind.definition())
+    }
+
+    /// Try to auto-detect a target from a kind definition
+    pub fn detect_definition(definition: &KindDefinition) -> Option<Self> {
+        if !definition.is_installed() {
+            return None;
         }
+
+        let mut target = Self::from_definition(definition, definition.skills_path()?);
+        target.auto_detected = true;
+        Some(target)
     }
 
-    /// Detect all installed target CLIs
-    pub fn detect_all() -> Vec<Self> {
-        TargetKind::all()
+    /// Detect all installed target CLIs, including kinds declared in config
+    pub fn detect_all(declared: &BTreeMap<String, KindDefinition>) -> Vec<Self> {
+        merged_kinds(declared)
             .iter()
-            .filter_map(|kind| Target::detect(*kind))
+            .filter_map(Target::detect_definition)
             .collect()
     }
 
@@ -250,11 +446,40 @@ impl Target {
         self.skills_path.exists()
     }
 
+    /// Get the tool's config directory (the parent of the skills directory)
+    pub fn config_dir(&self) -> Option<&Path> {
+        self.skills_path.parent()
+    }
+
+    /// Check if the tool's config directory exists
+    pub fn config_dir_exists(&self) -> bool {
+        self.config_dir().is_some_and(Path::exists)
+    }
+
+    /// Check if the target expects an executable that isn't on `PATH`
+    pub fn binary_missing(&self) -> bool {
+        self.binary.as_ref().is_some_and(|b| !b.is_found())
+    }
+
+    /// Run the executable's version probe, if it was found
+    pub fn probe_version(&mut self) -> Option<&str> {
+        self.binary.as_mut()?.probe_version()
+    }
+
     /// Ensure the skills directory exists, creating it if needed
+    ///
+    /// For section targets the path is a file, so its directory is created instead.
     pub fn ensure_skills_dir(&self) -> Result<()> {
-        if !self.skills_path.exists() {
-            std::fs::create_dir_all(&self.skills_path)
-                .map_err(|e| Error::create_dir(&self.skills_path, e))?;
+        let dir = if self.strategy.is_section() {
+            match self.skills_path.parent() {
+                Some(parent) => parent,
+                None => return Ok(()),
+            }
+        } else {
+            &self.skills_path
+        };
+        if !dir.exists() {
+            std::fs::create_dir_all(dir).map_err(|e| Error::create_dir(dir, e))?;
         }
         Ok(())
     }
@@ -267,7 +492,7 @@ impl Target {
     /// Validate that the target is accessible
     pub fn validate(&self) -> Result<()> {
         // Check if the parent config directory exists
-        if let Some(parent) = self.skills_path.parent() {
+        if let Some(parent) = self.config_dir() {
             if !parent.exists() {
                 return Err(Error::TargetNotFound {
                     name: self.name().to_string(),
@@ -288,11 +513,37 @@ pub struct SyncStatus {
     /// Skills missing from the target (exist in central but not in target)
     pub missing_skills: Vec<String>,
 
-    /// Items in target that aren't valid symlinks to central storage
+    /// Entries sync would remove or relink: links to the wrong skill, and
+    /// links or copies AgentLoom made for skills that are gone
     pub extra_items: Vec<String>,
 
+    /// Native folders standing where a managed skill should go
+    /// (`agentloom sync --adopt` replaces them)
+    #[serde(default)]
+    pub shadowed: Vec<String>,
+
+    /// Native folders, files and links AgentLoom doesn't manage
+    ///
+    /// Only reported; they don't make the target out of sync.
+    #[serde(default)]
+    pub unmanaged: Vec<String>,
+
+    /// Copies edited in the target since they were made
+    ///
+    /// Sync won't overwrite them, so they don't make the target out of sync.
+    #[serde(default)]
+    pub locally_modified: Vec<String>,
+
     /// Broken symlinks (point to non-existent paths)
     pub broken_links: Vec<String>,
+
+    /// Links that resolve to something outside the skills directory
+    #[serde(default)]
+    pub points_elsewhere: Vec<String>,
+
+    /// Copies whose source skill changed since they were made
+    #[serde(default)]
+    pub outdated: Vec<String>,
 }
 
 impl SyncStatus {
@@ -308,13 +559,42 @@ impl SyncStatus {
             issues.push(format!("{} missing skill(s)", self.missing_skills.len()));
         }
         if !self.extra_items.is_empty() {
-            issues.push(format!("{} unmanaged item(s)", self.extra_items.len()));
+            issues.push(format!("{} unexpected item(s)", self.extra_items.len()));
+        }
+        if !self.shadowed.is_empty() {
+            issues.push(format!(
+                "{} skill(s) shadowed by native folders",
+                self.shadowed.len()
+            ));
         }
         if !self.broken_links.is_empty() {
             issues.push(format!("{} broken link(s)", self.broken_links.len()));
         }
+        if !self.points_elsewhere.is_empty() {
+            issues.push(format!(
+                "{} link(s) pointing elsewhere",
+                self.points_elsewhere.len()
+            ));
+        }
+        if !self.outdated.is_empty() {
+            issues.push(format!("{} outdated cop(ies)", self.outdated.len()));
+        }
         issues
     }
+
+    /// Work out `is_synced` from the issue lists
+    ///
+    /// Only what sync or repair would change counts; `unmanaged` entries and
+    /// `locally_modified` copies don't.
+    pub fn finish(mut self) -> Self {
+        self.is_synced = self.missing_skills.is_empty()
+            && self.extra_items.is_empty()
+            && self.shadowed.is_empty()
+            && self.broken_links.is_empty()
+            && self.points_elsewhere.is_empty()
+            && self.outdated.is_empty();
+        self
+    }
 }
 
 /// Information about a target for serialization/display
@@ -326,36 +606,21 @@ pub struct TargetInfo {
     pub auto_detected: bool,
     pub enabled: bool,
     pub exists: bool,
+    pub scope: Scope,
+    pub format: TargetFormat,
+    pub config_dir_exists: bool,
+    pub binary: Option<TargetBinary>,
     pub sync_status: Option<SyncStatus>,
 }
 
 impl TargetInfo {
-    /// Create TargetInfo from a Target, optionally checking sync status
-    pub fn from_target(
-        target: &Target,
-        central_skills: Option<&[String]>,
-        skills_dir: Option<&PathBuf>,
-    ) -> Self {
-        let sync_status = if target.enabled && target.skills_dir_exists() {
-            central_skills.map(|skills| {
-                check_sync_status(
-                    target,
-                    skills,
-                    skills_dir.expect("skills_dir required when central_skills provided"),
-                )
-            })
-        } else {
-            None
-        };
-
+    /// Create TargetInfo from a Target along with its sync status
+    ///
+    /// The status comes from [`crate::SkillManager::target_status`].
+    pub fn from_target(target: &Target, sync_status: Option<SyncStatus>) -> Self {
         Self {
-            id: target.id().to_string(),
-            name: target.name().to_string(),
-            skills_path: target.skills_path.display().to_string(),
-            auto_detected: target.auto_detected,
-            enabled: target.enabled,
-            exists: target.skills_dir_exists(),
             sync_status,
+            ..Self::from(target)
         }
     }
 }
@@ -369,100 +634,15 @@ impl From<&Target> for TargetInfo {
             auto_detected: target.auto_detected,
             enabled: target.enabled,
             exists: target.skills_dir_exists(),
+            scope: target.scope,
+            format: target.format,
+            config_dir_exists: target.config_dir_exists(),
+            binary: target.binary.clone(),
             sync_status: None,
         }
     }
 }
 
-/// Check sync status of a target against central skills
-fn check_sync_status(
-    target: &Target,
-    central_skill_names: &[String],
-    skills_dir: &PathBuf,
-) -> SyncStatus {
-    use std::fs;
-
-    let mut status = SyncStatus {
-        is_synced: true,
-        missing_skills: Vec::new(),
-        extra_items: Vec::new(),
-        broken_links: Vec::new(),
-    };
-
-    let target_path = &target.skills_path;
-
-    // Get items in target directory (excluding hidden files/folders like .DS_Store, .system)
-    let target_items: std::collections::HashSet<String> = if target_path.exists() {
-        fs::read_dir(target_path)
-            .ok()
-            .map(|entries| {
-                entries
-                    .filter_map(|e| e.ok())
-                    .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
-                    .filter(|name| !name.starts_with('.'))
-                    .collect()
-            })
-            .unwrap_or_default()
-    } else {
-        std::collections::HashSet::new()
-    };
-
-    // Check each central skill
-    for skill_name in central_skill_names {
-        let link_path = target_path.join(skill_name);
-        let expected_target = skills_dir.join(skill_name);
-
-        if !link_path.exists() {
-            // Skill missing from target
-            status.missing_skills.push(skill_name.clone());
-            status.is_synced = false;
-        } else if link_path.is_symlink() {
-            // Check if symlink points to correct location
-            if let Ok(link_target) = fs::read_link(&link_path) {
-                if link_target != expected_target {
-                    // Symlink points to wrong location
-                    status.extra_items.push(skill_name.clone());
-                    status.is_synced = false;
-                } else if !expected_target.exists() {
-                    // Symlink target doesn't exist (broken link)
-                    status.broken_links.push(skill_name.clone());
-                    status.is_synced = false;
-                }
-            }
-        } else {
-            // Not a symlink - it's a real directory/file (unmanaged)
-            status.extra_items.push(skill_name.clone());
-            status.is_synced = false;
-        }
-    }
-
-    // Check for extra items in target that aren't in central skills
-    let central_set: std::collections::HashSet<&String> = central_skill_names.iter().collect();
-    for item in &target_items {
-        if !central_set.contains(item) {
-            let item_path = target_path.join(item);
-            if item_path.is_symlink() {
-                // Check if it's a broken symlink or points outside central
-                if let Ok(link_target) = fs::read_link(&item_path) {
-                    if !link_target.starts_with(skills_dir) {
-                        status.extra_items.push(item.clone());
-                        status.is_synced = false;
-                    } else if !link_target.exists() {
-                        status.broken_links.push(item.clone());
-                        status.is_synced = false;
-                    }
-                }
-            } else {
-                // Real directory/file not in central skills
-                status.extra_items.push(item.clone());
-                status.is_synced = false;
-            }
-        }
-    }
-
-    status
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,6 +763,54 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn target_reports_missing_binary_and_config_dir() {
+        let temp = TempDir::new().unwrap();
+        let mut target = Target::new(TargetKind::ClaudeCode, temp.path().join("skills"));
+        target.binary = Some(TargetBinary {
+            name: "agentloom-no-such-binary".to_string(),
+            path: None,
+            version: None,
+            version_args: vec!["--version".to_string()],
+        });
+
+        assert!(target.config_dir_exists());
+        assert!(target.binary_missing());
+        assert_eq!(target.probe_version(), None);
+
+        let folder = Target::new_folder(
+            PathBuf::from("/nonexistent/skills"),
+            "folder-x".to_string(),
+            "x".to_string(),
+        );
+        assert!(!folder.config_dir_exists());
+        assert!(!folder.binary_missing());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn version_probe_reads_first_line() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let script = temp.path().join("tool");
+        std::fs::write(
+            &script,
+            "#!/bin/sh\necho\necho \"tool $1 1.2.3\"\necho extra\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut binary = TargetBinary {
+            name: "tool".to_string(),
+            path: Some(script),
+            version: None,
+            version_args: vec!["-V".to_string()],
+        };
+        assert_eq!(binary.probe_version(), Some("tool -V 1.2.3"));
+        assert_eq!(binary.version.as_deref(), Some("tool -V 1.2.3"));
+    }
+
     #[test]
     fn all_targets_use_skills_subdir() {
         // All targets use the standard "skills" directory
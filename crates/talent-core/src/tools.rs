@@ -0,0 +1,208 @@
+//! Parsing of the `allowed-tools` frontmatter field
+//!
+//! The field lists the tools a skill may use without asking, separated by
+//! spaces (commas work too): `Bash(git:*) Bash(jq:*) Read`. Each entry names a
+//! tool and may restrict its arguments with a pattern in parentheses. A
+//! pattern ending in `*` (or `:*`) is a wildcard matching any arguments that
+//! start with the rest; so is a tool name ending in `*` (`mcp__github__*`).
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Tools that can do anything unless an argument pattern restricts them
+pub const UNRESTRICTED_TOOLS: &[&str] = &["Bash"];
+
+/// One entry of `allowed-tools`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowedTool {
+    /// Tool name, e.g. `Bash` or `mcp__github__*`
+    pub tool: String,
+
+    /// Argument pattern without its wildcard suffix, e.g. `git` for `Bash(git:*)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Whether the entry matches a family: arguments starting with the
+    /// pattern, or tools starting with the name
+    pub wildcard: bool,
+}
+
+impl AllowedTool {
+    /// Parse a single entry such as `Bash(git:*)` or `Read`
+    pub fn parse(entry: &str) -> Result<Self> {
+        let invalid = |message: &str| Error::InvalidAllowedTool {
+            entry: entry.to_string(),
+            message: message.to_string(),
+        };
+
+        let (tool, arguments) = match entry.find('(') {
+            Some(open) => {
+                let inner = entry[open + 1..]
+                    .strip_suffix(')')
+                    .ok_or_else(|| invalid("expected ')' at the end"))?;
+                (&entry[..open], Some(inner))
+            }
+            None if entry.contains(')') => return Err(invalid("unexpected ')'")),
+            None => (entry, None),
+        };
+
+        if tool.is_empty() {
+            return Err(invalid("missing tool name"));
+        }
+        let name = tool.strip_suffix('*').unwrap_or(tool);
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        {
+            return Err(invalid(
+                "tool names may only contain letters, digits, '_', '-' and '.'",
+            ));
+        }
+
+        let mut wildcard = name.len() < tool.len();
+        let pattern = match arguments.map(str::trim) {
+            None => None,
+            Some("") => return Err(invalid("empty argument pattern")),
+            Some(arguments) => match arguments.strip_suffix('*') {
+                Some(prefix) => {
+                    wildcard = true;
+                    let prefix = prefix.strip_suffix(':').unwrap_or(prefix).trim_end();
+                    (!prefix.is_empty()).then(|| prefix.to_string())
+                }
+                None => Some(arguments.to_string()),
+            },
+        };
+
+        Ok(Self {
+            tool: tool.to_string(),
+            pattern,
+            wildcard,
+        })
+    }
+
+    /// Check if the entry grants a tool without restricting what it may do
+    ///
+    /// True for unrestricted tools like bare `Bash` (or `Bash(*)`) and for `*`.
+    pub fn is_broad(&self) -> bool {
+        self.tool == "*"
+            || (self.pattern.is_none() && UNRESTRICTED_TOOLS.contains(&self.tool.as_str()))
+    }
+
+    /// Check if a tool name list (entries may end in `*`) contains this tool
+    pub fn is_known_to(&self, known_tools: &[String]) -> bool {
+        known_tools
+            .iter()
+            .any(|known| match known.strip_suffix('*') {
+                Some(prefix) => self.tool.starts_with(prefix),
+                None => *known == self.tool,
+            })
+    }
+}
+
+impl fmt::Display for AllowedTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.tool)?;
+        let tool_wildcard = self.tool.ends_with('*');
+        match (&self.pattern, self.wildcard && !tool_wildcard) {
+            (Some(pattern), true) => write!(f, "({pattern}:*)"),
+            (Some(pattern), false) => write!(f, "({pattern})"),
+            (None, true) => f.write_str("(*)"),
+            (None, false) => Ok(()),
+        }
+    }
+}
+
+/// Parse an `allowed-tools` value, one result per entry
+pub fn parse_allowed_tools(value: &str) -> Vec<Result<AllowedTool>> {
+    split_entries(value)
+        .into_iter()
+        .map(AllowedTool::parse)
+        .collect()
+}
+
+/// Split on spaces and commas outside parentheses
+fn split_entries(value: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 && (c.is_whitespace() || c == ',') => {
+                if let Some(s) = start.take() {
+                    entries.push(&value[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(s) = start {
+        entries.push(&value[s..]);
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tools_patterns_and_wildcards() {
+        let parsed: Vec<AllowedTool> =
+            parse_allowed_tools("Bash(git:*) Bash(npm run test), Read mcp__github__* Bash(*)")
+                .into_iter()
+                .collect::<Result<_>>()
+                .unwrap();
+
+        let summary: Vec<(&str, Option<&str>, bool)> = parsed
+            .iter()
+            .map(|t| (t.tool.as_str(), t.pattern.as_deref(), t.wildcard))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Bash", Some("git"), true),
+                ("Bash", Some("npm run test"), false),
+                ("Read", None, false),
+                ("mcp__github__*", None, true),
+                ("Bash", None, true),
+            ]
+        );
+        assert_eq!(parsed[0].to_string(), "Bash(git:*)");
+        assert!(!parsed[0].is_broad());
+        assert!(parsed[4].is_broad());
+        assert!(AllowedTool::parse("Bash").unwrap().is_broad());
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        let results = parse_allowed_tools("(git) Bash() Re@d Read) Bash(git:*");
+        let errors: Vec<String> = results
+            .iter()
+            .filter_map(|r| r.as_ref().err().map(ToString::to_string))
+            .collect();
+        assert_eq!(errors.len(), 5, "{errors:?}");
+        assert!(errors[0].contains("missing tool name"));
+        assert!(errors[1].contains("empty argument pattern"));
+        assert!(errors[2].contains("tool names may only contain"));
+        assert!(errors[3].contains("unexpected ')'"));
+        assert!(errors[4].contains("expected ')'"));
+    }
+
+    #[test]
+    fn known_tools_accept_prefixes() {
+        let known = vec!["Read".to_string(), "mcp__*".to_string()];
+        assert!(AllowedTool::parse("Read").unwrap().is_known_to(&known));
+        assert!(AllowedTool::parse("mcp__github__issues")
+            .unwrap()
+            .is_known_to(&known));
+        assert!(!AllowedTool::parse("Bash(git:*)")
+            .unwrap()
+            .is_known_to(&known));
+    }
+}
@@ -5,12 +5,30 @@
 //! - name must match parent directory name
 //! - description: 1-1024 chars
 //! - compatibility: max 500 chars (if provided)
-//! - Content presence (optional)
+//! - Content presence
+//!
+//! Bundled resources are checked too: links from SKILL.md must point at files
+//! inside the skill folder, bundled files should be referenced, and scripts
+//! with a shebang should be executable (see [`crate::resources`]).
+//!
+//! Rules that default to warnings flag skills without blocking them: a short
+//! description or legacy top-level fields make a skill
+//! [`ValidationStatus::ValidWithWarnings`], which still syncs.
+//!
+//! Each check is a rule in [`RULES`] with an id and a default severity, which
+//! config can change per skill (see [`crate::lint`]).
 //!
 //! See https://agentskills.io/specification for full spec.
 
 use crate::error::{Error, Result};
-use crate::skill::{Skill, ValidationStatus};
+use crate::lint::{self, Diagnostic, LintConfig, Location, Severity};
+use crate::resources::{self, Reference};
+use crate::size::{SkillSize, TextSize};
+use crate::skill::{Skill, ValidationStatus, SKILL_FILE_NAME};
+use crate::tools::parse_allowed_tools;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 /// Maximum length for skill name (per agentskills.io spec)
 const MAX_NAME_LENGTH: usize = 64;
@@ -21,112 +39,333 @@ const MAX_DESCRIPTION_LENGTH: usize = 1024;
 /// Maximum length for compatibility field (per agentskills.io spec)
 const MAX_COMPATIBILITY_LENGTH: usize = 500;
 
-/// Validator for skills
-pub struct Validator {
-    /// Whether to require non-empty content
-    pub require_content: bool,
+/// Descriptions shorter than this rarely say when to use the skill
+const MIN_DESCRIPTION_LENGTH: usize = 20;
+
+/// A named validation check
+pub struct Rule {
+    /// Id used in config, directives and diagnostics
+    pub id: &'static str,
+
+    /// Severity unless config says otherwise
+    pub severity: Severity,
+
+    /// What the rule checks
+    pub description: &'static str,
+
+    /// The check itself; `None` for problems found while loading the skill
+    check: Option<Check>,
 }
 
-impl Default for Validator {
-    fn default() -> Self {
+/// A check returns one finding per problem
+type Check = fn(&Validator, &Skill, &SkillFiles) -> Vec<Finding>;
+
+/// SKILL.md and the skill's bundled files, read once per validation
+struct SkillFiles {
+    /// SKILL.md as written, or `None` if it can't be read
+    raw: Option<String>,
+
+    /// Relative file references in SKILL.md
+    references: Vec<Reference>,
+
+    /// Files bundled with the skill (see [`resources::bundled_files`])
+    bundled: Vec<PathBuf>,
+
+    /// Size of each part of the skill
+    size: SkillSize,
+}
+
+impl SkillFiles {
+    fn read(skill: &Skill) -> Self {
+        let raw = skill.raw_content().ok();
+        let references = raw
+            .as_deref()
+            .map(resources::find_references)
+            .unwrap_or_default();
+        let bundled = resources::bundled_files(&skill.path);
+        let size = SkillSize::from_files(skill, raw.as_deref(), &bundled);
         Self {
-            require_content: true,
+            raw,
+            references,
+            bundled,
+            size,
         }
     }
 }
 
-impl Validator {
-    /// Create a new validator with default settings
-    pub fn new() -> Self {
-        Self::default()
+/// A problem found by a check, before the rule's severity is applied
+struct Finding {
+    message: String,
+    location: Location,
+}
+
+impl Finding {
+    /// A problem with a frontmatter field
+    fn field(field: &str, message: String) -> Self {
+        Self {
+            message,
+            location: Location {
+                file: PathBuf::from(SKILL_FILE_NAME),
+                line: None,
+                field: Some(field.to_string()),
+            },
+        }
     }
 
-    /// Validate a skill, updating its validation status
-    pub fn validate(&self, skill: &mut Skill) -> Result<()> {
-        // Preserve any loading errors (these indicate frontmatter/YAML issues)
-        let loading_errors: Vec<String> = skill
-            .validation_errors
-            .iter()
-            .filter(|e| {
-                e.contains("frontmatter")
-                    || e.contains("auto-fixed")
-                    || e.contains("Invalid YAML")
-                    || e.contains("YAML")
-            })
-            .cloned()
-            .collect();
+    /// A problem with SKILL.md as a whole
+    fn file(message: String) -> Self {
+        Self::at(Path::new(SKILL_FILE_NAME), None, message)
+    }
 
-        let mut errors = Vec::new();
+    /// A problem at a line of a file in the skill folder
+    fn at(file: &Path, line: Option<usize>, message: String) -> Self {
+        Self {
+            message,
+            location: Location {
+                file: file.to_path_buf(),
+                line,
+                field: None,
+            },
+        }
+    }
+}
 
-        // Check required fields
-        if skill.meta.name.is_empty() {
-            errors.push("name is required".to_string());
-        } else {
-            // Validate name length (max 64 chars)
-            if skill.meta.name.len() > MAX_NAME_LENGTH {
-                errors.push(format!(
-                    "name exceeds {} characters (has {})",
-                    MAX_NAME_LENGTH,
-                    skill.meta.name.len()
-                ));
-            }
+/// All rules, in the order they are reported
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: lint::UNREADABLE,
+        severity: Severity::Error,
+        description: "SKILL.md can be read",
+        check: None,
+    },
+    Rule {
+        id: lint::FRONTMATTER_SYNTAX,
+        severity: Severity::Error,
+        description: "Frontmatter is valid YAML with the expected field types",
+        check: None,
+    },
+    Rule {
+        id: "name-required",
+        severity: Severity::Error,
+        description: "Frontmatter has a name",
+        check: Some(check_name_required),
+    },
+    Rule {
+        id: "name-length",
+        severity: Severity::Error,
+        description: "Name is at most 64 characters",
+        check: Some(check_name_length),
+    },
+    Rule {
+        id: "name-format",
+        severity: Severity::Error,
+        description: "Name is kebab-case",
+        check: Some(check_name_format),
+    },
+    Rule {
+        id: "name-matches-folder",
+        severity: Severity::Error,
+        description: "Name matches the skill's folder name",
+        check: Some(check_name_matches_folder),
+    },
+    Rule {
+        id: "description-required",
+        severity: Severity::Error,
+        description: "Frontmatter has a description",
+        check: Some(check_description_required),
+    },
+    Rule {
+        id: "description-length",
+        severity: Severity::Error,
+        description: "Description is at most 1024 characters",
+        check: Some(check_description_length),
+    },
+    Rule {
+        id: "compatibility-length",
+        severity: Severity::Error,
+        description: "Compatibility is at most 500 characters",
+        check: Some(check_compatibility_length),
+    },
+    Rule {
+        id: "content-required",
+        severity: Severity::Error,
+        description: "SKILL.md has content after the frontmatter",
+        check: Some(check_content_required),
+    },
+    Rule {
+        id: "description-short",
+        severity: Severity::Warning,
+        description: "Description is at least 20 characters",
+        check: Some(check_description_short),
+    },
+    Rule {
+        id: "legacy-fields",
+        severity: Severity::Warning,
+        description: "Version and author live in metadata, not at the top level",
+        check: Some(check_legacy_fields),
+    },
+    Rule {
+        id: "allowed-tools-syntax",
+        severity: Severity::Error,
+        description: "allowed-tools entries are well-formed, e.g. Bash(git:*) Read",
+        check: Some(check_allowed_tools_syntax),
+    },
+    Rule {
+        id: "allowed-tools-broad",
+        severity: Severity::Warning,
+        description: "allowed-tools doesn't grant unrestricted tools like bare Bash",
+        check: Some(check_allowed_tools_broad),
+    },
+    Rule {
+        id: "allowed-tools-unknown",
+        severity: Severity::Warning,
+        description: "allowed-tools names tools the targets know (targets.<id>.known_tools)",
+        check: Some(check_allowed_tools_unknown),
+    },
+    Rule {
+        id: "resource-missing",
+        severity: Severity::Warning,
+        description: "Files linked from SKILL.md exist",
+        check: Some(check_resource_missing),
+    },
+    Rule {
+        id: "resource-escapes",
+        severity: Severity::Warning,
+        description: "Links from SKILL.md stay inside the skill folder",
+        check: Some(check_resource_escapes),
+    },
+    Rule {
+        id: "resource-unreferenced",
+        severity: Severity::Info,
+        description: "Bundled files are referenced from SKILL.md",
+        check: Some(check_resource_unreferenced),
+    },
+    Rule {
+        id: "script-not-executable",
+        severity: Severity::Warning,
+        description: "Bundled scripts with a shebang are executable",
+        check: Some(check_script_not_executable),
+    },
+    Rule {
+        id: "budget-frontmatter",
+        severity: Severity::Warning,
+        description: "Frontmatter fits lint.budgets.frontmatter_tokens",
+        check: Some(check_budget_frontmatter),
+    },
+    Rule {
+        id: "budget-body",
+        severity: Severity::Warning,
+        description: "Body fits lint.budgets.body_tokens and body_lines",
+        check: Some(check_budget_body),
+    },
+    Rule {
+        id: "budget-references",
+        severity: Severity::Warning,
+        description: "Reference files fit lint.budgets.references_tokens",
+        check: Some(check_budget_references),
+    },
+    Rule {
+        id: "license-missing",
+        severity: Severity::Warning,
+        description: "Frontmatter has a license",
+        check: Some(check_license_missing),
+    },
+    Rule {
+        id: "compatibility-missing",
+        severity: Severity::Warning,
+        description: "Frontmatter has a compatibility note",
+        check: Some(check_compatibility_missing),
+    },
+];
+
+/// Look up a rule by id
+pub fn find_rule(id: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| rule.id == id)
+}
 
-            // Validate name format (kebab-case)
-            if !is_kebab_case(&skill.meta.name) {
-                errors.push(format!(
-                    "name '{}' must be kebab-case (lowercase letters, numbers, hyphens; no leading/trailing/consecutive hyphens)",
-                    skill.meta.name
-                ));
-            }
+/// Validator for skills
+#[derive(Debug, Clone, Default)]
+pub struct Validator {
+    /// Rule levels from config
+    pub lint: LintConfig,
 
-            // Validate name matches parent directory (per agentskills.io spec)
-            let folder_name = skill.folder_name();
-            if folder_name != skill.meta.name {
-                errors.push(format!(
-                    "name '{}' must match parent directory '{}' (per agentskills.io spec)",
-                    skill.meta.name, folder_name
-                ));
-            }
-        }
+    /// Tool names each target knows (key = target id); targets without a
+    /// list aren't checked
+    pub known_tools: BTreeMap<String, Vec<String>>,
+}
 
-        if skill.meta.description.is_empty() {
-            errors.push("description is required".to_string());
-        } else if skill.meta.description.len() > MAX_DESCRIPTION_LENGTH {
-            errors.push(format!(
-                "description exceeds {} characters (has {})",
-                MAX_DESCRIPTION_LENGTH,
-                skill.meta.description.len()
-            ));
-        }
+impl Validator {
+    /// Create a new validator with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        // Validate optional compatibility field (max 500 chars per spec)
-        if let Some(ref compat) = skill.meta.compatibility {
-            if compat.len() > MAX_COMPATIBILITY_LENGTH {
-                errors.push(format!(
-                    "compatibility exceeds {} characters (has {})",
-                    MAX_COMPATIBILITY_LENGTH,
-                    compat.len()
-                ));
-            }
+    /// Create a validator that applies configured rule levels
+    pub fn with_lint(lint: LintConfig) -> Self {
+        Self {
+            lint,
+            ..Default::default()
         }
+    }
 
-        // Check content presence
-        if self.require_content && skill.content.trim().is_empty() {
-            errors.push("skill must have content".to_string());
+    /// Validate a skill, updating its validation status and diagnostics
+    ///
+    /// The skill is invalid if any diagnostic is an error, and valid with
+    /// warnings if any is a warning. Only invalid skills return an error.
+    pub fn validate(&self, skill: &mut Skill) -> Result<()> {
+        let disabled = lint::disabled_rules(&skill.meta.metadata);
+        let files = SkillFiles::read(skill);
+        let mut diagnostics = Vec::new();
+
+        for rule in RULES {
+            let Some(severity) = self.severity(rule, skill, &disabled) else {
+                continue;
+            };
+            match rule.check {
+                // Problems found while loading stay until the skill is reloaded
+                None => {
+                    diagnostics.extend(skill.diagnostics.iter().filter(|d| d.rule == rule.id).map(
+                        |d| Diagnostic {
+                            severity,
+                            ..d.clone()
+                        },
+                    ))
+                }
+                Some(check) => {
+                    diagnostics.extend(check(self, skill, &files).into_iter().map(|finding| {
+                        Diagnostic {
+                            rule: rule.id.to_string(),
+                            severity,
+                            message: finding.message,
+                            location: Some(finding.location),
+                            fix: None,
+                        }
+                    }))
+                }
+            }
         }
 
-        // Combine loading errors with validation errors
-        let all_errors: Vec<String> = loading_errors.into_iter().chain(errors).collect();
+        add_field_lines(files.raw.as_deref(), &mut diagnostics);
+        skill.size = OnceLock::from(files.size);
 
-        // Update skill status
-        if all_errors.is_empty() {
-            skill.validation_status = ValidationStatus::Valid;
-            skill.validation_errors.clear();
+        let message = diagnostics
+            .iter()
+            .filter(|d| d.is_error())
+            .map(|d| d.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ");
+        skill.diagnostics = diagnostics;
+
+        if message.is_empty() {
+            let has_warnings = skill.diagnostics_of(Severity::Warning).next().is_some();
+            skill.validation_status = if has_warnings {
+                ValidationStatus::ValidWithWarnings
+            } else {
+                ValidationStatus::Valid
+            };
             Ok(())
         } else {
             skill.validation_status = ValidationStatus::Invalid;
-            let message = all_errors.join("; ");
-            skill.validation_errors = all_errors;
             Err(Error::ValidationFailed {
                 name: skill.meta.name.clone(),
                 message,
@@ -138,6 +377,393 @@ impl Validator {
     pub fn validate_all(&self, skills: &mut [Skill]) -> Vec<Result<()>> {
         skills.iter_mut().map(|s| self.validate(s)).collect()
     }
+
+    /// Severity a rule reports with for a skill, or `None` if it is switched off
+    fn severity(
+        &self,
+        rule: &Rule,
+        skill: &Skill,
+        disabled: &std::collections::HashSet<&str>,
+    ) -> Option<Severity> {
+        if disabled.contains(rule.id) {
+            return None;
+        }
+        // Per-skill levels are keyed by name, falling back to the folder for unnamed skills
+        let key = if skill.meta.name.is_empty() {
+            skill.folder_name()
+        } else {
+            &skill.meta.name
+        };
+        match self.lint.level(key, rule.id) {
+            Some(level) => level.severity(),
+            None => Some(rule.severity),
+        }
+    }
+}
+
+fn check_name_required(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    if skill.meta.name.is_empty() {
+        vec![Finding::field("name", "name is required".to_string())]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_name_length(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    let length = skill.meta.name.len();
+    if length > MAX_NAME_LENGTH {
+        vec![Finding::field(
+            "name",
+            format!("name exceeds {MAX_NAME_LENGTH} characters (has {length})"),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_name_format(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    let name = &skill.meta.name;
+    if !name.is_empty() && !is_kebab_case(name) {
+        vec![Finding::field(
+            "name",
+            format!(
+                "name '{name}' must be kebab-case (lowercase letters, numbers, hyphens; no leading/trailing/consecutive hyphens)"
+            ),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_name_matches_folder(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    let name = &skill.meta.name;
+    let folder_name = skill.folder_name();
+    if !name.is_empty() && folder_name != name {
+        vec![Finding::field(
+            "name",
+            format!(
+                "name '{name}' must match parent directory '{folder_name}' (per agentskills.io spec)"
+            ),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_description_required(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    if skill.meta.description.is_empty() {
+        vec![Finding::field(
+            "description",
+            "description is required".to_string(),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_description_length(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    let length = skill.meta.description.len();
+    if length > MAX_DESCRIPTION_LENGTH {
+        vec![Finding::field(
+            "description",
+            format!("description exceeds {MAX_DESCRIPTION_LENGTH} characters (has {length})"),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_compatibility_length(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    match &skill.meta.compatibility {
+        Some(compat) if compat.len() > MAX_COMPATIBILITY_LENGTH => vec![Finding::field(
+            "compatibility",
+            format!(
+                "compatibility exceeds {MAX_COMPATIBILITY_LENGTH} characters (has {})",
+                compat.len()
+            ),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+fn check_content_required(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    if skill.content.trim().is_empty() {
+        vec![Finding::file("skill must have content".to_string())]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_description_short(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    let length = skill.meta.description.chars().count();
+    if length > 0 && length < MIN_DESCRIPTION_LENGTH {
+        vec![Finding::field(
+            "description",
+            format!(
+                "description is short ({length} characters); say what the skill does and when to use it"
+            ),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_legacy_fields(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    [
+        ("version", skill.meta.version.is_some()),
+        ("author", skill.meta.author.is_some()),
+    ]
+    .into_iter()
+    .filter(|(_, present)| *present)
+    .map(|(field, _)| {
+        Finding::field(
+            field,
+            format!("top-level '{field}' is not in the spec; move it to metadata.{field}"),
+        )
+    })
+    .collect()
+}
+
+fn check_budget_frontmatter(validator: &Validator, _: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    let budget = validator.lint.budgets.frontmatter_tokens;
+    if budget == 0 {
+        return Vec::new();
+    }
+    over_budget("frontmatter", files.size.frontmatter, budget, 0)
+}
+
+fn check_budget_body(validator: &Validator, _: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    let budgets = &validator.lint.budgets;
+    over_budget(
+        "body",
+        files.size.body,
+        budgets.body_tokens,
+        budgets.body_lines,
+    )
+}
+
+fn check_budget_references(validator: &Validator, _: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    let budget = validator.lint.budgets.references_tokens;
+    if budget == 0 {
+        return Vec::new();
+    }
+    over_budget("references", files.size.references, budget, 0)
+}
+
+/// Findings for a part of a skill that exceeds its token or line budget (0 = no limit)
+fn over_budget(part: &str, size: TextSize, max_tokens: usize, max_lines: usize) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if max_tokens > 0 && size.tokens > max_tokens {
+        findings.push(Finding::file(format!(
+            "{part} is about {} tokens, over the budget of {max_tokens}",
+            size.tokens
+        )));
+    }
+    if max_lines > 0 && size.lines > max_lines {
+        findings.push(Finding::file(format!(
+            "{part} has {} lines, over the budget of {max_lines}",
+            size.lines
+        )));
+    }
+    findings
+}
+
+fn check_license_missing(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    if skill.meta.license.is_none() {
+        vec![Finding::file("no license given".to_string())]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_compatibility_missing(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    if skill.meta.compatibility.is_none() {
+        vec![Finding::file(
+            "no compatibility note (e.g. required tools or network access)".to_string(),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_allowed_tools_syntax(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    let Some(value) = &skill.meta.allowed_tools else {
+        return Vec::new();
+    };
+    parse_allowed_tools(value)
+        .into_iter()
+        .filter_map(|entry| entry.err())
+        .map(|e| Finding::field("allowed-tools", e.to_string()))
+        .collect()
+}
+
+fn check_allowed_tools_broad(_: &Validator, skill: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    skill
+        .allowed_tools()
+        .into_iter()
+        .filter(|tool| tool.is_broad())
+        .map(|tool| {
+            Finding::field(
+                "allowed-tools",
+                format!(
+                    "'{tool}' allows any use of the tool; restrict it with a pattern such as {}(git:*)",
+                    tool.tool
+                ),
+            )
+        })
+        .collect()
+}
+
+fn check_allowed_tools_unknown(
+    validator: &Validator,
+    skill: &Skill,
+    _: &SkillFiles,
+) -> Vec<Finding> {
+    let tools = skill.allowed_tools();
+    validator
+        .known_tools
+        .iter()
+        .flat_map(|(target, known)| {
+            tools
+                .iter()
+                .filter(|tool| !tool.is_known_to(known))
+                .map(move |tool| {
+                    Finding::field(
+                        "allowed-tools",
+                        format!("'{}' is not a tool target '{target}' knows", tool.tool),
+                    )
+                })
+        })
+        .collect()
+}
+
+fn check_resource_missing(_: &Validator, skill: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    files
+        .references
+        .iter()
+        .filter(|r| !r.escapes() && !skill.path.join(r.path()).exists())
+        .map(|r| {
+            Finding::at(
+                Path::new(SKILL_FILE_NAME),
+                Some(r.line),
+                format!("'{}' does not exist in the skill folder", r.target),
+            )
+        })
+        .collect()
+}
+
+fn check_resource_escapes(_: &Validator, _: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    files
+        .references
+        .iter()
+        .filter(|r| r.escapes())
+        .map(|r| {
+            Finding::at(
+                Path::new(SKILL_FILE_NAME),
+                Some(r.line),
+                format!("'{}' points outside the skill folder", r.target),
+            )
+        })
+        .collect()
+}
+
+fn check_resource_unreferenced(_: &Validator, _: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    let Some(raw) = &files.raw else {
+        return Vec::new();
+    };
+
+    // Mentioned by path anywhere (code blocks too), or inside a linked folder
+    files
+        .bundled
+        .iter()
+        .filter(|file| {
+            let mentioned = raw.contains(&*file.to_string_lossy().replace('\\', "/"));
+            !mentioned && !files.references.iter().any(|r| file.starts_with(r.path()))
+        })
+        .map(|file| {
+            let message = format!(
+                "'{}' is bundled but never referenced from SKILL.md",
+                file.display()
+            );
+            Finding::at(file, None, message)
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn check_script_not_executable(_: &Validator, skill: &Skill, files: &SkillFiles) -> Vec<Finding> {
+    use std::io::Read;
+    use std::os::unix::fs::PermissionsExt;
+
+    let has_shebang = |path: &Path| {
+        let mut start = [0u8; 2];
+        std::fs::File::open(path)
+            .and_then(|mut f| f.read_exact(&mut start))
+            .is_ok_and(|()| &start == b"#!")
+    };
+
+    files
+        .bundled
+        .iter()
+        .filter(|file| file.starts_with("scripts"))
+        .filter(|file| {
+            let path = skill.path.join(file);
+            let executable = path
+                .metadata()
+                .is_ok_and(|m| m.permissions().mode() & 0o111 != 0);
+            !executable && has_shebang(&path)
+        })
+        .map(|file| {
+            let message = format!(
+                "'{}' has a shebang but is not executable (chmod +x)",
+                file.display()
+            );
+            Finding::at(file, None, message)
+        })
+        .collect()
+}
+
+/// Executable bits don't exist elsewhere
+#[cfg(not(unix))]
+fn check_script_not_executable(_: &Validator, _: &Skill, _: &SkillFiles) -> Vec<Finding> {
+    Vec::new()
+}
+
+/// Fill in line numbers for diagnostics about frontmatter fields
+///
+/// Best effort: the line of the first top-level `field:` in the frontmatter
+/// of SKILL.md as read (`raw`).
+fn add_field_lines(raw: Option<&str>, diagnostics: &mut [Diagnostic]) {
+    let needs_lines = diagnostics.iter().any(|d| {
+        d.location
+            .as_ref()
+            .is_some_and(|l| l.line.is_none() && l.field.is_some())
+    });
+    if !needs_lines {
+        return;
+    }
+    let Some(raw) = raw else {
+        return;
+    };
+
+    for location in diagnostics.iter_mut().filter_map(|d| d.location.as_mut()) {
+        if let (None, Some(field)) = (location.line, &location.field) {
+            location.line = field_line(raw, field);
+        }
+    }
+}
+
+/// Line (1-based) of a top-level frontmatter field
+fn field_line(raw: &str, field: &str) -> Option<usize> {
+    let mut lines = raw.lines().enumerate();
+    lines.find(|(_, line)| line.trim() == "---")?;
+    lines
+        .take_while(|(_, line)| line.trim() != "---")
+        .find(|(_, line)| {
+            line.strip_prefix(field)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'))
+        })
+        .map(|(index, _)| index + 1)
 }
 
 /// Check if a string is valid kebab-case
@@ -180,6 +806,8 @@ fn is_kebab_case(s: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lint::RuleLevel;
+    use crate::project::Scope;
     use crate::skill::SkillMeta;
     use std::path::PathBuf;
 
@@ -195,7 +823,9 @@ mod tests {
             content: content.to_string(),
             path: PathBuf::from(format!("/test/skills/{}", name)),
             validation_status: ValidationStatus::Unknown,
-            validation_errors: Vec::new(),
+            diagnostics: Vec::new(),
+            scope: Scope::Global,
+            size: Default::default(),
         }
     }
 
@@ -215,7 +845,9 @@ mod tests {
             content: content.to_string(),
             path: PathBuf::from(path),
             validation_status: ValidationStatus::Unknown,
-            validation_errors: Vec::new(),
+            diagnostics: Vec::new(),
+            scope: Scope::Global,
+            size: Default::default(),
         }
     }
 
@@ -237,19 +869,67 @@ mod tests {
             content: content.to_string(),
             path: PathBuf::from(format!("/test/skills/{}", name)),
             validation_status: ValidationStatus::Unknown,
-            validation_errors: Vec::new(),
+            diagnostics: Vec::new(),
+            scope: Scope::Global,
+            size: Default::default(),
         }
     }
 
     #[test]
     fn valid_skill_passes() {
         let validator = Validator::new();
-        let mut skill = create_test_skill("my-skill", "A test skill", "Some content here");
+        let mut skill = create_test_skill_with_compatibility(
+            "my-skill",
+            "A test skill for the validator",
+            "Some content here",
+            Some("Any agent"),
+        );
+        skill.meta.license = Some("MIT".to_string());
 
         let result = validator.validate(&mut skill);
         assert!(result.is_ok());
         assert_eq!(skill.validation_status, ValidationStatus::Valid);
-        assert!(skill.validation_errors.is_empty());
+        assert!(skill.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn warnings_keep_a_skill_valid() {
+        let validator = Validator::new();
+        let mut skill = create_test_skill("my-skill", "Terse", "Content");
+        skill.meta.version = Some("1.0".to_string());
+        skill.meta.author = Some("Someone".to_string());
+
+        assert!(validator.validate(&mut skill).is_ok());
+        assert_eq!(skill.validation_status, ValidationStatus::ValidWithWarnings);
+        assert!(skill.is_valid());
+        let warnings: Vec<&str> = skill
+            .diagnostics_of(Severity::Warning)
+            .map(|d| d.rule.as_str())
+            .collect();
+        assert_eq!(
+            warnings,
+            [
+                "description-short",
+                "legacy-fields",
+                "legacy-fields",
+                "license-missing",
+                "compatibility-missing"
+            ]
+        );
+
+        // A missing license or compatibility note is enough for a warning
+        let mut skill = create_test_skill("my-skill", "A skill without a license", "Content");
+        skill.meta.compatibility = Some("Any agent".to_string());
+        assert!(validator.validate(&mut skill).is_ok());
+        assert_eq!(skill.validation_status, ValidationStatus::ValidWithWarnings);
+        assert!(skill
+            .diagnostics
+            .iter()
+            .any(|d| d.rule == "license-missing" && d.severity == Severity::Warning));
+
+        skill.meta.license = Some("MIT".to_string());
+        assert!(validator.validate(&mut skill).is_ok());
+        assert_eq!(skill.validation_status, ValidationStatus::Valid);
     }
 
     #[test]
@@ -261,9 +941,9 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(skill.validation_status, ValidationStatus::Invalid);
         assert!(skill
-            .validation_errors
+            .diagnostics
             .iter()
-            .any(|e| e.contains("name is required")));
+            .any(|d| d.message.contains("name is required")));
     }
 
     #[test]
@@ -275,9 +955,9 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(skill.validation_status, ValidationStatus::Invalid);
         assert!(skill
-            .validation_errors
+            .diagnostics
             .iter()
-            .any(|e| e.contains("description is required")));
+            .any(|d| d.message.contains("description is required")));
     }
 
     #[test]
@@ -288,16 +968,17 @@ mod tests {
         let result = validator.validate(&mut skill);
         assert!(result.is_err());
         assert!(skill
-            .validation_errors
+            .diagnostics
             .iter()
-            .any(|e| e.contains("must have content")));
+            .any(|d| d.message.contains("must have content")));
     }
 
     #[test]
     fn empty_content_allowed_when_disabled() {
-        let validator = Validator {
-            require_content: false,
-        };
+        let validator = Validator::with_lint(LintConfig {
+            rules: [("content-required".to_string(), RuleLevel::Off)].into(),
+            ..Default::default()
+        });
         let mut skill = create_test_skill("my-skill", "Description", "");
 
         let result = validator.validate(&mut skill);
@@ -312,9 +993,9 @@ mod tests {
         let mut skill = create_test_skill("MySkill", "Desc", "Content");
         assert!(validator.validate(&mut skill).is_err());
         assert!(skill
-            .validation_errors
+            .diagnostics
             .iter()
-            .any(|e| e.contains("kebab-case")));
+            .any(|d| d.message.contains("kebab-case")));
 
         // Underscores
         let mut skill = create_test_skill("my_skill", "Desc", "Content");
@@ -403,9 +1084,9 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(skill.validation_status, ValidationStatus::Invalid);
         assert!(skill
-            .validation_errors
+            .diagnostics
             .iter()
-            .any(|e| e.contains("must match parent directory")));
+            .any(|d| d.message.contains("must match parent directory")));
     }
 
     #[test]
@@ -413,8 +1094,14 @@ mod tests {
         let validator = Validator::new();
 
         // Skill with matching folder name
-        let mut skill =
-            create_test_skill_with_path("my-skill", "Desc", "Content", "/test/skills/my-skill");
+        let mut skill = create_test_skill_with_path(
+            "my-skill",
+            "A skill whose name matches its folder",
+            "Content",
+            "/test/skills/my-skill",
+        );
+        skill.meta.license = Some("MIT".to_string());
+        skill.meta.compatibility = Some("Any agent".to_string());
 
         let result = validator.validate(&mut skill);
         assert!(result.is_ok());
@@ -442,9 +1129,9 @@ mod tests {
         let result = validator.validate(&mut skill);
         assert!(result.is_err());
         assert!(skill
-            .validation_errors
+            .diagnostics
             .iter()
-            .any(|e| e.contains("compatibility exceeds")));
+            .any(|d| d.message.contains("compatibility exceeds")));
     }
 
     #[test]
@@ -468,4 +1155,184 @@ mod tests {
         let result = validator.validate(&mut skill);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn diagnostics_carry_rule_ids_and_fields() {
+        let validator = Validator::new();
+        let mut skill = create_test_skill("MySkill", "", "Content");
+        validator.validate(&mut skill).unwrap_err();
+
+        let rules: Vec<&str> = skill
+            .diagnostics_of(Severity::Error)
+            .map(|d| d.rule.as_str())
+            .collect();
+        assert_eq!(rules, ["name-format", "description-required"]);
+        let location = skill.diagnostics[1].location.as_ref().unwrap();
+        assert_eq!(location.file, PathBuf::from(SKILL_FILE_NAME));
+        assert_eq!(location.field.as_deref(), Some("description"));
+    }
+
+    #[test]
+    fn releveled_rules_only_fail_as_errors() {
+        let long_description = "a".repeat(MAX_DESCRIPTION_LENGTH + 1);
+        let validator = Validator::with_lint(LintConfig {
+            rules: [("description-length".to_string(), RuleLevel::Warning)].into(),
+            skills: [(
+                "strict-skill".to_string(),
+                [("description-length".to_string(), RuleLevel::Error)].into(),
+            )]
+            .into(),
+            ..Default::default()
+        });
+
+        let mut skill = create_test_skill("relaxed-skill", &long_description, "Content");
+        assert!(validator.validate(&mut skill).is_ok());
+        assert_eq!(skill.validation_status, ValidationStatus::ValidWithWarnings);
+        assert_eq!(skill.diagnostics[0].rule, "description-length");
+        assert_eq!(skill.diagnostics[0].severity, Severity::Warning);
+
+        let mut skill = create_test_skill("strict-skill", &long_description, "Content");
+        assert!(validator.validate(&mut skill).is_err());
+        assert_eq!(skill.diagnostics[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn frontmatter_directive_suppresses_rules() {
+        let validator = Validator::new();
+        let mut skill = create_test_skill_with_path("my_skill", "Desc", "", "/test/skills/other");
+        skill.meta.metadata.insert(
+            lint::DISABLE_DIRECTIVE.to_string(),
+            "name-format, name-matches-folder".to_string(),
+        );
+
+        assert!(validator.validate(&mut skill).is_err());
+        let rules: Vec<&str> = skill
+            .diagnostics_of(Severity::Error)
+            .map(|d| d.rule.as_str())
+            .collect();
+        assert_eq!(rules, ["content-required"]);
+    }
+
+    #[test]
+    fn loading_problems_survive_validation() {
+        let validator = Validator::new();
+        let mut skill = create_test_skill("my-skill", "Desc", "Content");
+        skill.diagnostics.push(Diagnostic::new(
+            lint::FRONTMATTER_SYNTAX,
+            Severity::Error,
+            "invalid type: sequence, expected a string",
+        ));
+
+        assert!(validator.validate(&mut skill).is_err());
+        let errors: Vec<_> = skill.diagnostics_of(Severity::Error).collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule, lint::FRONTMATTER_SYNTAX);
+    }
+
+    #[test]
+    fn field_line_finds_top_level_keys() {
+        let raw = "---\nname: my-skill\nmetadata:\n  description: nested\ndescription: Top\n---\n\ndescription: body\n";
+        assert_eq!(field_line(raw, "name"), Some(2));
+        assert_eq!(field_line(raw, "description"), Some(5));
+        assert_eq!(field_line(raw, "compatibility"), None);
+    }
+
+    #[test]
+    fn bundled_resources_are_checked() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("my-skill");
+        std::fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("scripts/run.sh"), "#!/bin/sh\necho hi\n").unwrap();
+        std::fs::write(skill_dir.join("references/api.md"), "# API\n").unwrap();
+        std::fs::write(skill_dir.join("references/unused.md"), "# Unused\n").unwrap();
+        std::fs::write(
+            skill_dir.join(SKILL_FILE_NAME),
+            "---\nname: my-skill\ndescription: Exercises bundled resource checks\n---\n\n\
+             Read [the API](references/api.md) and [setup](references/setup.md).\n\
+             Run `scripts/run.sh`, see [other](../other-skill/SKILL.md).\n",
+        )
+        .unwrap();
+        let mut skill = Skill::load(&skill_dir).unwrap();
+
+        assert!(Validator::new().validate(&mut skill).is_ok());
+        let found: Vec<(&str, String)> = skill
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule.starts_with("resource") || d.rule.starts_with("script"))
+            .map(|d| (d.rule.as_str(), d.location.as_ref().unwrap().to_string()))
+            .collect();
+
+        let mut expected = vec![
+            ("resource-missing", "SKILL.md:6".to_string()),
+            ("resource-escapes", "SKILL.md:7".to_string()),
+            (
+                "resource-unreferenced",
+                Path::new("references/unused.md").display().to_string(),
+            ),
+        ];
+        if cfg!(unix) {
+            expected.push((
+                "script-not-executable",
+                Path::new("scripts/run.sh").display().to_string(),
+            ));
+        }
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn allowed_tools_are_checked() {
+        let mut validator = Validator::new();
+        validator.known_tools.insert(
+            "claude-code".to_string(),
+            vec!["Bash".to_string(), "Read".to_string()],
+        );
+        let mut skill = create_test_skill("my-skill", "Checks allowed tools", "Content");
+        skill.meta.allowed_tools = Some("Bash(git:*) Bash Grep Read(".to_string());
+
+        assert!(validator.validate(&mut skill).is_err());
+        let found: Vec<(&str, &str)> = skill
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule.starts_with("allowed-tools"))
+            .map(|d| (d.rule.as_str(), d.message.as_str()))
+            .collect();
+        assert_eq!(found.len(), 3, "{found:?}");
+        assert_eq!(found[0].0, "allowed-tools-syntax");
+        assert!(found[0].1.contains("'Read('"));
+        assert_eq!(found[1].0, "allowed-tools-broad");
+        assert!(found[1].1.starts_with("'Bash' allows any use"));
+        assert_eq!(
+            found[2],
+            (
+                "allowed-tools-unknown",
+                "'Grep' is not a tool target 'claude-code' knows"
+            )
+        );
+    }
+
+    #[test]
+    fn budgets_flag_oversized_bodies() {
+        let body = "word ".repeat(40);
+        let mut validator = Validator::new();
+        validator.lint.budgets.body_tokens = 30;
+        validator.lint.budgets.body_lines = 0;
+        let mut skill = create_test_skill("my-skill", "Checks size budgets", &body);
+
+        assert!(validator.validate(&mut skill).is_ok());
+        let budget: Vec<&str> = skill
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule.starts_with("budget"))
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(budget, ["body is about 40 tokens, over the budget of 30"]);
+
+        validator.lint.budgets.body_tokens = 0;
+        validator.validate(&mut skill).unwrap();
+        assert!(!skill
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("budget")));
+    }
 }
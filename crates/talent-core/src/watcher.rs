@@ -0,0 +1,338 @@
+//! Filesystem watching for automatic resyncs
+//!
+//! Watches the central skills directory (recursively) and each target's skills
+//! directory (top level only). Raw events are debounced and grouped into a
+//! [`ChangeSet`] naming the skills and targets that changed, which
+//! [`SkillManager::apply_changes`](crate::SkillManager::apply_changes) turns
+//! into an incremental sync.
+
+use crate::error::{Error, Result};
+use crate::journal::JOURNAL_DIR_NAME;
+use crate::manifest::MANIFEST_FILE_NAME;
+use crate::syncer::ADOPT_BACKUP_DIR_NAME;
+use crate::target::Target;
+use notify_debouncer_full::notify::{EventKind, RecommendedWatcher, RecursiveMode};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, RecommendedCache};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// Default time to wait for events to settle before reporting changes
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Skills and targets touched by a batch of filesystem events
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeSet {
+    /// Skill folder names (under the central skills directory) that changed
+    pub skills: BTreeSet<String>,
+
+    /// Skill folder names under the project skills directory that changed
+    pub project_skills: BTreeSet<String>,
+
+    /// IDs of targets whose skills directory changed
+    pub targets: BTreeSet<String>,
+}
+
+impl ChangeSet {
+    /// Check if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.skills.is_empty() && self.project_skills.is_empty() && self.targets.is_empty()
+    }
+}
+
+/// A watched directory and what changes inside it mean
+#[derive(Debug, Clone)]
+enum Root {
+    /// The central skills directory
+    Skills,
+    /// The project skills directory
+    ProjectSkills,
+    /// A target's skills directory (value = target id)
+    Target(String),
+}
+
+/// Maps event paths back to skills and targets
+#[derive(Debug, Default)]
+struct WatchRoots {
+    roots: Vec<(PathBuf, Root)>,
+}
+
+impl WatchRoots {
+    /// Register a root under both its given and canonical path
+    ///
+    /// Some platforms report canonical paths (e.g. `/private/var` on macOS).
+    fn add(&mut self, path: &Path, root: Root) {
+        if let Ok(canonical) = path.canonicalize() {
+            if canonical != path {
+                self.roots.push((canonical, root.clone()));
+            }
+        }
+        self.roots.push((path.to_path_buf(), root));
+    }
+
+    /// Record what an event at `path` means in `changes`
+    fn classify(&self, path: &Path, changes: &mut ChangeSet) {
+        for (root_path, root) in &self.roots {
+            let Ok(relative) = path.strip_prefix(root_path) else {
+                continue;
+            };
+
+            // Only the first component matters: it names the skill or target entry
+            let Some(Component::Normal(first)) = relative.components().next() else {
+                continue;
+            };
+            let Some(name) = first.to_str() else {
+                continue;
+            };
+
+            match root {
+                // Hidden files and folders are not skills
+                Root::Skills | Root::ProjectSkills if name.starts_with('.') => {}
+                Root::Skills => {
+                    changes.skills.insert(name.to_string());
+                }
+                Root::ProjectSkills => {
+                    changes.project_skills.insert(name.to_string());
+                }
+                Root::Target(id) => {
+                    // Our own manifest, journal and backup writes are not interesting
+                    let ours = [MANIFEST_FILE_NAME, JOURNAL_DIR_NAME, ADOPT_BACKUP_DIR_NAME];
+                    if !ours.contains(&name) {
+                        changes.targets.insert(id.clone());
+                    }
+                }
+            }
+            return;
+        }
+    }
+}
+
+/// Watches skill and target directories and reports debounced changes
+pub struct SkillWatcher {
+    /// Keeps the underlying watcher alive
+    _debouncer: Debouncer<RecommendedWatcher, RecommendedCache>,
+
+    /// Debounced events
+    events: Receiver<DebounceEventResult>,
+
+    /// Watched directories
+    roots: WatchRoots,
+
+    /// Number of target directories being watched
+    watched_targets: usize,
+}
+
+impl SkillWatcher {
+    /// Start watching the central skills directory, a project skills directory and the given targets
+    ///
+    /// Targets whose skills directory doesn't exist yet are not watched.
+    pub fn new<'a>(
+        skills_dir: &Path,
+        project_skills_dir: Option<&Path>,
+        targets: impl IntoIterator<Item = &'a Target>,
+        debounce: Duration,
+    ) -> Result<Self> {
+        let (tx, events) = channel();
+        let mut debouncer = new_debouncer(debounce, None, tx).map_err(watch_error)?;
+        let mut roots = WatchRoots::default();
+
+        debouncer
+            .watch(skills_dir, RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+        roots.add(skills_dir, Root::Skills);
+
+        if let Some(dir) = project_skills_dir.filter(|d| d.is_dir()) {
+            debouncer
+                .watch(dir, RecursiveMode::Recursive)
+                .map_err(watch_error)?;
+            roots.add(dir, Root::ProjectSkills);
+        }
+        let mut watched_targets = 0;
+
+        for target in targets {
+            if !target.skills_dir_exists() {
+                continue;
+            }
+            debouncer
+                .watch(&target.skills_path, RecursiveMode::NonRecursive)
+                .map_err(watch_error)?;
+            roots.add(&target.skills_path, Root::Target(target.id().to_string()));
+            watched_targets += 1;
+        }
+
+        Ok(Self {
+            _debouncer: debouncer,
+            events,
+            roots,
+            watched_targets,
+        })
+    }
+
+    /// Number of target directories being watched (targets without a skills directory are skipped)
+    pub fn watched_targets(&self) -> usize {
+        self.watched_targets
+    }
+
+    /// Block until something relevant changes
+    ///
+    /// Batches that only touch irrelevant paths are skipped. Returns an error
+    /// if the underlying watcher fails or stops.
+    pub fn next_changes(&self) -> Result<ChangeSet> {
+        loop {
+            let batch = self.events.recv().map_err(|_| watcher_stopped())?;
+            if let Some(changes) = self.relevant_changes(batch)? {
+                return Ok(changes);
+            }
+        }
+    }
+
+    /// Like [`next_changes`](Self::next_changes), but give up after `timeout`
+    ///
+    /// Returns `None` if nothing relevant changed in time, so callers can
+    /// check whether the watcher needs rebuilding between batches.
+    pub fn next_changes_timeout(&self, timeout: Duration) -> Result<Option<ChangeSet>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let batch = match self.events.recv_timeout(remaining) {
+                Ok(batch) => batch,
+                Err(RecvTimeoutError::Timeout) => return Ok(None),
+                Err(RecvTimeoutError::Disconnected) => return Err(watcher_stopped()),
+            };
+            if let Some(changes) = self.relevant_changes(batch)? {
+                return Ok(Some(changes));
+            }
+        }
+    }
+
+    /// Turn a batch of debounced events into a change set, if anything relevant changed
+    fn relevant_changes(&self, batch: DebounceEventResult) -> Result<Option<ChangeSet>> {
+        let events = batch.map_err(|errors| {
+            Error::Watch(
+                errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        })?;
+
+        let mut changes = ChangeSet::default();
+        // Reading files (including our own reloads) produces access events; only changes count
+        for event in events
+            .iter()
+            .filter(|e| !matches!(e.kind, EventKind::Access(_)))
+        {
+            for path in &event.paths {
+                self.roots.classify(path, &mut changes);
+            }
+        }
+
+        Ok((!changes.is_empty()).then_some(changes))
+    }
+}
+
+/// Error returned once the underlying watcher has gone away
+fn watcher_stopped() -> Error {
+    Error::Watch("watcher stopped".to_string())
+}
+
+/// Convert a notify error into our error type
+fn watch_error(e: notify_debouncer_full::notify::Error) -> Error {
+    Error::Watch(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::{Skill, SKILL_FILE_NAME};
+    use crate::target::TargetKind;
+    use tempfile::TempDir;
+
+    fn test_roots() -> WatchRoots {
+        let mut roots = WatchRoots::default();
+        roots.add(Path::new("/home/me/.agents/skills"), Root::Skills);
+        roots.add(
+            Path::new("/home/me/.claude/skills"),
+            Root::Target("claude-code".to_string()),
+        );
+        roots
+    }
+
+    #[test]
+    fn classifies_skill_and_target_paths() {
+        let roots = test_roots();
+        let mut changes = ChangeSet::default();
+
+        roots.classify(
+            Path::new("/home/me/.agents/skills/my-skill/SKILL.md"),
+            &mut changes,
+        );
+        roots.classify(
+            Path::new("/home/me/.agents/skills/other/scripts/run.sh"),
+            &mut changes,
+        );
+        roots.classify(Path::new("/home/me/.claude/skills/my-skill"), &mut changes);
+
+        assert_eq!(
+            changes.skills.iter().collect::<Vec<_>>(),
+            vec!["my-skill", "other"]
+        );
+        assert_eq!(
+            changes.targets.iter().collect::<Vec<_>>(),
+            vec!["claude-code"]
+        );
+    }
+
+    #[test]
+    fn ignores_roots_hidden_files_and_manifest() {
+        let roots = test_roots();
+        let mut changes = ChangeSet::default();
+
+        roots.classify(Path::new("/home/me/.agents/skills"), &mut changes);
+        roots.classify(Path::new("/home/me/.agents/skills/.DS_Store"), &mut changes);
+        roots.classify(
+            &Path::new("/home/me/.claude/skills").join(MANIFEST_FILE_NAME),
+            &mut changes,
+        );
+        roots.classify(Path::new("/somewhere/else/file"), &mut changes);
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn reports_skill_edits() {
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        std::fs::create_dir_all(&skills_dir).unwrap();
+        let skill = Skill::create(&skills_dir, "my-skill", "Test skill").unwrap();
+
+        let target = Target::new(TargetKind::ClaudeCode, temp.path().join("missing"));
+        let watcher =
+            SkillWatcher::new(&skills_dir, None, [&target], Duration::from_millis(50)).unwrap();
+        assert_eq!(watcher.watched_targets(), 0);
+
+        std::fs::write(
+            skill.path.join(SKILL_FILE_NAME),
+            "---\nname: my-skill\ndescription: Edited\n---\n",
+        )
+        .unwrap();
+
+        let changes = watcher.next_changes().unwrap();
+        assert!(changes.skills.contains("my-skill"));
+    }
+
+    #[test]
+    fn next_changes_timeout_returns_none_when_quiet() {
+        let temp = TempDir::new().unwrap();
+        let watcher = SkillWatcher::new(temp.path(), None, [], Duration::from_millis(50)).unwrap();
+        assert_eq!(
+            watcher
+                .next_changes_timeout(Duration::from_millis(100))
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -5,9 +5,11 @@ use crate::{
     ImportSelectionInfo, ScannedSkillInfo, SkillInfo, StatsInfo,
 };
 use agentloom_core::{
-    check_filemerge_available, open_filemerge, Importer, MigrationResult, SyncResult, TargetInfo,
+    check_filemerge_available, open_filemerge, AdoptCandidate, AdoptResult, AdoptSelection,
+    Importer, MigrationResult, RepairResult, SyncPlan, SyncResult, TargetInfo,
 };
 use std::path::PathBuf;
+use std::sync::atomic::Ordering;
 
 /// Get all skills (sorted alphabetically by name)
 #[tauri::command]
@@ -23,18 +25,14 @@ pub fn get_skills(state: tauri::State<'_, AppState>) -> Result<Vec<SkillInfo>, S
 pub fn get_targets(state: tauri::State<'_, AppState>) -> Result<Vec<TargetInfo>, String> {
     let manager = state.manager.lock().map_err(|e| e.to_string())?;
 
-    // Get skill names for sync status check
-    let skill_names: Vec<String> = manager
-        .skills()
-        .iter()
-        .map(|s| s.folder_name().to_string())
-        .collect();
-    let skills_dir = manager.config().skills_dir.clone();
-
     let mut targets: Vec<TargetInfo> = manager
         .targets()
         .iter()
-        .map(|t| TargetInfo::from_target(t, Some(&skill_names), Some(&skills_dir)))
+        .map(|t| {
+            // Disabled targets aren't expected to hold anything
+            let status = t.enabled.then(|| manager.target_status(t.id())).flatten();
+            TargetInfo::from_target(t, status)
+        })
         .collect();
     targets.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
     Ok(targets)
@@ -47,6 +45,39 @@ pub fn sync_all(state: tauri::State<'_, AppState>) -> Result<Vec<SyncResult>, St
     Ok(manager.sync_all())
 }
 
+/// Fix broken links in all targets
+#[tauri::command]
+pub fn repair_all(state: tauri::State<'_, AppState>) -> Result<Vec<RepairResult>, String> {
+    let manager = state.manager.lock().map_err(|e| e.to_string())?;
+    Ok(manager.repair_all())
+}
+
+/// Find native folders in targets that keep managed skills from syncing
+#[tauri::command]
+pub fn get_adoptable_skills(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<AdoptCandidate>, String> {
+    let manager = state.manager.lock().map_err(|e| e.to_string())?;
+    Ok(manager.adoptable_skills())
+}
+
+/// Replace the selected native folders with the managed skills
+#[tauri::command]
+pub fn adopt_skills(
+    state: tauri::State<'_, AppState>,
+    selections: Vec<AdoptSelection>,
+) -> Result<Vec<AdoptResult>, String> {
+    let mut manager = state.manager.lock().map_err(|e| e.to_string())?;
+    Ok(manager.adopt(&selections))
+}
+
+/// Preview what `sync_all` would do without changing anything
+#[tauri::command]
+pub fn plan_sync(state: tauri::State<'_, AppState>) -> Result<Vec<SyncPlan>, String> {
+    let manager = state.manager.lock().map_err(|e| e.to_string())?;
+    Ok(manager.plan_all())
+}
+
 /// Create a new skill
 #[tauri::command]
 pub fn create_skill(
@@ -162,9 +193,12 @@ pub fn get_stats(state: tauri::State<'_, AppState>) -> Result<StatsInfo, String>
     Ok(StatsInfo {
         total_skills: stats.total_skills,
         valid_skills: stats.valid_skills,
+        warning_skills: stats.warning_skills,
         invalid_skills: stats.invalid_skills,
+        total_size: stats.total_size,
         total_targets: stats.total_targets,
         enabled_targets: stats.enabled_targets,
+        is_watching: state.watching.load(Ordering::Relaxed),
     })
 }
 
@@ -371,8 +405,6 @@ pub fn remove_custom_target(
 pub fn get_available_target_types(
     state: tauri::State<'_, AppState>,
 ) -> Result<Vec<(String, String)>, String> {
-    use agentloom_core::TargetKind;
-
     let manager = state.manager.lock().map_err(|e| e.to_string())?;
     let existing_ids: Vec<_> = manager
         .targets()
@@ -380,11 +412,12 @@ pub fn get_available_target_types(
         .map(|t| t.id().to_string())
         .collect();
 
-    // Return target types that aren't already configured
-    let available: Vec<_> = TargetKind::all()
-        .iter()
-        .filter(|k| !existing_ids.contains(&k.id().to_string()))
-        .map(|k| (k.id().to_string(), k.display_name().to_string()))
+    // Return target types (built-in and declared) that aren't already configured
+    let available: Vec<_> = manager
+        .target_kinds()
+        .into_iter()
+        .filter(|k| !existing_ids.contains(&k.id))
+        .map(|k| (k.id.clone(), k.name().to_string()))
         .collect();
 
     Ok(available)
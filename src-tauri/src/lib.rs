@@ -5,10 +5,14 @@
 
 mod commands;
 mod menu;
+mod watch;
 
-use agentloom_core::{ConflictResolution, SkillManager, ValidationStatus};
+use agentloom_core::{
+    AllowedTool, ConflictResolution, Diagnostic, Scope, SkillManager, SkillSize, ValidationStatus,
+};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 use std::sync::Mutex;
 
 /// Skill information for the frontend
@@ -25,6 +29,8 @@ pub struct SkillInfo {
     pub compatibility: Option<String>,
     pub metadata: std::collections::HashMap<String, String>,
     pub allowed_tools: Option<String>,
+    /// `allowed_tools` parsed into entries (malformed ones left out)
+    pub parsed_allowed_tools: Vec<AllowedTool>,
 
     // Legacy fields (not in spec, kept for backward compatibility)
     pub tags: Vec<String>,
@@ -33,8 +39,11 @@ pub struct SkillInfo {
 
     // Internal fields
     pub path: String,
+    pub scope: Scope,
     pub validation_status: String,
-    pub validation_errors: Vec<String>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Lines, bytes and estimated tokens of each part of the skill
+    pub size: SkillSize,
 }
 
 impl From<&agentloom_core::Skill> for SkillInfo {
@@ -47,16 +56,20 @@ impl From<&agentloom_core::Skill> for SkillInfo {
             compatibility: skill.meta.compatibility.clone(),
             metadata: skill.meta.metadata.clone(),
             allowed_tools: skill.meta.allowed_tools.clone(),
+            parsed_allowed_tools: skill.allowed_tools(),
             tags: skill.meta.tags.clone(),
             version: skill.meta.version.clone(),
             author: skill.meta.author.clone(),
             path: skill.path.display().to_string(),
+            scope: skill.scope,
             validation_status: match skill.validation_status {
                 ValidationStatus::Unknown => "unknown".to_string(),
                 ValidationStatus::Valid => "valid".to_string(),
+                ValidationStatus::ValidWithWarnings => "valid_with_warnings".to_string(),
                 ValidationStatus::Invalid => "invalid".to_string(),
             },
-            validation_errors: skill.validation_errors.clone(),
+            diagnostics: skill.diagnostics.clone(),
+            size: skill.size(),
         }
     }
 }
@@ -66,9 +79,12 @@ impl From<&agentloom_core::Skill> for SkillInfo {
 pub struct StatsInfo {
     pub total_skills: usize,
     pub valid_skills: usize,
+    pub warning_skills: usize,
     pub invalid_skills: usize,
+    pub total_size: SkillSize,
     pub total_targets: usize,
     pub enabled_targets: usize,
+    pub is_watching: bool,
 }
 
 /// Discovered skill for import UI
@@ -185,6 +201,8 @@ pub struct ImportResultInfo {
 /// Application state shared across commands
 pub struct AppState {
     pub manager: Mutex<SkillManager>,
+    /// Whether the background watcher is running
+    pub watching: AtomicBool,
 }
 
 /// Initialize and run the Tauri application
@@ -198,11 +216,15 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .manage(AppState {
             manager: Mutex::new(manager),
+            watching: AtomicBool::new(false),
         })
         .setup(|app| {
             // Create and set application menu
             let menu = menu::create_menu(app.handle())?;
             app.set_menu(menu)?;
+
+            // Resync automatically when skills or targets change on disk
+            watch::start(app.handle());
             Ok(())
         })
         .on_menu_event(|app, event| {
@@ -218,6 +240,10 @@ pub fn run() {
             commands::get_skills,
             commands::get_targets,
             commands::sync_all,
+            commands::repair_all,
+            commands::get_adoptable_skills,
+            commands::adopt_skills,
+            commands::plan_sync,
             commands::create_skill,
             commands::validate_skill,
             commands::validate_all,
@@ -0,0 +1,85 @@
+//! Background filesystem watching that keeps targets synced while the app runs
+
+use crate::AppState;
+use agentloom_core::{ChangeSet, SkillWatcher, SyncResult, DEFAULT_DEBOUNCE};
+use serde::Serialize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Event sent to the frontend after the watcher resynced
+pub const SKILLS_CHANGED_EVENT: &str = "skills-changed";
+
+/// Payload of the skills-changed event
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillsChangedPayload {
+    pub changes: ChangeSet,
+    pub results: Vec<SyncResult>,
+}
+
+/// How long to wait for changes before checking whether the watcher needs rebuilding
+const REBUILD_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Start watching skills and targets on a background thread
+///
+/// The watcher is rebuilt whenever the directories it should watch change:
+/// a target is added, enabled or disabled, or a missing skills directory
+/// appears.
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+
+    std::thread::spawn(move || {
+        let state = app.state::<AppState>();
+        let mut watched_paths = Vec::new();
+        let mut watcher: Option<SkillWatcher> = None;
+
+        loop {
+            {
+                let Ok(manager) = state.manager.lock() else {
+                    break;
+                };
+                let paths = manager.watched_paths();
+                if watcher.is_none() || paths != watched_paths {
+                    match manager.watch(DEFAULT_DEBOUNCE) {
+                        Ok(new_watcher) => {
+                            watcher = Some(new_watcher);
+                            watched_paths = paths;
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to start watching skills: {e}");
+                            break;
+                        }
+                    }
+                }
+            }
+            let Some(ref current) = watcher else {
+                break;
+            };
+            state.watching.store(true, Ordering::Relaxed);
+
+            let changes = match current.next_changes_timeout(REBUILD_CHECK_INTERVAL) {
+                Ok(Some(changes)) => changes,
+                Ok(None) => continue,
+                Err(e) => {
+                    eprintln!("Stopped watching skills: {e}");
+                    break;
+                }
+            };
+
+            let results = match state.manager.lock() {
+                Ok(mut manager) => manager.apply_changes(&changes),
+                Err(_) => break,
+            };
+
+            // Emit event to frontend
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit(
+                    SKILLS_CHANGED_EVENT,
+                    SkillsChangedPayload { changes, results },
+                );
+            }
+        }
+
+        state.watching.store(false, Ordering::Relaxed);
+    });
+}
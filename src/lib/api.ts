@@ -1,7 +1,8 @@
 // API wrapper for Tauri commands
 
 import { invoke } from '@tauri-apps/api/core';
-import type { SkillInfo, TargetInfo, SyncResult, StatsInfo, DiscoveredSkillInfo, ImportSelectionInfo, ImportResultInfo, ScannedSkillInfo, FolderImportSelectionInfo, MigrationResult } from './types';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import type { SkillInfo, TargetInfo, SyncResult, SyncPlan, RepairResult, AdoptCandidate, AdoptSelection, AdoptResult, StatsInfo, DiscoveredSkillInfo, ImportSelectionInfo, ImportResultInfo, ScannedSkillInfo, FolderImportSelectionInfo, MigrationResult, SkillsChangedPayload } from './types';
 
 export async function getSkills(): Promise<SkillInfo[]> {
   return invoke<SkillInfo[]>('get_skills');
@@ -15,6 +16,22 @@ export async function syncAll(): Promise<SyncResult[]> {
   return invoke<SyncResult[]>('sync_all');
 }
 
+export async function repairAll(): Promise<RepairResult[]> {
+  return invoke<RepairResult[]>('repair_all');
+}
+
+export async function getAdoptableSkills(): Promise<AdoptCandidate[]> {
+  return invoke<AdoptCandidate[]>('get_adoptable_skills');
+}
+
+export async function adoptSkills(selections: AdoptSelection[]): Promise<AdoptResult[]> {
+  return invoke<AdoptResult[]>('adopt_skills', { selections });
+}
+
+export async function planSync(): Promise<SyncPlan[]> {
+  return invoke<SyncPlan[]>('plan_sync');
+}
+
 export async function createSkill(name: string, description: string): Promise<SkillInfo> {
   return invoke<SkillInfo>('create_skill', { name, description });
 }
@@ -130,3 +147,9 @@ export async function checkAndMigrate(): Promise<MigrationResult> {
 export async function hasLegacySkills(): Promise<boolean> {
   return invoke<boolean>('has_legacy_skills');
 }
+
+// Watching
+
+export async function onSkillsChanged(handler: (payload: SkillsChangedPayload) => void): Promise<UnlistenFn> {
+  return listen<SkillsChangedPayload>('skills-changed', (event) => handler(event.payload));
+}
@@ -1,6 +1,8 @@
 // Types that mirror the Rust backend structs
 // See https://agentskills.io/specification for field definitions
 
+export type Scope = 'global' | 'project';
+
 export interface SkillInfo {
   // Required fields (per spec)
   name: string;
@@ -12,6 +14,7 @@ export interface SkillInfo {
   compatibility: string | null;
   metadata: Record<string, string>;
   allowed_tools: string | null;
+  parsed_allowed_tools: AllowedTool[];
 
   // Legacy fields (not in spec, kept for backward compatibility)
   tags: string[];
@@ -20,15 +23,56 @@ export interface SkillInfo {
 
   // Internal fields
   path: string;
-  validation_status: 'unknown' | 'valid' | 'invalid';
-  validation_errors: string[];
+  scope: Scope;
+  validation_status: 'unknown' | 'valid' | 'valid_with_warnings' | 'invalid';
+  diagnostics: Diagnostic[];
+  size: SkillSize;
+}
+
+export interface AllowedTool {
+  tool: string;
+  pattern?: string;
+  wildcard: boolean;
+}
+
+export interface TextSize {
+  bytes: number;
+  lines: number;
+  tokens: number;
+}
+
+export interface SkillSize {
+  frontmatter: TextSize;
+  body: TextSize;
+  references: TextSize;
+}
+
+export type Severity = 'error' | 'warning' | 'info';
+
+export interface DiagnosticLocation {
+  file: string;
+  line?: number;
+  field?: string;
+}
+
+export interface Diagnostic {
+  rule: string;
+  severity: Severity;
+  message: string;
+  location?: DiagnosticLocation;
+  fix?: string;
 }
 
 export interface SyncStatus {
   is_synced: boolean;
   missing_skills: string[];
   extra_items: string[];
+  shadowed: string[];
+  unmanaged: string[];
+  locally_modified: string[];
   broken_links: string[];
+  points_elsewhere: string[];
+  outdated: string[];
 }
 
 export interface TargetInfo {
@@ -38,16 +82,70 @@ export interface TargetInfo {
   auto_detected: boolean;
   enabled: boolean;
   exists: boolean;
+  scope: Scope;
+  format: TargetFormat;
+  config_dir_exists: boolean;
+  binary: TargetBinary | null;
   sync_status: SyncStatus | null;
 }
 
+export interface TargetBinary {
+  name: string;
+  path: string | null;
+  version: string | null;
+}
+
+export type SyncStrategy = 'symlink' | 'hardlink-tree' | 'copy' | 'section';
+
+export type TargetFormat = 'skill-folder' | 'cursor-rule' | 'gemini-command' | 'agents-md';
+
 export interface SyncResult {
   target_id: string;
   target_name: string;
   created: string[];
   removed: string[];
   unchanged: string[];
+  updated: string[];
+  locally_modified: string[];
+  strategies: Record<string, SyncStrategy>;
+  filtered: string[];
   skipped_native: string[];
+  extra_items: string[];
+  errors: SyncError[];
+  rolled_back: boolean;
+  recovered: boolean;
+}
+
+export interface RepairResult {
+  target_id: string;
+  target_name: string;
+  relinked: Record<string, string>;
+  removed: string[];
+  unmanaged: string[];
+  errors: SyncError[];
+}
+
+export interface AdoptCandidate {
+  target_id: string;
+  target_name: string;
+  skill: string;
+  native_path: string;
+  conflict: { existing_path: string; existing_description: string } | null;
+}
+
+export interface AdoptSelection {
+  target_id: string;
+  skill: string;
+  resolution: 'import' | 'skip' | 'overwrite';
+}
+
+export interface AdoptResult {
+  target_id: string;
+  target_name: string;
+  adopted: string[];
+  overwritten: string[];
+  skipped: string[];
+  backups: Record<string, string>;
   errors: SyncError[];
 }
 
@@ -56,10 +154,46 @@ export interface SyncError {
   message: string;
 }
 
+export interface PlannedLink {
+  skill: string;
+  source_path: string;
+}
+
+export interface SyncPlan {
+  target_id: string;
+  target_name: string;
+  skills_path: string;
+  strategy: SyncStrategy;
+  format: TargetFormat;
+  create_dir: boolean;
+  create: PlannedLink[];
+  broken: PlannedLink[];
+  update: PlannedLink[];
+  locally_modified: string[];
+  filtered: string[];
+  remove: string[];
+  unchanged: string[];
+  skipped_native: string[];
+  extra_items: string[];
+  errors: SyncError[];
+}
+
+export interface ChangeSet {
+  skills: string[];
+  targets: string[];
+}
+
+export interface SkillsChangedPayload {
+  changes: ChangeSet;
+  results: SyncResult[];
+}
+
 export interface StatsInfo {
   total_skills: number;
   valid_skills: number;
+  warning_skills: number;
   invalid_skills: number;
+  total_size: SkillSize;
   total_targets: number;
   enabled_targets: number;
   is_watching: boolean;